@@ -0,0 +1,404 @@
+//! Module pour lire les arguments de la ligne de commande.
+//! Ce module contient la structure `Arguments`, qui regroupe les options passées au lancement du jeu
+//! (par exemple `cargo run -- --seed 42 --asteroid-texture mods/rochers.png`).
+
+use crate::interface::{rapport_depuis_texte, CoinEcran, StyleVies};
+
+/// Options passées au jeu sur la ligne de commande.
+#[derive(Debug, Default, PartialEq)]
+pub struct Arguments {
+    /// Graine du générateur aléatoire (`--seed <u64>`), pour rejouer exactement la même partie.
+    pub graine: Option<u64>,
+    /// Chemin d'une image à utiliser pour les astéroïdes (`--asteroid-texture <chemin>`).
+    pub texture_asteroids: Option<String>,
+    /// Fichier décrivant la première vague d'astéroïdes, par exemple pour le tutoriel (`--layout <chemin>`).
+    pub disposition: Option<String>,
+    /// Fichier décrivant la composition des vagues de certains niveaux (`--waves <chemin>`).
+    pub vagues: Option<String>,
+    /// Remplace l'image de fond par un champ d'étoiles scintillantes (`--starfield`).
+    pub champ_etoiles: bool,
+    /// Désactive la pause automatique quand la fenêtre perd le focus (`--no-auto-pause`).
+    pub sans_pause_auto: bool,
+    /// Ouvre la boutique des améliorations entre deux vagues (`--shop`).
+    pub boutique: bool,
+    /// Coin de l'écran où afficher les vies (`--lives-corner <haut-gauche|haut-droite|bas-gauche|bas-droite>`).
+    pub coin_vies: Option<CoinEcran>,
+    /// Style de l'indicateur des vies (`--lives-style <nombre|icones>`).
+    pub style_vies: Option<StyleVies>,
+    /// Proportions imposées à la zone de jeu, entourée de bandes noires (`--aspect <largeur:hauteur>`, par exemple `4:3`).
+    pub rapport_aspect: Option<f32>,
+    /// Fichier dans lequel enregistrer la partie, pour la revoir ensuite (`--record <chemin>`).
+    pub enregistrement: Option<String>,
+    /// Fichier CSV auquel ajouter les statistiques de chaque partie terminée (`--stats <chemin>`).
+    pub statistiques: Option<String>,
+    /// Fichier d'une partie enregistrée à revoir, au lieu de jouer (`--replay <chemin>`).
+    pub replay: Option<String>,
+    /// Nombre de niveaux entre deux boss (`--boss-interval <n>`, 0 = jamais).
+    pub intervalle_boss: Option<u32>,
+    /// Nombre de niveaux entre deux clusters protégés (`--cluster-interval <n>`, 0 = jamais).
+    pub intervalle_cluster: Option<u32>,
+    /// Mode facile : un seul impact détruit n'importe quel astéroïde, qui ne se divise pas (`--easy`).
+    pub mode_facile: bool,
+    /// Mode horde : les astéroïdes apparaissent en continu au lieu d'arriver par vagues (`--horde`).
+    pub mode_horde: bool,
+    /// Inverse les flèches gauche/droite pour la rotation du vaisseau (`--invert-rotation`).
+    pub inverser_rotation: bool,
+    /// Les flèches poussent le vaisseau dans leur direction à l'écran, quelle que soit son orientation (`--direct-thrust`).
+    pub poussee_directe: bool,
+    /// Recommencer une partie rejoue la même disposition d'astéroïdes, pour s'entraîner (`--fixed-seed`).
+    pub graine_fixe: bool,
+    /// Ajoute un trou noir qui attire les astéroïdes et le vaisseau (`--black-hole`).
+    pub trou_noir: bool,
+    /// Nombre maximal de bonus visibles en même temps à l'écran (`--max-bonuses <n>`).
+    pub max_bonus_simultanes: Option<usize>,
+    /// Les grands astéroïdes détruits laissent un nuage de débris qui abîme le vaisseau (`--debris-clouds`).
+    pub nuages_debris: bool,
+    /// Contre-la-montre : points perdus chaque seconde sans détruire d'astéroïde (`--score-decay <points>`).
+    pub decroissance_score: Option<f32>,
+    /// La rotation du vaisseau accélère et continue un instant après avoir relâché la touche (`--inertial-rotation`).
+    pub rotation_inertielle: bool,
+    /// Mode difficile : part de la direction des fragments tournée vers le vaisseau (`--aimed-fragments <0.0 à 1.0>`).
+    pub fragments_vers_vaisseau: Option<f32>,
+    /// La flèche bas freine le vaisseau jusqu'à l'arrêt au lieu de le faire reculer (`--brake`).
+    pub freinage: bool,
+}
+
+impl Arguments {
+    /// Lit les arguments du programme.
+    /// En cas d'argument invalide, un message est affiché et les options par défaut sont utilisées.
+    pub fn depuis_ligne_de_commande() -> Self {
+        Self::analyser(std::env::args().skip(1)).unwrap_or_else(|erreur| {
+            eprintln!("{erreur}");
+            Self::default()
+        })
+    }
+
+    /// Analyse une liste d'arguments (sans le nom du programme).
+    /// # Retourne une erreur décrivant le problème si un argument est inconnu ou mal formé.
+    pub fn analyser(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut arguments = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--seed" => {
+                    let valeur = args
+                        .next()
+                        .ok_or("L'option --seed attend une valeur.".to_string())?;
+                    let graine = valeur
+                        .parse()
+                        .map_err(|_| format!("Graine invalide : '{valeur}'."))?;
+                    arguments.graine = Some(graine);
+                }
+                "--asteroid-texture" => {
+                    let chemin = args
+                        .next()
+                        .ok_or("L'option --asteroid-texture attend un chemin.".to_string())?;
+                    arguments.texture_asteroids = Some(chemin);
+                }
+                "--layout" => {
+                    let chemin = args
+                        .next()
+                        .ok_or("L'option --layout attend un chemin.".to_string())?;
+                    arguments.disposition = Some(chemin);
+                }
+                "--waves" => {
+                    let chemin = args
+                        .next()
+                        .ok_or("L'option --waves attend un chemin.".to_string())?;
+                    arguments.vagues = Some(chemin);
+                }
+                "--lives-corner" => {
+                    let valeur = args
+                        .next()
+                        .ok_or("L'option --lives-corner attend un coin.".to_string())?;
+                    let coin = CoinEcran::depuis_nom(&valeur)
+                        .ok_or_else(|| format!("Coin inconnu : '{valeur}'."))?;
+                    arguments.coin_vies = Some(coin);
+                }
+                "--lives-style" => {
+                    let valeur = args
+                        .next()
+                        .ok_or("L'option --lives-style attend un style.".to_string())?;
+                    let style = StyleVies::depuis_nom(&valeur)
+                        .ok_or_else(|| format!("Style inconnu : '{valeur}'."))?;
+                    arguments.style_vies = Some(style);
+                }
+                "--aspect" => {
+                    let valeur = args
+                        .next()
+                        .ok_or("L'option --aspect attend des proportions.".to_string())?;
+                    let rapport = rapport_depuis_texte(&valeur)
+                        .ok_or_else(|| format!("Proportions invalides : '{valeur}'."))?;
+                    arguments.rapport_aspect = Some(rapport);
+                }
+                "--record" => {
+                    let chemin = args
+                        .next()
+                        .ok_or("L'option --record attend un chemin.".to_string())?;
+                    arguments.enregistrement = Some(chemin);
+                }
+                "--stats" => {
+                    let chemin = args
+                        .next()
+                        .ok_or("L'option --stats attend un chemin.".to_string())?;
+                    arguments.statistiques = Some(chemin);
+                }
+                "--replay" => {
+                    let chemin = args
+                        .next()
+                        .ok_or("L'option --replay attend un chemin.".to_string())?;
+                    arguments.replay = Some(chemin);
+                }
+                "--boss-interval" => {
+                    let valeur = args.next().ok_or(
+                        "L'option --boss-interval attend un nombre de niveaux.".to_string(),
+                    )?;
+                    let intervalle = valeur
+                        .parse()
+                        .map_err(|_| format!("Intervalle invalide : '{valeur}'."))?;
+                    arguments.intervalle_boss = Some(intervalle);
+                }
+                "--cluster-interval" => {
+                    let valeur = args.next().ok_or(
+                        "L'option --cluster-interval attend un nombre de niveaux.".to_string(),
+                    )?;
+                    let intervalle = valeur
+                        .parse()
+                        .map_err(|_| format!("Intervalle invalide : '{valeur}'."))?;
+                    arguments.intervalle_cluster = Some(intervalle);
+                }
+                "--max-bonuses" => {
+                    let valeur = args
+                        .next()
+                        .ok_or("L'option --max-bonuses attend un nombre de bonus.".to_string())?;
+                    let nombre = valeur
+                        .parse()
+                        .map_err(|_| format!("Nombre de bonus invalide : '{valeur}'."))?;
+                    arguments.max_bonus_simultanes = Some(nombre);
+                }
+                "--score-decay" => {
+                    let valeur = args.next().ok_or(
+                        "L'option --score-decay attend un nombre de points par seconde."
+                            .to_string(),
+                    )?;
+                    let points = valeur
+                        .parse::<f32>()
+                        .ok()
+                        .filter(|points| points.is_finite() && *points >= 0.0)
+                        .ok_or_else(|| format!("Nombre de points invalide : '{valeur}'."))?;
+                    arguments.decroissance_score = Some(points);
+                }
+                "--aimed-fragments" => {
+                    let valeur = args.next().ok_or(
+                        "L'option --aimed-fragments attend une valeur entre 0 et 1.".to_string(),
+                    )?;
+                    let part = valeur
+                        .parse::<f32>()
+                        .ok()
+                        .filter(|part| (0.0..=1.0).contains(part))
+                        .ok_or_else(|| format!("Valeur invalide : '{valeur}'."))?;
+                    arguments.fragments_vers_vaisseau = Some(part);
+                }
+                "--starfield" => arguments.champ_etoiles = true,
+                "--no-auto-pause" => arguments.sans_pause_auto = true,
+                "--shop" => arguments.boutique = true,
+                "--easy" => arguments.mode_facile = true,
+                "--horde" => arguments.mode_horde = true,
+                "--invert-rotation" => arguments.inverser_rotation = true,
+                "--direct-thrust" => arguments.poussee_directe = true,
+                "--fixed-seed" => arguments.graine_fixe = true,
+                "--black-hole" => arguments.trou_noir = true,
+                "--debris-clouds" => arguments.nuages_debris = true,
+                "--inertial-rotation" => arguments.rotation_inertielle = true,
+                "--brake" => arguments.freinage = true,
+                _ => return Err(format!("Argument inconnu : '{arg}'.")),
+            }
+        }
+
+        Ok(arguments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Transforme une ligne de commande en liste d'arguments.
+    fn args(ligne: &str) -> Vec<String> {
+        ligne.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_sans_argument() {
+        assert_eq!(Arguments::analyser(args("")), Ok(Arguments::default()));
+    }
+
+    #[test]
+    fn test_graine() {
+        let arguments = Arguments::analyser(args("--seed 42")).unwrap();
+        assert_eq!(arguments.graine, Some(42));
+    }
+
+    #[test]
+    fn test_champ_etoiles() {
+        assert!(!Arguments::analyser(args("")).unwrap().champ_etoiles);
+        assert!(
+            Arguments::analyser(args("--starfield"))
+                .unwrap()
+                .champ_etoiles
+        );
+    }
+
+    #[test]
+    fn test_sans_pause_auto() {
+        assert!(!Arguments::analyser(args("")).unwrap().sans_pause_auto);
+        assert!(
+            Arguments::analyser(args("--no-auto-pause"))
+                .unwrap()
+                .sans_pause_auto
+        );
+    }
+
+    #[test]
+    fn test_affichage_vies() {
+        let arguments =
+            Arguments::analyser(args("--lives-corner bas-droite --lives-style icones")).unwrap();
+        assert_eq!(arguments.coin_vies, Some(CoinEcran::BasDroite));
+        assert_eq!(arguments.style_vies, Some(StyleVies::Icones));
+        assert!(Arguments::analyser(args("--lives-corner milieu")).is_err());
+        assert!(Arguments::analyser(args("--lives-style")).is_err());
+    }
+
+    #[test]
+    fn test_rapport_aspect() {
+        assert_eq!(Arguments::analyser(args("")).unwrap().rapport_aspect, None);
+        let arguments = Arguments::analyser(args("--aspect 4:3")).unwrap();
+        assert_eq!(arguments.rapport_aspect, Some(4.0 / 3.0));
+        assert!(Arguments::analyser(args("--aspect")).is_err());
+        assert!(Arguments::analyser(args("--aspect 4x3")).is_err());
+    }
+
+    #[test]
+    fn test_enregistrement_et_replay() {
+        let arguments = Arguments::analyser(args("--record partie.txt")).unwrap();
+        assert_eq!(arguments.enregistrement.as_deref(), Some("partie.txt"));
+        let arguments = Arguments::analyser(args("--replay partie.txt")).unwrap();
+        assert_eq!(arguments.replay.as_deref(), Some("partie.txt"));
+    }
+
+    #[test]
+    fn test_statistiques() {
+        assert_eq!(Arguments::analyser(args("")).unwrap().statistiques, None);
+        let arguments = Arguments::analyser(args("--stats parties.csv")).unwrap();
+        assert_eq!(arguments.statistiques.as_deref(), Some("parties.csv"));
+        assert!(Arguments::analyser(args("--stats")).is_err());
+    }
+
+    #[test]
+    fn test_intervalle_boss() {
+        assert_eq!(Arguments::analyser(args("")).unwrap().intervalle_boss, None);
+        let arguments = Arguments::analyser(args("--boss-interval 5")).unwrap();
+        assert_eq!(arguments.intervalle_boss, Some(5));
+        assert!(Arguments::analyser(args("--boss-interval")).is_err());
+        assert!(Arguments::analyser(args("--boss-interval cinq")).is_err());
+    }
+
+    #[test]
+    fn test_intervalle_cluster() {
+        assert_eq!(
+            Arguments::analyser(args("")).unwrap().intervalle_cluster,
+            None
+        );
+        let arguments = Arguments::analyser(args("--cluster-interval 3")).unwrap();
+        assert_eq!(arguments.intervalle_cluster, Some(3));
+        assert!(Arguments::analyser(args("--cluster-interval")).is_err());
+        assert!(Arguments::analyser(args("--cluster-interval -1")).is_err());
+    }
+
+    #[test]
+    fn test_max_bonus_simultanes() {
+        assert_eq!(
+            Arguments::analyser(args("")).unwrap().max_bonus_simultanes,
+            None
+        );
+        let arguments = Arguments::analyser(args("--max-bonuses 3")).unwrap();
+        assert_eq!(arguments.max_bonus_simultanes, Some(3));
+        assert!(Arguments::analyser(args("--max-bonuses")).is_err());
+        assert!(Arguments::analyser(args("--max-bonuses trois")).is_err());
+    }
+
+    #[test]
+    fn test_decroissance_score() {
+        assert_eq!(
+            Arguments::analyser(args("")).unwrap().decroissance_score,
+            None
+        );
+        let arguments = Arguments::analyser(args("--score-decay 2.5")).unwrap();
+        assert_eq!(arguments.decroissance_score, Some(2.5));
+        assert!(Arguments::analyser(args("--score-decay")).is_err());
+        assert!(Arguments::analyser(args("--score-decay -1")).is_err());
+        assert!(Arguments::analyser(args("--score-decay inf")).is_err());
+    }
+
+    #[test]
+    fn test_fragments_vers_vaisseau() {
+        assert_eq!(
+            Arguments::analyser(args(""))
+                .unwrap()
+                .fragments_vers_vaisseau,
+            None
+        );
+        let arguments = Arguments::analyser(args("--aimed-fragments 0.5")).unwrap();
+        assert_eq!(arguments.fragments_vers_vaisseau, Some(0.5));
+        assert!(Arguments::analyser(args("--aimed-fragments")).is_err());
+        assert!(Arguments::analyser(args("--aimed-fragments 1.5")).is_err());
+        assert!(Arguments::analyser(args("--aimed-fragments NaN")).is_err());
+    }
+
+    /// Option sans valeur de la ligne de commande, avec le champ qu'elle active.
+    type OptionBooleenne = (&'static str, fn(&Arguments) -> bool);
+
+    /// Options de la ligne de commande qui activent un réglage, avec le champ correspondant.
+    const OPTIONS_BOOLEENNES: [OptionBooleenne; 10] = [
+        ("--shop", |a| a.boutique),
+        ("--easy", |a| a.mode_facile),
+        ("--horde", |a| a.mode_horde),
+        ("--invert-rotation", |a| a.inverser_rotation),
+        ("--direct-thrust", |a| a.poussee_directe),
+        ("--fixed-seed", |a| a.graine_fixe),
+        ("--black-hole", |a| a.trou_noir),
+        ("--debris-clouds", |a| a.nuages_debris),
+        ("--inertial-rotation", |a| a.rotation_inertielle),
+        ("--brake", |a| a.freinage),
+    ];
+
+    /// Chaque option sans valeur active son propre champ, et seulement celui-là.
+    #[test]
+    fn test_options_booleennes() {
+        let defaut = Arguments::analyser(args("")).unwrap();
+        for (option, champ) in OPTIONS_BOOLEENNES {
+            assert!(!champ(&defaut), "{option}");
+            let arguments = Arguments::analyser(args(option)).unwrap();
+            for (autre, champ_autre) in OPTIONS_BOOLEENNES {
+                assert_eq!(
+                    champ_autre(&arguments),
+                    autre == option,
+                    "{option} / {autre}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_graine_invalide_ou_absente() {
+        assert!(Arguments::analyser(args("--seed abc")).is_err());
+        assert!(Arguments::analyser(args("--seed")).is_err());
+        assert!(Arguments::analyser(args("--inconnu")).is_err());
+        assert!(Arguments::analyser(args("--asteroid-texture")).is_err());
+        assert!(Arguments::analyser(args("--layout")).is_err());
+        assert!(Arguments::analyser(args("--waves")).is_err());
+        assert!(Arguments::analyser(args("--record")).is_err());
+        assert!(Arguments::analyser(args("--replay")).is_err());
+    }
+}
@@ -1,75 +1,243 @@
 //! Module pour gérer les astéroïdes dans le jeu.
 //! Ce module contient la structure 'Asteroid' ainsi que les méthodes pour les créer, déplacer et manipuler dans le jeu.
 
-use ::rand::{thread_rng, Rng};
+use crate::constantes;
+use crate::stellarobject::StellarObject;
+use crate::transform::{direction_ou_defaut, Transform};
+use ::rand::Rng;
 use macroquad::prelude::*;
 use std::f32::consts::PI;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 /// Structure représentant un astéroïde dans le jeu.
 /// Un 'Asteroid' est caractérisé par une position, une vitesse et une taille.
 /// La taille de l'astéroïde détermine sa résistance (difficulté à le détruire) et sa taille à l'écran.
+#[derive(Debug, Clone)]
 pub struct Asteroid {
-    position: Vec2, // Vecteur 2 dimensions qui représente la position de l'astéroïde: (x,y)
-    speed: Vec2, // Vecteur 2 dimensions qui représente sa vitesse: (1.0, 0.0) = il va vers la doite par ex.
+    transform: Transform, // Position (x,y) et vitesse de l'astéroïde: (1.0, 0.0) = il va vers la doite par ex.
     speed_min: Vec2,
     taille: u8, // Taille de l'astéroïde: 1 = petit, 2 = moyen, 3 = grand. Valeur u8 tirée au sort par la suite.
     resistance: u8, // Nombre de missiles necessaires pour détruire l'astéroïde.
+    blinde: bool, // Un astéroïde blindé renvoie les missiles, sauf s'ils touchent son point faible (l'arrière).
+    angle: f32,   // Orientation de l'astéroïde (en radians), pour l'affichage
+    vitesse_rotation: f32, // Vitesse de rotation sur lui-même (en radians par frame, positive = sens horaire à l'écran)
+    id: u32, // Identifiant unique, qui reste le même d'une frame à l'autre (les indices changent)
+}
+
+/// Deux astéroïdes sont égaux s'ils sont dans le même état, quel que soit leur identifiant.
+impl PartialEq for Asteroid {
+    fn eq(&self, autre: &Self) -> bool {
+        self.transform == autre.transform
+            && self.speed_min == autre.speed_min
+            && self.taille == autre.taille
+            && self.resistance == autre.resistance
+            && self.blinde == autre.blinde
+            && self.angle == autre.angle
+            && self.vitesse_rotation == autre.vitesse_rotation
+    }
+}
+
+/// Prochain identifiant à donner à un astéroïde.
+static PROCHAIN_ID: AtomicU32 = AtomicU32::new(1);
+
+/// Borne la norme d'une vitesse d'astéroïde à `VITESSE_MAX_ASTEROID`, sans changer sa direction.
+fn limiter_vitesse(vitesse: Vec2) -> Vec2 {
+    vitesse.clamp_length_max(constantes::VITESSE_MAX_ASTEROID)
+}
+
+/// Retourne un identifiant jamais donné à un autre astéroïde.
+fn nouvel_id() -> u32 {
+    PROCHAIN_ID.fetch_add(1, Ordering::Relaxed)
 }
 
 impl Asteroid {
     /// Constante représentant la taille initiale des astéroïdes.
-    pub const ASTEROID_INIT_SIZE: f32 = 60.0;
+    pub const ASTEROID_INIT_SIZE: f32 = constantes::TAILLE_ASTEROIDE_BASE;
+
+    /// Vitesse de rotation maximale d'un astéroïde sur lui-même (en radians par frame).
+    pub const ROTATION_MAX: f32 = 0.03;
+
+    /// Angle (en radians) dont les fragments sont déviés pour chaque radian par frame de rotation du parent.
+    pub const DEVIATION_ROTATION: f32 = 10.0;
 
     /// Méthode qui permet de créer un nouvel astéroïde avec une position et une vitesse aléatoires.
     /// Lors de la création, la taille de l'astéroïde est donnée aléatoirement.
     /// Cette taille défini la résistance de l'astéroïde.
+    /// # Arguments
+    /// - `rng` : Générateur aléatoire utilisé pour la taille, la position et la vitesse.
+    /// - `ecran` : Dimensions de l'écran (largeur, hauteur), l'astéroïde apparaît près d'un bord.
     /// # Retourne un objet 'Asteroid'
-    pub fn new() -> Self {
+    pub fn nouveau_dans(rng: &mut impl Rng, ecran: Vec2) -> Self {
         // Génère une taille entre 1 (petit), 2 (moyen), et 3 (grand) aléatoirement.
-        let mut rng = thread_rng();
         let taille = rng.gen_range(1..=3);
         // La vitesse est choisie avec la méthode "new_alea_speed()", aléatoirement.
-        let vitesse = Self::new_alea_speed();
-        // La résistance de l'astéroïde dépend de sa taille.
-        let resistance: u8 = match taille {
-            1 => 1,
-            2 => 3,
-            3 => 5,
-            _ => 0,
-        };
-
+        let vitesse = Self::new_alea_speed(rng);
         // Retourne un objet avec une position, une vitesse, une taille et une résistance.
         Self {
-            position: Self::new_alea_pos(),
-            speed: vitesse,
+            transform: Transform::new(Self::new_alea_pos(rng, ecran), vitesse),
+            id: nouvel_id(),
             speed_min: vitesse,
             taille,
-            resistance,
+            resistance: resistance_initiale(taille), // La résistance de l'astéroïde dépend de sa taille.
+            blinde: false,
+            angle: 0.0,
+            vitesse_rotation: Self::new_alea_rotation(rng),
         }
     }
 
+    /// Crée un astéroïde de la taille donnée, près d'un bord de l'écran, avec une vitesse et une rotation aléatoires.
+    pub fn de_taille_dans(rng: &mut impl Rng, taille: u8, ecran: Vec2) -> Self {
+        let position = Self::new_alea_pos(rng, ecran);
+        Self::nouvel_asteroid(rng, taille, position)
+    }
+
     /// Crée un nouvel astéroïde de taille spécifique et à une position donnée.
-    pub fn nouvel_asteroid(taille: u8, position: Vec2) -> Self {
-        let vitesse = Self::new_alea_speed(); // Générer une nouvelle vitesse aléatoire
+    /// Sa vitesse et sa rotation sont tirées au sort avec le générateur `rng`.
+    pub fn nouvel_asteroid(rng: &mut impl Rng, taille: u8, position: Vec2) -> Self {
+        let vitesse = Self::new_alea_speed(rng); // Générer une nouvelle vitesse aléatoire
+        let vitesse_rotation = Self::new_alea_rotation(rng); // Et une rotation sur lui-même
+
+        Self {
+            transform: Transform::new(position, vitesse),
+            id: nouvel_id(),
+            speed_min: vitesse,
+            taille,
+            resistance: resistance_initiale(taille),
+            blinde: false,
+            angle: 0.0,
+            vitesse_rotation,
+        }
+    }
 
+    /// Crée un astéroïde entièrement déterminé, sans tirage aléatoire (niveaux scriptés, tutoriel).
+    /// # Arguments
+    /// - `taille` : Taille de l'astéroïde (1, 2 ou 3), qui fixe sa résistance.
+    /// - `position` : Position de l'astéroïde.
+    /// - `vitesse` : Vitesse de l'astéroïde, qui est aussi sa vitesse minimale.
+    ///
+    /// L'astéroïde ne tourne pas sur lui-même.
+    pub fn scripté(taille: u8, position: Vec2, vitesse: Vec2) -> Self {
+        let vitesse = limiter_vitesse(vitesse);
         Self {
-            position,
-            speed: vitesse,
+            transform: Transform::new(position, vitesse),
+            id: nouvel_id(),
             speed_min: vitesse,
             taille,
-            resistance: match taille {
-                1 => 1,
-                2 => 3,
-                3 => 5,
-                _ => 1,
-            },
+            resistance: resistance_initiale(taille),
+            blinde: false,
+            angle: 0.0,
+            vitesse_rotation: 0.0,
         }
     }
 
+    /// Crée un fragment d'astéroïde dont la vitesse mélange celle de l'astéroïde parent et une vitesse aléatoire.
+    /// Les fragments suivent ainsi en partie le mouvement du parent au lieu de s'éparpiller au hasard.
+    /// Si le parent tournait sur lui-même, les fragments sont déviés dans le sens de sa rotation (effet de tourbillon)
+    /// et continuent à tourner comme lui.
+    /// # Arguments
+    /// - `rng` : Générateur aléatoire.
+    /// - `taille` : Taille du fragment.
+    /// - `position` : Position du fragment.
+    /// - `parent` : Astéroïde détruit.
+    /// - `heritage` : Part de la vitesse du parent conservée, de 0.0 (vitesse aléatoire) à 1.0 (vitesse du parent).
+    /// - `position_vaisseau` : Position du vaisseau du joueur.
+    /// - `attraction` : Part de la direction du fragment tournée vers le vaisseau, de 0.0 (aucune) à 1.0
+    ///   (le fragment fonce droit sur le vaisseau). Sert au mode difficile.
+    pub fn fragment(
+        rng: &mut impl Rng,
+        taille: u8,
+        position: Vec2,
+        parent: &Asteroid,
+        heritage: f32,
+        position_vaisseau: Vec2,
+        attraction: f32,
+    ) -> Self {
+        let heritage = heritage.clamp(0.0, 1.0);
+        let mut fragment = Self::nouvel_asteroid(rng, taille, position);
+        let aleatoire = fragment.transform.vitesse;
+        let vitesse_parent = parent.transform.vitesse;
+
+        // On mélange les directions, puis on donne au fragment une vitesse intermédiaire entre les deux.
+        let direction = (vitesse_parent * heritage + aleatoire * (1.0 - heritage))
+            .try_normalize()
+            .unwrap_or(aleatoire); // Directions opposées qui s'annulent : on garde la vitesse aléatoire.
+        let longueur = vitesse_parent.length() * heritage + aleatoire.length() * (1.0 - heritage);
+        // La rotation du parent dévie la direction d'éjection.
+        let deviation = Vec2::from_angle(parent.vitesse_rotation * Self::DEVIATION_ROTATION);
+        let direction = deviation.rotate(direction);
+        // En mode difficile, la direction est en partie tournée vers le vaisseau.
+        let attraction = attraction.clamp(0.0, 1.0);
+        let direction = match (position_vaisseau - position).try_normalize() {
+            Some(vers_vaisseau) if attraction > 0.0 => (direction * (1.0 - attraction)
+                + vers_vaisseau * attraction)
+                .try_normalize()
+                .unwrap_or(vers_vaisseau),
+            _ => direction, // Pas d'attraction, ou fragment déjà sur le vaisseau
+        };
+        let vitesse = limiter_vitesse(direction * longueur);
+
+        fragment.transform.vitesse = vitesse;
+        fragment.speed_min = vitesse;
+        fragment.vitesse_rotation = parent.vitesse_rotation;
+        fragment
+    }
+
+    /// Crée un astéroïde aléatoire juste à l'extérieur de l'écran, qui se dirige vers l'intérieur.
+    /// Utilisé par le mode horde : les nouveaux astéroïdes arrivent par un bord au lieu d'apparaître au milieu
+    /// de l'écran, éventuellement sur le vaisseau.
+    /// # Arguments
+    /// - `rng` : Générateur aléatoire.
+    /// - `ecran` : Dimensions de l'écran (largeur, hauteur).
+    pub fn new_hors_ecran_vers_interieur(rng: &mut impl Rng, ecran: Vec2) -> Self {
+        let taille = rng.gen_range(1..=3);
+        let mut asteroid = Self::nouvel_asteroid(rng, taille, Vec2::ZERO);
+        let marge = asteroid.rayon_asteroid(); // L'astéroïde est entièrement caché au départ.
+
+        // On choisit un bord, puis une position le long de ce bord, et la normale qui pointe vers l'écran.
+        let (position, normale) = match rng.gen_range(1..=4) {
+            1 => (vec2(rng.gen_range(0.0..=ecran.x), -marge), vec2(0.0, 1.0)), // Haut
+            2 => (
+                vec2(ecran.x + marge, rng.gen_range(0.0..=ecran.y)),
+                vec2(-1.0, 0.0),
+            ), // Droite
+            3 => (
+                vec2(rng.gen_range(0.0..=ecran.x), ecran.y + marge),
+                vec2(0.0, -1.0),
+            ), // Bas
+            _ => (vec2(-marge, rng.gen_range(0.0..=ecran.y)), vec2(1.0, 0.0)), // Gauche
+        };
+
+        // La direction s'écarte d'au plus 60° de la normale, pour entrer franchement dans l'écran.
+        let ecart: f32 = rng.gen_range(-PI / 3.0..=PI / 3.0);
+        let vitesse = Vec2::from_angle(ecart).rotate(normale);
+
+        asteroid.transform.position = position;
+        asteroid.transform.vitesse = vitesse;
+        asteroid.speed_min = vitesse;
+        asteroid
+    }
+
     /// Retourne la position actuelle de l'astéroïde.
     /// # Retourne un vecteur 'Vec2' avec les positions x et y de l'astéroïde dans l'espace de jeu.
     pub fn get_position(&self) -> Vec2 {
-        self.position
+        self.transform.position
+    }
+
+    /// Retourne la vitesse actuelle de l'astéroïde.
+    /// # Retourne un vecteur 'Vec2' correspondant au déplacement de l'astéroïde à chaque frame.
+    pub fn get_vitesse(&self) -> Vec2 {
+        self.transform.vitesse
+    }
+
+    /// Retourne la position prévue de l'astéroïde dans `anticipation` frames, s'il garde sa vitesse actuelle.
+    /// Le rebouclage sur les bords de l'écran n'est pas pris en compte.
+    pub fn position_predite(&self, anticipation: f32) -> Vec2 {
+        self.transform.position + self.transform.vitesse * anticipation
+    }
+
+    /// Retourne l'orientation actuelle de l'astéroïde (en radians).
+    pub fn get_angle(&self) -> f32 {
+        self.angle
     }
 
     /// Retourne la taille de l'astéroïde (1 = petit, 2 = moyen, 3 = grand)
@@ -77,11 +245,40 @@ impl Asteroid {
         self.taille
     }
 
+    /// Retourne l'identifiant de l'astéroïde, qui ne change pas tant qu'il existe.
+    pub fn get_id(&self) -> u32 {
+        self.id
+    }
+
     /// Retourne la résistance actuelle de l'astéroïde. (Nombre de missiles qui peuvent encore taper l'astéroïde avant sa destruction)
     pub fn get_resistance(&self) -> u8 {
         self.resistance
     }
 
+    /// Indique si l'astéroïde est blindé.
+    pub fn est_blinde(&self) -> bool {
+        self.blinde
+    }
+
+    /// Rend l'astéroïde blindé (ou non).
+    pub fn set_blinde(&mut self, blinde: bool) {
+        self.blinde = blinde;
+    }
+
+    /// Indique si un impact au point donné touche le point faible de l'astéroïde.
+    /// Le point faible est l'arrière de l'astéroïde, à l'opposé de son déplacement (cône de 45° de chaque côté).
+    /// Un astéroïde immobile n'a pas de point faible.
+    pub fn touche_point_faible(&self, point_impact: Vec2) -> bool {
+        let direction_impact = (point_impact - self.transform.position).normalize_or_zero();
+        let arriere = -self.transform.vitesse.normalize_or_zero();
+        arriere != Vec2::ZERO && direction_impact.dot(arriere) >= (PI / 4.0).cos()
+    }
+
+    /// Détruit l'astéroïde d'un coup, quelle que soit sa résistance restante (mode facile).
+    pub fn detruire(&mut self) {
+        self.resistance = 0;
+    }
+
     /// Retourne la résistance de l'astéroïde de 1 (lors de l'impact avec un missile)
     pub fn diminuer_résistance(&mut self) {
         if self.resistance > 0 {
@@ -94,16 +291,28 @@ impl Asteroid {
         self.resistance == 0
     }
 
+    /// Indique si l'astéroïde se divise en deux fragments plus petits quand il est détruit.
+    /// Seuls les astéroïdes de taille 2 ou plus se divisent : les petits disparaissent, ce qui empêche une fragmentation infinie.
+    pub fn peut_se_diviser(&self) -> bool {
+        self.taille >= 2
+    }
+
     /// Met à jour la position de l'astéroide en fonction de sa vitesse.
-    pub fn move_object(&mut self) -> Vec2 {
-        self.position += self.speed;
-        self.position = Self::bound_pos(self.position);
-        self.position
+    /// Le déplacement est multiplié par `facteur` (1.0 = vitesse normale) sans modifier la vitesse stockée.
+    /// # Arguments
+    /// - `facteur` : Facteur appliqué au déplacement de cette frame.
+    /// - `ecran` : Dimensions de l'écran (largeur, hauteur).
+    pub fn move_object(&mut self, facteur: f32, ecran: Vec2) -> Vec2 {
+        self.transform.advance(facteur);
+        self.angle += self.vitesse_rotation * facteur;
+        self.transform.wrap(ecran.x, ecran.y);
+        self.transform.position
     }
 
-    /// Applique une nouvelle vitesse à l'astéroïde (par exemple après une collision avec le vaisseau)
+    /// Applique une nouvelle vitesse à l'astéroïde (par exemple après une collision avec le vaisseau),
+    /// bornée à `VITESSE_MAX_ASTEROID`.
     pub fn nouvelle_vitesse(&mut self, nv_vitesse: Vec2) {
-        self.speed = nv_vitesse;
+        self.transform.vitesse = limiter_vitesse(nv_vitesse);
     }
 
     /// Applique une résistance pour empêcher que la vitesse de l'astéroïde descende en dessous de sa vitesse minimale.
@@ -112,76 +321,158 @@ impl Asteroid {
     pub fn appliquer_resistance(&mut self) {
         let effet_friction = 0.98;
 
-        if self.speed.length() > self.speed_min.length() {
-            self.speed *= effet_friction;
-
-            if self.speed.length() < self.speed_min.length() {
-                self.speed = self.speed;
-            }
+        if self.transform.vitesse.length() > self.speed_min.length() {
+            self.transform.vitesse *= effet_friction;
         }
     }
 
     /// Méthode qui retourne le rayon de l'astéroïde en fonction de sa taille.
     pub fn rayon_asteroid(&self) -> f32 {
-        match self.taille {
-            1 => Self::ASTEROID_INIT_SIZE / 2.0, //Petit
-            2 => Self::ASTEROID_INIT_SIZE,       //Moyen
-            3 => Self::ASTEROID_INIT_SIZE * 1.5, //Grand
-            _ => Self::ASTEROID_INIT_SIZE,       //Par défaut : moyen
-        }
+        rayon_pour(self.taille)
     }
 
     /// Fait rebondir l'astéroïde lorqu'il entre en collision avec un autre objet.
     pub fn rebondir(&mut self, collision_direction: Vec2) {
         // Variable qui stocke la normale par rapport à l'endroit de la collision
-        let normale = collision_direction.normalize();
+        // (direction par défaut si les deux objets sont confondus, pour ne pas obtenir de NaN).
+        let normale = direction_ou_defaut(collision_direction);
         // L'astéroïde rebondi alors en prenant de la vitesse dans le sens inverse à la collision.
-        self.speed = self.speed - 2.0 * self.speed.dot(normale) * normale;
+        self.transform.vitesse = limiter_vitesse(
+            self.transform.vitesse - 2.0 * self.transform.vitesse.dot(normale) * normale,
+        );
     }
 
     /// Génère une position aléatoire près de l'un des bords.
-    fn new_alea_pos() -> Vec2 {
-        let mut rng = thread_rng();
-
+    fn new_alea_pos(rng: &mut impl Rng, ecran: Vec2) -> Vec2 {
         let nearpos: f32 = rng.gen_range(Self::ASTEROID_INIT_SIZE / 2.0..=Self::ASTEROID_INIT_SIZE);
         let nearside = rng.gen_range(1..=4); // 1 = top, 2 = right, 3 = down, 4 = left
         let xpos: f32 = match nearside {
-            2 => screen_width() - nearpos,
+            2 => ecran.x - nearpos,
             4 => nearpos,
-            _ => rng.gen_range(0.0..=screen_width()),
+            _ => rng.gen_range(0.0..=ecran.x),
         };
         let ypos: f32 = match nearside {
             1 => nearpos,
-            3 => screen_height() - nearpos,
-            _ => rng.gen_range(0.0..=screen_height()),
+            3 => ecran.y - nearpos,
+            _ => rng.gen_range(0.0..=ecran.y),
         };
         vec2(xpos, ypos)
     }
 
-    /// Génère une vitesse aléatoire pour l'astéroïde.
-    fn new_alea_speed() -> Vec2 {
-        let mut rng = thread_rng();
+    /// Génère une vitesse de rotation aléatoire pour l'astéroïde, dans un sens ou dans l'autre.
+    fn new_alea_rotation(rng: &mut impl Rng) -> f32 {
+        rng.gen_range(-Self::ROTATION_MAX..=Self::ROTATION_MAX)
+    }
 
+    /// Génère une vitesse aléatoire pour l'astéroïde.
+    fn new_alea_speed(rng: &mut impl Rng) -> Vec2 {
         let angle: f32 = rng.gen_range(0.0..=(2.0 * PI));
         Vec2::from_angle(angle)
     }
+}
 
-    /// Assure que l'astéroïde reste à l'écran en rebouclant sa position.
-    fn bound_pos(mut pos: Vec2) -> Vec2 {
-        pos.x = Self::bound_to(pos.x, screen_width());
-        pos.y = Self::bound_to(pos.y, screen_height());
-        pos
+impl StellarObject for Asteroid {
+    fn get_position(&self) -> Vec2 {
+        self.transform.position
     }
 
-    /// Assure que les cordonnées restent dans les limites de l'écran.
-    fn bound_to(coord: f32, max: f32) -> f32 {
-        if coord < 0.0 {
-            max - coord
-        } else if coord > max {
-            coord - max
-        } else {
-            coord
-        }
+    fn set_position(&mut self, new_position: Vec2) {
+        self.transform.position = new_position;
+    }
+
+    fn get_vitesse(&self) -> Vec2 {
+        self.transform.vitesse
+    }
+
+    fn set_vitesse(&mut self, new_vitesse: Vec2) {
+        self.transform.vitesse = limiter_vitesse(new_vitesse);
+    }
+}
+
+/// Nombre maximal d'astéroïdes présents en même temps, pour que le vecteur des astéroïdes reste borné.
+pub const MAX_ASTEROIDS: usize = 200;
+
+/// Nombre d'astéroïdes générés au début d'un niveau.
+/// Premier niveau = 5 astéroïdes, ensuite 1 astéroïde de plus à chaque niveau.
+pub fn nombre_asteroides_niveau(niveau: i32) -> usize {
+    niveau.saturating_add(4).max(0) as usize
+}
+
+/// Génère une vague de `nombre` astéroïdes aléatoires (au plus `MAX_ASTEROIDS`).
+/// # Arguments
+/// - `rng` : Générateur aléatoire.
+/// - `nombre` : Nombre d'astéroïdes à créer.
+/// - `ecran` : Dimensions de l'écran (largeur, hauteur).
+pub fn generer_asteroides(rng: &mut impl Rng, nombre: usize, ecran: Vec2) -> Vec<Asteroid> {
+    (0..nombre.min(MAX_ASTEROIDS))
+        .map(|_| Asteroid::nouveau_dans(rng, ecran))
+        .collect()
+}
+
+/// Ajoute de nouveaux astéroïdes (fragments, apparitions...) sans dépasser `MAX_ASTEROIDS`.
+/// Les astéroïdes en trop sont ignorés.
+/// # Retourne le nombre d'astéroïdes réellement ajoutés.
+pub fn ajouter_asteroides(
+    asteroids: &mut Vec<Asteroid>,
+    nouveaux: impl IntoIterator<Item = Asteroid>,
+) -> usize {
+    let place = MAX_ASTEROIDS.saturating_sub(asteroids.len());
+    let avant = asteroids.len();
+    asteroids.extend(nouveaux.into_iter().take(place));
+    asteroids.len() - avant
+}
+
+/// Lit une disposition d'astéroïdes écrite sous forme de texte (par exemple un fichier de tutoriel).
+/// Chaque ligne décrit un astéroïde : `taille x y vx vy`. Les lignes vides et celles commençant par `#` sont ignorées.
+/// # Retourne une erreur indiquant la ligne fautive si une ligne est mal formée.
+pub fn analyser_disposition(texte: &str) -> Result<Vec<Asteroid>, String> {
+    texte
+        .lines()
+        .enumerate()
+        .map(|(numero, ligne)| (numero + 1, ligne.trim()))
+        .filter(|(_, ligne)| !ligne.is_empty() && !ligne.starts_with('#'))
+        .map(|(numero, ligne)| {
+            let erreur =
+                || format!("Ligne {numero} invalide : '{ligne}' (attendu : taille x y vx vy).");
+            let champs: Vec<&str> = ligne.split_whitespace().collect();
+            if champs.len() != 5 {
+                return Err(erreur());
+            }
+            let taille: u8 = champs[0].parse().map_err(|_| erreur())?;
+            if !(1..=3).contains(&taille) {
+                return Err(erreur());
+            }
+            let nombres = champs[1..]
+                .iter()
+                .map(|champ| champ.parse::<f32>().map_err(|_| erreur()))
+                .collect::<Result<Vec<f32>, String>>()?;
+            Ok(Asteroid::scripté(
+                taille,
+                vec2(nombres[0], nombres[1]),
+                vec2(nombres[2], nombres[3]),
+            ))
+        })
+        .collect()
+}
+
+/// Nombre de missiles nécessaires pour détruire un astéroïde de la taille donnée.
+/// Utilisée par tous les constructeurs, pour que la résistance ne dépende que de la taille.
+pub fn resistance_initiale(taille: u8) -> u8 {
+    match taille {
+        1 => 1, // Petit
+        2 => 3, // Moyen
+        3 => 5, // Grand
+        _ => 1, // Par défaut : un seul missile
+    }
+}
+
+/// Rayon d'un astéroïde de la taille donnée.
+pub fn rayon_pour(taille: u8) -> f32 {
+    match taille {
+        1 => Asteroid::ASTEROID_INIT_SIZE / 2.0, //Petit
+        2 => Asteroid::ASTEROID_INIT_SIZE,       //Moyen
+        3 => Asteroid::ASTEROID_INIT_SIZE * 1.5, //Grand
+        _ => Asteroid::ASTEROID_INIT_SIZE,       //Par défaut : moyen
     }
 }
 
@@ -190,7 +481,8 @@ impl Asteroid {
 /// Pos2 sera la position du deuxième astéroïde.
 pub fn positions_asteroides_apres_collision(missile_pos: Vec2, asteroid_pos: Vec2) -> (Vec2, Vec2) {
     // Calculer la direction du missile par rapport à l'astéroïde
-    let direction_missile = (asteroid_pos - missile_pos).normalize();
+    // (direction par défaut si le missile est pile au centre de l'astéroïde).
+    let direction_missile = direction_ou_defaut(asteroid_pos - missile_pos);
 
     // Générer les nouvelles positions en se décalant légèrement de part et d'autre de l'axe de la collision
     let offset_distance = 50.0; // Distance pour écarter les fragments
@@ -205,44 +497,343 @@ pub fn positions_asteroides_apres_collision(missile_pos: Vec2, asteroid_pos: Vec
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ::rand::thread_rng;
 
     #[test]
     fn test_creation_asteroid() {
-        let asteroid = Asteroid {
-            position: Vec2::new(0.0, 0.0), // Assure-toi de ne pas appeler de fonctions de macroquad ici.
-            speed: Vec2::new(1.0, 1.0),
-            speed_min: Vec2::new(1.0, 1.0),
-            taille: 2,
-            resistance: 3,
-        };
+        let asteroid = Asteroid::scripté(2, Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0));
         assert!(asteroid.get_taille() >= 1 && asteroid.get_taille() <= 3);
         assert!(asteroid.get_resistance() > 0);
     }
 
     #[test]
     fn test_diminuer_resistance() {
-        let mut asteroid = Asteroid {
-            position: Vec2::new(0.0, 0.0),
-            speed: Vec2::new(1.0, 1.0),
-            speed_min: Vec2::new(1.0, 1.0),
-            taille: 2,
-            resistance: 3,
-        };
+        let mut asteroid = Asteroid::scripté(2, Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0));
         let initial_resistance = asteroid.get_resistance();
         asteroid.diminuer_résistance();
         assert_eq!(asteroid.get_resistance(), initial_resistance - 1);
     }
 
+    /// Un missile pile au centre de l'astéroïde ne doit pas produire de positions NaN pour les fragments.
+    #[test]
+    fn test_fragments_positions_confondues() {
+        let position = vec2(200.0, 150.0);
+        let (pos1, pos2) = positions_asteroides_apres_collision(position, position);
+        assert!(pos1.is_finite() && pos2.is_finite());
+        assert_ne!(pos1, pos2); // Les fragments sont quand même écartés.
+    }
+
+    /// Un rebond sur une direction nulle garde une vitesse valide.
+    #[test]
+    fn test_rebondir_direction_nulle() {
+        let mut asteroid = Asteroid::scripté(2, vec2(200.0, 150.0), vec2(1.0, 2.0));
+        asteroid.rebondir(Vec2::ZERO);
+        assert!(asteroid.get_vitesse().is_finite());
+    }
+
+    /// Tous les constructeurs donnent la même résistance et le même rayon pour une même taille.
+    #[test]
+    fn test_resistance_et_rayon_partages() {
+        let mut rng = thread_rng();
+        for _ in 0..50 {
+            let asteroid = Asteroid::nouveau_dans(&mut rng, vec2(800.0, 600.0));
+            assert_eq!(
+                asteroid.get_resistance(),
+                resistance_initiale(asteroid.get_taille())
+            );
+        }
+        for taille in 1..=3 {
+            let aleatoire = Asteroid::nouvel_asteroid(&mut rng, taille, Vec2::ZERO);
+            let scripte = Asteroid::scripté(taille, Vec2::ZERO, Vec2::ZERO);
+            assert_eq!(aleatoire.get_resistance(), resistance_initiale(taille));
+            assert_eq!(scripte.get_resistance(), resistance_initiale(taille));
+            assert_eq!(aleatoire.rayon_asteroid(), rayon_pour(taille));
+        }
+        assert_eq!(
+            [1, 2, 3].map(resistance_initiale),
+            [1, 3, 5] // Valeurs d'origine du jeu.
+        );
+    }
+
+    #[test]
+    fn test_nombre_asteroides_niveau() {
+        assert_eq!(nombre_asteroides_niveau(1), 5);
+        assert_eq!(nombre_asteroides_niveau(3), 7);
+    }
+
+    #[test]
+    fn test_hors_ecran_vers_interieur() {
+        let ecran = vec2(800.0, 600.0);
+        for _ in 0..100 {
+            let asteroid = Asteroid::new_hors_ecran_vers_interieur(&mut thread_rng(), ecran);
+            let (position, vitesse) = (asteroid.get_position(), asteroid.transform.vitesse);
+            // L'astéroïde est en dehors de l'écran...
+            assert!(
+                position.x < 0.0
+                    || position.x > ecran.x
+                    || position.y < 0.0
+                    || position.y > ecran.y
+            );
+            // ... et sa vitesse le ramène vers l'intérieur.
+            if position.x < 0.0 {
+                assert!(vitesse.x > 0.0);
+            } else if position.x > ecran.x {
+                assert!(vitesse.x < 0.0);
+            } else if position.y < 0.0 {
+                assert!(vitesse.y > 0.0);
+            } else {
+                assert!(vitesse.y < 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_vitesse_suit_la_vitesse_actuelle() {
+        let mut asteroid = Asteroid::nouvel_asteroid(&mut thread_rng(), 2, vec2(100.0, 100.0));
+        asteroid.nouvelle_vitesse(vec2(3.0, -1.0)); // Par exemple après une impulsion du vaisseau.
+        assert_eq!(asteroid.get_vitesse(), vec2(3.0, -1.0));
+    }
+
+    /// Une impulsion trop forte est ramenée à la vitesse maximale, sans changer la direction de l'astéroïde.
+    #[test]
+    fn test_vitesse_max() {
+        let mut asteroid = Asteroid::scripté(2, vec2(100.0, 100.0), vec2(1.0, 0.0));
+        let impulsion = vec2(30.0, -40.0);
+        asteroid.nouvelle_vitesse(impulsion);
+        let vitesse = asteroid.get_vitesse();
+        assert!((vitesse.length() - constantes::VITESSE_MAX_ASTEROID).abs() < 1e-4);
+        assert!(vitesse.normalize().distance(impulsion.normalize()) < 1e-5);
+
+        // Le rebond et la création sont bornés de la même façon.
+        asteroid.rebondir(vec2(-1.0, 0.0));
+        assert!(asteroid.get_vitesse().length() <= constantes::VITESSE_MAX_ASTEROID + 1e-4);
+        let rapide = Asteroid::scripté(1, Vec2::ZERO, vec2(0.0, 100.0));
+        assert_eq!(
+            rapide.get_vitesse(),
+            vec2(0.0, constantes::VITESSE_MAX_ASTEROID)
+        );
+    }
+
+    #[test]
+    fn test_limite_nombre_asteroides() {
+        let mut asteroids = Vec::new();
+        let nouveaux = (0..MAX_ASTEROIDS - 1)
+            .map(|_| Asteroid::nouvel_asteroid(&mut thread_rng(), 1, Vec2::ZERO));
+        assert_eq!(
+            ajouter_asteroides(&mut asteroids, nouveaux),
+            MAX_ASTEROIDS - 1
+        );
+
+        // Il ne reste de la place que pour un astéroïde : les autres sont refusés.
+        let nouveaux = (0..3).map(|_| Asteroid::nouvel_asteroid(&mut thread_rng(), 1, Vec2::ZERO));
+        assert_eq!(ajouter_asteroides(&mut asteroids, nouveaux), 1);
+        assert_eq!(asteroids.len(), MAX_ASTEROIDS);
+
+        let nouveaux = (0..3).map(|_| Asteroid::nouvel_asteroid(&mut thread_rng(), 1, Vec2::ZERO));
+        assert_eq!(ajouter_asteroides(&mut asteroids, nouveaux), 0);
+        assert_eq!(asteroids.len(), MAX_ASTEROIDS);
+
+        assert_eq!(
+            generer_asteroides(&mut thread_rng(), MAX_ASTEROIDS + 50, vec2(800.0, 600.0)).len(),
+            MAX_ASTEROIDS
+        );
+    }
+
     #[test]
     fn test_est_detruit() {
-        let mut asteroid = Asteroid {
-            position: Vec2::new(0.0, 0.0),
-            speed: Vec2::new(0.0, 0.0),
-            speed_min: Vec2::new(0.0, 0.0),
-            taille: 1,
-            resistance: 1,
-        };
+        let mut asteroid = Asteroid::scripté(1, Vec2::new(0.0, 0.0), Vec2::new(0.0, 0.0));
         asteroid.diminuer_résistance();
         assert!(asteroid.est_détruit());
     }
+
+    /// Une copie d'un astéroïde lui est égale, mais la modifier ne change pas l'original.
+    #[test]
+    fn test_clone_independant() {
+        let original = Asteroid::nouvel_asteroid(&mut thread_rng(), 3, vec2(100.0, 100.0));
+        let mut copie = original.clone();
+        assert_eq!(copie, original);
+
+        copie.diminuer_résistance();
+        copie.nouvelle_vitesse(vec2(9.0, 9.0));
+        assert_ne!(copie, original);
+        assert_eq!(original.get_resistance(), 5);
+        assert_ne!(original.get_vitesse(), vec2(9.0, 9.0));
+    }
+
+    /// Avec un héritage total, le fragment part comme son parent ; sans héritage, sa vitesse est la vitesse aléatoire habituelle.
+    #[test]
+    fn test_fragment_heritage_vitesse() {
+        use ::rand::rngs::SmallRng;
+        use ::rand::SeedableRng;
+
+        let parent = Asteroid::scripté(3, Vec2::ZERO, vec2(1.5, -0.5));
+        let herite = Asteroid::fragment(
+            &mut thread_rng(),
+            2,
+            Vec2::ZERO,
+            &parent,
+            1.0,
+            Vec2::ZERO,
+            0.0,
+        );
+        assert!((herite.get_vitesse() - parent.get_vitesse()).length() < 1e-5);
+
+        let aleatoire = Asteroid::fragment(
+            &mut SmallRng::seed_from_u64(3),
+            2,
+            Vec2::ZERO,
+            &parent,
+            0.0,
+            Vec2::ZERO,
+            0.0,
+        );
+        let reference = Asteroid::nouvel_asteroid(&mut SmallRng::seed_from_u64(3), 2, Vec2::ZERO);
+        assert!((aleatoire.get_vitesse() - reference.get_vitesse()).length() < 1e-5);
+    }
+
+    /// Avec l'attraction du mode difficile, les fragments partent tous en direction du vaisseau.
+    #[test]
+    fn test_fragment_attire_par_vaisseau() {
+        use ::rand::rngs::SmallRng;
+        use ::rand::SeedableRng;
+
+        let parent = Asteroid::scripté(3, vec2(400.0, 300.0), vec2(-1.0, 0.5));
+        let vaisseau = vec2(600.0, 100.0);
+        for graine in 0..50 {
+            let fragment = Asteroid::fragment(
+                &mut SmallRng::seed_from_u64(graine),
+                2,
+                vec2(410.0, 300.0),
+                &parent,
+                0.5,
+                vaisseau,
+                0.7,
+            );
+            let vers_vaisseau = vaisseau - fragment.get_position();
+            assert!(fragment.get_vitesse().dot(vers_vaisseau) > 0.0);
+        }
+
+        // Avec une attraction totale, le fragment fonce droit sur le vaisseau.
+        let fragment = Asteroid::fragment(
+            &mut thread_rng(),
+            2,
+            vec2(400.0, 300.0),
+            &parent,
+            0.5,
+            vaisseau,
+            1.0,
+        );
+        let direction = fragment.get_vitesse().normalize();
+        assert!((direction - (vaisseau - vec2(400.0, 300.0)).normalize()).length() < 1e-5);
+    }
+
+    /// Chaque astéroïde créé reçoit un identifiant différent, qui ne change pas quand il bouge.
+    #[test]
+    fn test_identifiants_uniques() {
+        let mut rng = thread_rng();
+        let mut asteroid = Asteroid::nouvel_asteroid(&mut rng, 3, vec2(100.0, 100.0));
+        let fragment = Asteroid::fragment(
+            &mut rng,
+            2,
+            vec2(100.0, 100.0),
+            &asteroid,
+            0.5,
+            Vec2::ZERO,
+            0.0,
+        );
+        assert_ne!(asteroid.get_id(), fragment.get_id());
+
+        let id = asteroid.get_id();
+        asteroid.move_object(1.0, vec2(800.0, 600.0));
+        assert_eq!(asteroid.get_id(), id);
+    }
+
+    /// Un astéroïde scripté a exactement la taille, la position et la vitesse demandées.
+    #[test]
+    fn test_scripte() {
+        let asteroid = Asteroid::scripté(2, vec2(100.0, 200.0), vec2(0.5, -1.0));
+        assert_eq!(asteroid.get_taille(), 2);
+        assert_eq!(asteroid.get_resistance(), 3);
+        assert_eq!(asteroid.get_position(), vec2(100.0, 200.0));
+        assert_eq!(asteroid.get_vitesse(), vec2(0.5, -1.0));
+        assert!(!asteroid.est_blinde());
+    }
+
+    /// Une disposition écrite en texte donne les astéroïdes attendus, et une ligne mal formée est refusée.
+    #[test]
+    fn test_analyser_disposition() {
+        let texte = "# Tutoriel\n3 100 100 1 0\n\n1 400.5 300 0 -0.5\n";
+        assert_eq!(
+            analyser_disposition(texte),
+            Ok(vec![
+                Asteroid::scripté(3, vec2(100.0, 100.0), vec2(1.0, 0.0)),
+                Asteroid::scripté(1, vec2(400.5, 300.0), vec2(0.0, -0.5)),
+            ])
+        );
+
+        assert!(analyser_disposition("3 100 100 1").is_err());
+        assert!(analyser_disposition("4 100 100 1 0").is_err());
+        assert!(analyser_disposition("2 a 100 1 0").is_err());
+
+        // Le fichier du tutoriel fourni avec le jeu est valide.
+        let tutoriel = analyser_disposition(include_str!("../ressources/tutoriel.txt")).unwrap();
+        assert_eq!(tutoriel.len(), 3);
+    }
+
+    /// Les fragments d'un astéroïde qui tourne dans le sens horaire sont tous déviés dans ce sens.
+    #[test]
+    fn test_fragment_devie_par_rotation() {
+        use ::rand::rngs::SmallRng;
+        use ::rand::SeedableRng;
+
+        let immobile = Asteroid::scripté(3, Vec2::ZERO, vec2(1.0, 0.0));
+        let mut tournant = immobile.clone();
+        tournant.vitesse_rotation = Asteroid::ROTATION_MAX; // Sens horaire à l'écran (y vers le bas)
+
+        for graine in 0..20 {
+            let sans_rotation = Asteroid::fragment(
+                &mut SmallRng::seed_from_u64(graine),
+                2,
+                Vec2::ZERO,
+                &immobile,
+                0.5,
+                Vec2::ZERO,
+                0.0,
+            );
+            let avec_rotation = Asteroid::fragment(
+                &mut SmallRng::seed_from_u64(graine),
+                2,
+                Vec2::ZERO,
+                &tournant,
+                0.5,
+                Vec2::ZERO,
+                0.0,
+            );
+            // Angle (positif = sens horaire) entre la direction sans rotation et celle avec rotation.
+            let ecart = sans_rotation
+                .get_vitesse()
+                .angle_between(avec_rotation.get_vitesse());
+            assert!((ecart - Asteroid::ROTATION_MAX * Asteroid::DEVIATION_ROTATION).abs() < 1e-4);
+            assert_eq!(avec_rotation.vitesse_rotation, Asteroid::ROTATION_MAX); // Le fragment tourne comme son parent.
+        }
+    }
+
+    /// Seuls les astéroïdes moyens et grands se divisent.
+    #[test]
+    fn test_peut_se_diviser() {
+        let position = vec2(100.0, 100.0);
+        assert!(!Asteroid::scripté(1, position, Vec2::ZERO).peut_se_diviser());
+        assert!(Asteroid::scripté(2, position, Vec2::ZERO).peut_se_diviser());
+        assert!(Asteroid::scripté(3, position, Vec2::ZERO).peut_se_diviser());
+    }
+
+    /// La position prévue avance le long de la vitesse, proportionnellement à l'anticipation.
+    #[test]
+    fn test_position_predite() {
+        let asteroid = Asteroid::scripté(2, vec2(100.0, 200.0), vec2(1.5, -0.5));
+        assert_eq!(asteroid.position_predite(0.0), vec2(100.0, 200.0));
+        assert_eq!(asteroid.position_predite(60.0), vec2(190.0, 170.0));
+        let immobile = Asteroid::scripté(1, vec2(50.0, 50.0), Vec2::ZERO);
+        assert_eq!(immobile.position_predite(60.0), vec2(50.0, 50.0));
+    }
 }
@@ -0,0 +1,188 @@
+//! Module pour les sons du jeu.
+//! Ce module contient la structure `Audio`, qui charge les sons au lancement et les joue pendant la partie,
+//! en respectant le réglage `muet` des paramètres. Pour l'instant, le seul son est le bip de proximité :
+//! comme un radar de recul, il accélère quand l'astéroïde le plus proche se rapproche du vaisseau.
+//!
+//! Les sons sont générés par le jeu (pas de fichier à charger). Pour les entendre, le jeu doit être compilé
+//! avec la fonctionnalité `audio` (`cargo run --features audio`), qui active celle de macroquad. Sans elle,
+//! `Audio` ne joue aucun son, mais garde la même interface.
+
+#[cfg(feature = "audio")]
+use macroquad::audio::{load_sound_from_bytes, play_sound_once, Sound};
+
+/// Distance (entre le vaisseau et le bord de l'astéroïde le plus proche) en dessous de laquelle le bip se déclenche.
+pub const DISTANCE_ALERTE: f32 = 250.0;
+
+/// Intervalle (en secondes) entre deux bips quand l'astéroïde est au contact du vaisseau.
+pub const INTERVALLE_MIN: f32 = 0.08;
+
+/// Intervalle (en secondes) entre deux bips quand l'astéroïde arrive à la distance d'alerte.
+pub const INTERVALLE_MAX: f32 = 0.8;
+
+/// Fréquence (en Hz) du bip de proximité.
+#[cfg(feature = "audio")]
+const FREQUENCE_BIP: f32 = 880.0;
+
+/// Durée (en secondes) d'un bip.
+#[cfg(feature = "audio")]
+const DUREE_BIP: f32 = 0.05;
+
+/// Nombre d'échantillons par seconde des sons générés.
+#[cfg(feature = "audio")]
+const ECHANTILLONNAGE: u32 = 22_050;
+
+/// Calcule l'intervalle entre deux bips selon la distance de l'astéroïde le plus proche :
+/// plus il est près, plus les bips sont rapprochés.
+/// # Arguments
+/// - `distance` - Distance entre le vaisseau et le bord de l'astéroïde le plus proche (négative s'ils se touchent).
+/// # Retourne `None` si l'astéroïde est au-delà de `DISTANCE_ALERTE` (pas de bip).
+pub fn intervalle_bip(distance: f32) -> Option<f32> {
+    if distance >= DISTANCE_ALERTE {
+        return None;
+    }
+    let proportion = (distance / DISTANCE_ALERTE).clamp(0.0, 1.0); // 0 = au contact, 1 = à la distance d'alerte
+    Some(INTERVALLE_MIN + (INTERVALLE_MAX - INTERVALLE_MIN) * proportion)
+}
+
+/// Génère un fichier WAV (mono, 16 bits) contenant un bip sinusoïdal, qui s'estompe pour ne pas claquer.
+/// # Arguments
+/// - `frequence` - Fréquence du bip (en Hz).
+/// - `duree` - Durée du bip (en secondes).
+#[cfg(feature = "audio")]
+fn generer_wav_bip(frequence: f32, duree: f32) -> Vec<u8> {
+    let nombre = (ECHANTILLONNAGE as f32 * duree) as u32;
+    let taille_donnees = nombre * 2;
+    let mut wav = Vec::with_capacity(44 + taille_donnees as usize);
+    // En-tête RIFF / WAVE.
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + taille_donnees).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes()); // Taille du bloc "fmt "
+    wav.extend_from_slice(&1u16.to_le_bytes()); // Format PCM
+    wav.extend_from_slice(&1u16.to_le_bytes()); // Mono
+    wav.extend_from_slice(&ECHANTILLONNAGE.to_le_bytes());
+    wav.extend_from_slice(&(ECHANTILLONNAGE * 2).to_le_bytes()); // Octets par seconde
+    wav.extend_from_slice(&2u16.to_le_bytes()); // Octets par échantillon
+    wav.extend_from_slice(&16u16.to_le_bytes()); // Bits par échantillon
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&taille_donnees.to_le_bytes());
+    for i in 0..nombre {
+        let t = i as f32 / ECHANTILLONNAGE as f32;
+        let attenuation = 1.0 - i as f32 / nombre as f32;
+        let valeur = (t * frequence * std::f32::consts::TAU).sin() * attenuation * 0.5;
+        wav.extend_from_slice(&((valeur * i16::MAX as f32) as i16).to_le_bytes());
+    }
+    wav
+}
+
+/// Sons du jeu, chargés une seule fois au lancement.
+pub struct Audio {
+    muet: bool, // Si vrai, aucun son n'est joué
+    #[cfg(feature = "audio")]
+    bip: Option<Sound>, // Bip de proximité (`None` s'il n'a pas pu être chargé)
+    dernier_bip: Option<f64>, // Moment (en secondes) du dernier bip joué
+}
+
+impl Audio {
+    /// Charge les sons du jeu. Si le jeu est muet, ou compilé sans la fonctionnalité `audio`, rien n'est chargé.
+    /// # Arguments
+    /// - `muet` - Réglage `muet` des paramètres.
+    pub async fn charger(muet: bool) -> Self {
+        Self {
+            muet,
+            #[cfg(feature = "audio")]
+            bip: if muet {
+                None
+            } else {
+                load_sound_from_bytes(&generer_wav_bip(FREQUENCE_BIP, DUREE_BIP))
+                    .await
+                    .ok()
+            },
+            dernier_bip: None,
+        }
+    }
+
+    /// Joue le bip de proximité si l'intervalle correspondant à la distance est écoulé depuis le dernier bip.
+    /// # Arguments
+    /// - `distance` - Distance entre le vaisseau et le bord de l'astéroïde le plus proche (`None` s'il n'y en a aucun).
+    /// - `temps` - Temps actuel en secondes (`get_time()`).
+    pub fn bip_proximite(&mut self, distance: Option<f32>, temps: f64) {
+        let Some(intervalle) = distance.and_then(intervalle_bip) else {
+            return;
+        };
+        if !self.muet
+            && self
+                .dernier_bip
+                .is_none_or(|dernier| temps - dernier >= intervalle as f64)
+        {
+            self.jouer_bip();
+            self.dernier_bip = Some(temps);
+        }
+    }
+
+    /// Joue le bip de proximité, s'il a été chargé.
+    #[cfg(feature = "audio")]
+    fn jouer_bip(&self) {
+        if let Some(bip) = &self.bip {
+            play_sound_once(bip);
+        }
+    }
+
+    /// Sans la fonctionnalité `audio`, aucun son n'est joué.
+    #[cfg(not(feature = "audio"))]
+    fn jouer_bip(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Plus l'astéroïde se rapproche, plus les bips sont rapprochés ; au-delà de la distance d'alerte, plus de bip.
+    #[test]
+    fn test_intervalle_decroit_avec_la_distance() {
+        let intervalles: Vec<f32> = [240.0, 200.0, 150.0, 100.0, 50.0, 10.0, 0.0]
+            .into_iter()
+            .map(|distance| intervalle_bip(distance).unwrap())
+            .collect();
+        assert!(intervalles.windows(2).all(|paire| paire[1] < paire[0]));
+        assert_eq!(intervalle_bip(0.0), Some(INTERVALLE_MIN));
+        assert_eq!(intervalle_bip(-5.0), Some(INTERVALLE_MIN)); // Astéroïde au contact.
+        assert_eq!(intervalle_bip(DISTANCE_ALERTE), None);
+        assert_eq!(intervalle_bip(1000.0), None);
+    }
+
+    /// Un bip est joué dès qu'un astéroïde est assez proche, puis pas avant la fin de son intervalle ;
+    /// jamais si le jeu est muet.
+    #[test]
+    fn test_rythme_bips() {
+        let nouvel_audio = |muet| Audio {
+            muet,
+            #[cfg(feature = "audio")]
+            bip: None,
+            dernier_bip: None,
+        };
+        let mut audio = nouvel_audio(false);
+        audio.bip_proximite(Some(1000.0), 0.0);
+        assert_eq!(audio.dernier_bip, None);
+        audio.bip_proximite(Some(0.0), 1.0);
+        assert_eq!(audio.dernier_bip, Some(1.0));
+        audio.bip_proximite(Some(0.0), 1.0 + INTERVALLE_MIN as f64 / 2.0);
+        assert_eq!(audio.dernier_bip, Some(1.0));
+        audio.bip_proximite(Some(0.0), 1.1);
+        assert_eq!(audio.dernier_bip, Some(1.1));
+
+        let mut audio = nouvel_audio(true);
+        audio.bip_proximite(Some(0.0), 1.0);
+        assert_eq!(audio.dernier_bip, None);
+    }
+
+    #[cfg(feature = "audio")]
+    #[test]
+    fn test_wav_bip() {
+        let wav = generer_wav_bip(FREQUENCE_BIP, DUREE_BIP);
+        assert_eq!(&wav[0..4], b"RIFF");
+        assert_eq!(&wav[8..12], b"WAVE");
+        let echantillons = (ECHANTILLONNAGE as f32 * DUREE_BIP) as usize;
+        assert_eq!(wav.len(), 44 + echantillons * 2);
+    }
+}
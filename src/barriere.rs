@@ -0,0 +1,177 @@
+//! Module pour les barrières déployées par le joueur.
+//! Ce module contient la structure `Barriere` : un court segment placé devant le vaisseau (touche B), sur lequel
+//! les astéroïdes rebondissent comme sur un mur, jusqu'à ce que la barrière disparaisse.
+
+use macroquad::prelude::*;
+
+/// Barrière déployée par le joueur : un segment sur lequel rebondissent les astéroïdes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Barriere {
+    debut: Vec2,         // Première extrémité du segment
+    fin: Vec2,           // Seconde extrémité du segment
+    duree_restante: f32, // Temps (en secondes) avant que la barrière disparaisse
+}
+
+impl Barriere {
+    /// Longueur (en pixels) d'une barrière déployée par le joueur.
+    pub const LONGUEUR: f32 = 120.0;
+
+    /// Distance (en pixels) entre le vaisseau et le milieu de la barrière qu'il déploie.
+    pub const DISTANCE: f32 = 50.0;
+
+    /// Durée de vie (en secondes) d'une barrière déployée par le joueur.
+    pub const DUREE: f32 = 6.0;
+
+    /// Épaisseur (en pixels) de la barrière, pour le dessin comme pour les rebonds.
+    pub const EPAISSEUR: f32 = 4.0;
+
+    /// Crée une barrière.
+    /// # Arguments
+    /// - `debut` - Première extrémité du segment.
+    /// - `fin` - Seconde extrémité du segment.
+    /// - `duree` - Durée de vie de la barrière (en secondes).
+    pub fn new(debut: Vec2, fin: Vec2, duree: f32) -> Self {
+        Self {
+            debut,
+            fin,
+            duree_restante: duree,
+        }
+    }
+
+    /// Crée la barrière déployée par le joueur : perpendiculaire à la direction du vaisseau, juste devant lui.
+    /// # Arguments
+    /// - `position` - Position du vaisseau.
+    /// - `rotation` - Orientation du vaisseau (en radians).
+    pub fn deployer(position: Vec2, rotation: f32) -> Self {
+        let direction = Vec2::from_angle(rotation);
+        let milieu = position + direction * Self::DISTANCE;
+        let demi_segment = direction.perp() * Self::LONGUEUR / 2.0;
+        Self::new(milieu - demi_segment, milieu + demi_segment, Self::DUREE)
+    }
+
+    /// Indique si la barrière a disparu.
+    pub fn est_expiree(&self) -> bool {
+        self.duree_restante <= 0.0
+    }
+
+    /// Fait vieillir la barrière.
+    /// # Arguments
+    /// - `delta_time` - Temps écoulé (en secondes) depuis la dernière frame.
+    pub fn avancer(&mut self, delta_time: f32) {
+        self.duree_restante = (self.duree_restante - delta_time).max(0.0);
+    }
+
+    /// Retourne le point du segment le plus proche du point donné.
+    pub fn point_le_plus_proche(&self, point: Vec2) -> Vec2 {
+        let segment = self.fin - self.debut;
+        let longueur_carree = segment.length_squared();
+        if longueur_carree == 0.0 {
+            return self.debut;
+        }
+        let t = ((point - self.debut).dot(segment) / longueur_carree).clamp(0.0, 1.0);
+        self.debut + segment * t
+    }
+
+    /// Calcule la vitesse d'un astéroïde qui rebondit sur la barrière : la vitesse est réfléchie par rapport
+    /// à la normale au point de contact (la normale du segment, ou la direction de l'extrémité touchée).
+    /// # Arguments
+    /// - `centre` - Centre de l'astéroïde.
+    /// - `rayon` - Rayon de l'astéroïde.
+    /// - `vitesse` - Vitesse de l'astéroïde.
+    /// # Retourne `None` si l'astéroïde ne touche pas la barrière, ou s'il s'en éloigne déjà (il vient de rebondir).
+    pub fn reflechir(&self, centre: Vec2, rayon: f32, vitesse: Vec2) -> Option<Vec2> {
+        if self.est_expiree() {
+            return None;
+        }
+        let ecart = centre - self.point_le_plus_proche(centre);
+        if ecart.length() >= rayon + Self::EPAISSEUR / 2.0 {
+            return None;
+        }
+        // Si le centre est exactement sur le segment, on prend la normale du segment face à l'astéroïde.
+        let normale = ecart.try_normalize().unwrap_or_else(|| {
+            let normale = (self.fin - self.debut).perp().normalize_or_zero();
+            if vitesse.dot(normale) > 0.0 {
+                -normale
+            } else {
+                normale
+            }
+        });
+        let approche = vitesse.dot(normale);
+        if approche >= 0.0 {
+            return None;
+        }
+        Some(vitesse - 2.0 * approche * normale)
+    }
+
+    /// Dessine la barrière, de plus en plus pâle à mesure qu'elle disparaît.
+    pub fn draw(&self) {
+        if self.est_expiree() {
+            return;
+        }
+        let alpha = (self.duree_restante / Self::DUREE).min(1.0);
+        draw_line(
+            self.debut.x,
+            self.debut.y,
+            self.fin.x,
+            self.fin.y,
+            Self::EPAISSEUR,
+            Color::new(1.0, 0.8, 0.2, 0.9 * alpha),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Un astéroïde qui arrive en biais sur la barrière repart avec le même angle, de l'autre côté de la normale.
+    #[test]
+    fn test_reflexion_en_biais() {
+        let barriere = Barriere::new(vec2(300.0, 300.0), vec2(500.0, 300.0), 5.0);
+        let vitesse = vec2(2.0, 1.0); // Descend vers la barrière horizontale.
+        let reflechie = barriere.reflechir(vec2(400.0, 285.0), 20.0, vitesse);
+        assert_eq!(reflechie, Some(vec2(2.0, -1.0)));
+
+        // Trop loin, ou en train de s'éloigner : pas de rebond.
+        assert_eq!(barriere.reflechir(vec2(400.0, 250.0), 20.0, vitesse), None);
+        assert_eq!(
+            barriere.reflechir(vec2(400.0, 285.0), 20.0, vec2(2.0, -1.0)),
+            None
+        );
+        // À côté du segment, hors de portée de ses extrémités.
+        assert_eq!(barriere.reflechir(vec2(540.0, 300.0), 20.0, vitesse), None);
+    }
+
+    /// Sur une extrémité, l'astéroïde rebondit dans la direction qui l'éloigne de ce point.
+    #[test]
+    fn test_reflexion_extremite() {
+        let barriere = Barriere::new(vec2(300.0, 300.0), vec2(500.0, 300.0), 5.0);
+        let reflechie = barriere
+            .reflechir(vec2(510.0, 300.0), 20.0, vec2(-3.0, 0.0))
+            .unwrap();
+        assert!(reflechie.distance(vec2(3.0, 0.0)) < 1e-5);
+    }
+
+    /// La barrière déployée est perpendiculaire au vaisseau, devant lui ; elle disparaît après sa durée de vie.
+    #[test]
+    fn test_deployer_et_expiration() {
+        let mut barriere = Barriere::deployer(vec2(400.0, 300.0), 0.0);
+        assert!(
+            barriere
+                .point_le_plus_proche(vec2(400.0, 300.0))
+                .distance(vec2(450.0, 300.0))
+                < 1e-3
+        );
+        assert!((barriere.debut.distance(barriere.fin) - Barriere::LONGUEUR).abs() < 1e-3);
+        let asteroide = vec2(460.0, 300.0);
+        assert!(barriere
+            .reflechir(asteroide, 20.0, vec2(-1.0, 0.0))
+            .is_some());
+
+        barriere.avancer(Barriere::DUREE - 1.0);
+        assert!(!barriere.est_expiree());
+        barriere.avancer(2.0);
+        assert!(barriere.est_expiree());
+        assert_eq!(barriere.reflechir(asteroide, 20.0, vec2(-1.0, 0.0)), None);
+    }
+}
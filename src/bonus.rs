@@ -1,32 +1,152 @@
 //! Module représentant le bonus de bouclier dans le jeu Asteroids.
 //! Ce fichier contient la structure `Bonus` et les méthodes associées permettant de gérer
-//! l'apparition, l'affichage, la mise à jour, et la collision du bonus de bouclier avec le vaisseau.
+//! l'apparition, l'affichage, la mise à jour, et la collision du bonus de bouclier avec le vaisseau,
+//! ainsi que `GestionnaireBonus`, qui gère les bonus présents en même temps à l'écran.
+//! Un bonus rare, l'aimant, attire tous les bonus vers le vaisseau et repousse les astéroïdes proches
+//! pendant quelques secondes (voir `repulsion_aimant`).
 
+use ::rand::Rng;
 use macroquad::prelude::*;
 
 /// Structure représentant le Bonus qui s'affiche à l'écran et qui remet le bouclier à 100%.
 /// Ce 'Bonus' a une position, un timer car il ne reste que quelques secondes à l'écran,
 /// ainsi qu'un booléen qui permet de dire s'il est visible ou pas.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Bonus {
     position: Vec2,
     visible: bool,
-    timer: f32, // Temps restant avant que le bonus disparaisse
+    timer: f32,      // Temps restant avant que le bonus disparaisse
+    surcharge: bool, // Bonus rare : surcharge le bouclier au-delà de 100% au lieu de le remettre à 100%
+    aimant: bool,    // Bonus rare : active l'aimant au lieu de recharger le bouclier
+}
+
+/// Réglages de l'apparition et de la durée du bonus.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigBonus {
+    /// Active les bonus de bouclier (`false` = défi sans bonus).
+    pub actifs: bool,
+    /// Nombre moyen de tirages par seconde pour savoir si un bonus peut apparaître.
+    pub frequence_tirage: f32,
+    /// Probabilité qu'un tirage fasse apparaître le bonus.
+    pub chance_apparition: f32,
+    /// Probabilité qu'un tirage fasse apparaître le bonus quand le bouclier est faible.
+    pub chance_bouclier_faible: f32,
+    /// En dessous de ce pourcentage, le bouclier est considéré comme faible.
+    pub seuil_bouclier_faible: u8,
+    /// Durée minimale (en secondes) pendant laquelle le bonus reste visible.
+    pub duree_min: f32,
+    /// Durée maximale (en secondes) pendant laquelle le bonus reste visible.
+    pub duree_max: f32,
+    /// Durée ajoutée quand le bonus apparaît alors que le bouclier est faible.
+    pub duree_bouclier_faible: f32,
+    /// Probabilité qu'un bonus qui apparaît soit un bonus de surcharge.
+    pub chance_surcharge: f32,
+    /// Probabilité qu'un bonus qui apparaît (et qui n'est pas une surcharge) soit un aimant (0.0 = jamais).
+    /// Avec 0.0, aucun tirage n'est fait pour l'aimant : les parties enregistrées avant l'aimant se rejouent
+    /// à l'identique.
+    pub chance_aimant: f32,
+    /// Nombre maximal de bonus visibles en même temps à l'écran.
+    pub max_simultanes: usize,
+}
+
+impl Default for ConfigBonus {
+    /// Réglages d'origine : environ un tirage toutes les 30 secondes, 10% de chance (20% si le bouclier est sous 30%),
+    /// et un bonus visible 5 à 10 secondes (10 à 15 si le bouclier est faible).
+    fn default() -> Self {
+        Self {
+            actifs: true,
+            frequence_tirage: 5.0 / 150.0,
+            chance_apparition: 0.1,
+            chance_bouclier_faible: 0.2,
+            seuil_bouclier_faible: 30,
+            duree_min: 5.0,
+            duree_max: 10.0,
+            duree_bouclier_faible: 5.0,
+            chance_surcharge: 0.1,
+            chance_aimant: 0.1,
+            max_simultanes: 1,
+        }
+    }
+}
+
+/// Distance minimale entre le vaisseau et un bonus qui apparaît, pour qu'il ne soit pas ramassé instantanément.
+pub const DISTANCE_MIN_VAISSEAU: f32 = 100.0;
+
+/// Nombre maximal de tirages pour trouver une position assez loin du vaisseau.
+const ESSAIS_POSITION: usize = 100;
+
+/// Distance minimale (en pixels) entre un bonus qui apparaît et les bords de l'écran.
+const MARGE_BORDS: f32 = 50.0;
+
+/// Tire au sort une coordonnée le long d'un côté de l'écran, à au moins `MARGE_BORDS` de ses extrémités.
+/// Sur un côté trop court pour garder cette marge, la coordonnée est celle du milieu.
+/// # Arguments
+/// - `rng` - Générateur aléatoire.
+/// - `longueur` - Longueur du côté de l'écran.
+fn coordonnee_aleatoire(rng: &mut impl Rng, longueur: f32) -> f32 {
+    if longueur > 2.0 * MARGE_BORDS {
+        rng.gen_range(MARGE_BORDS..longueur - MARGE_BORDS)
+    } else {
+        longueur / 2.0
+    }
+}
+
+/// Durée (en secondes) de l'effet d'un bonus aimant.
+pub const DUREE_AIMANT: f32 = 4.0;
+
+/// Distance en dessous de laquelle l'aimant repousse les astéroïdes.
+pub const RAYON_AIMANT: f32 = 250.0;
+
+/// Vitesse (en pixels par frame) à laquelle l'aimant attire les bonus vers le vaisseau.
+pub const VITESSE_ATTRACTION_AIMANT: f32 = 6.0;
+
+/// Accélération (en pixels par frame, à chaque frame) donnée par l'aimant aux astéroïdes tout près du vaisseau.
+/// Elle diminue avec la distance et s'annule à `RAYON_AIMANT`.
+pub const FORCE_REPULSION_AIMANT: f32 = 0.3;
+
+/// Calcule l'accélération donnée par l'aimant à un astéroïde : il est repoussé à l'opposé du vaisseau,
+/// d'autant plus fort qu'il en est proche.
+/// # Arguments
+/// - `position_vaisseau` - Position du vaisseau (centre de l'aimant).
+/// - `position` - Position de l'astéroïde.
+/// # Retourne une accélération nulle au-delà de `RAYON_AIMANT` ou si l'astéroïde est exactement sur le vaisseau.
+pub fn repulsion_aimant(position_vaisseau: Vec2, position: Vec2) -> Vec2 {
+    let ecart = position - position_vaisseau;
+    let distance = ecart.length();
+    if distance >= RAYON_AIMANT {
+        return Vec2::ZERO;
+    }
+    ecart.normalize_or_zero() * FORCE_REPULSION_AIMANT * (1.0 - distance / RAYON_AIMANT)
 }
 
 impl Bonus {
-    /// Méthode pour créer un nouveau bonus avec une position aléatoire.
-    pub fn nouveau_bonus() -> Self {
+    /// Méthode pour créer un nouveau bonus avec une position aléatoire, loin du vaisseau.
+    /// # Arguments
+    /// - `rng`: Générateur aléatoire.
+    /// - `ecran`: Dimensions de l'écran (largeur, hauteur).
+    /// - `position_vaisseau`: Position actuelle du vaisseau.
+    pub fn nouveau_bonus(rng: &mut impl Rng, ecran: Vec2, position_vaisseau: Vec2) -> Self {
         Self {
-            position: vec2(
-                rand::gen_range(50.0, screen_width() - 50.0), // Génération aléatoire de la position
-                rand::gen_range(50.0, screen_height() - 50.0),
-            ),
+            position: Self::position_aleatoire(rng, ecran, position_vaisseau), // Génération aléatoire de la position
             visible: false,
             timer: 0.0,
+            surcharge: false,
+            aimant: false,
         }
     }
 
+    /// Indique si le bonus surcharge le bouclier (au lieu de simplement le remettre à 100%).
+    pub fn est_surcharge(&self) -> bool {
+        self.surcharge
+    }
+
+    /// Indique si le bonus est un aimant (au lieu de recharger le bouclier).
+    pub fn est_aimant(&self) -> bool {
+        self.aimant
+    }
+
     /// Dessine le bonus à l'écran uniquement s'il est visible.
+    /// Un bonus de surcharge est teinté en doré, un aimant en magenta.
     /// # Arguments:
     /// - 'texture': Utilise la texture (image bouclier) pour la dessiner.
     pub fn draw_bonus(&self, texture: &Texture2D) {
@@ -35,7 +155,13 @@ impl Bonus {
                 texture,
                 self.position.x - 15.0, // -15.0 pour placer le début de l'image sur le périmètre du cercle et non pas au centre.
                 self.position.y - 15.0, // -15.0 car le rayon est de 15.0px pour le bonus.
-                WHITE,
+                if self.surcharge {
+                    GOLD
+                } else if self.aimant {
+                    MAGENTA
+                } else {
+                    WHITE
+                },
                 DrawTextureParams {
                     dest_size: Some(vec2(30.0, 30.0)), // Redimensionner l'image pour correspondre à un rayon de 15.0
                     ..Default::default()
@@ -47,9 +173,26 @@ impl Bonus {
     /// Met à jour le timer du bonus, gère son apparition aléatoire et sa disparition après un certain temps.
     ///
     /// # Arguments
+    /// - `rng`: Générateur aléatoire utilisé pour l'apparition du bonus.
     /// - `delta_time`: Le temps écoulé depuis la dernière mise à jour.
     /// - `bouclier`: Le niveau actuel du bouclier du vaisseau.
-    pub fn update_bonus(&mut self, delta_time: f32, bouclier: u8) {
+    /// - `position_vaisseau`: Position actuelle du vaisseau, le bonus apparaît loin de lui.
+    /// - `ecran`: Dimensions de l'écran (largeur, hauteur).
+    /// - `config`: Réglages du bonus. S'ils sont désactivés, le bonus n'apparaît jamais et ne peut pas être ramassé.
+    pub fn update_bonus(
+        &mut self,
+        rng: &mut impl Rng,
+        delta_time: f32,
+        bouclier: u8,
+        position_vaisseau: Vec2,
+        ecran: Vec2,
+        config: &ConfigBonus,
+    ) {
+        if !config.actifs {
+            self.visible = false;
+            return;
+        }
+
         // Si le bonus est visible la variable avec le timer va diminuer chaque seconde.
         // Dès qu'on atteint 0, le bonus devient invisible à nouveau
         if self.visible {
@@ -57,35 +200,49 @@ impl Bonus {
             if self.timer <= 0.0 {
                 self.visible = false; // Disparition après un certain temps
             }
-        } else {
-            // Générer aléatoirement l'apparition du bonus toutes les 60 secondes
-            if rand::gen_range(0.0, 150.0) < delta_time * 5.0 {
-                // Chance accrue si le bouclier est en dessous de 30%
-                if bouclier < 30 {
-                    if rand::gen_range(0.0, 1.0) < 0.2 {
-                        // 20% de chances quand le bouclier est inférieur à 30%
-                        self.position = vec2(
-                            rand::gen_range(50.0, screen_width() - 50.0),
-                            rand::gen_range(50.0, screen_height() - 50.0),
-                        );
-                        self.visible = true;
-                        self.timer = rand::gen_range(10.0, 15.0); // Durée de 10-15 secondes
-                    }
+        } else if rng.gen_range(0.0..1.0) < delta_time * config.frequence_tirage {
+            // Chance accrue si le bouclier est faible, et le bonus reste alors plus longtemps.
+            let bouclier_faible = bouclier < config.seuil_bouclier_faible;
+            let (chance, duree_en_plus) = if bouclier_faible {
+                (config.chance_bouclier_faible, config.duree_bouclier_faible)
+            } else {
+                (config.chance_apparition, 0.0)
+            };
+            if rng.gen_range(0.0..1.0) < chance {
+                self.position = Self::position_aleatoire(rng, ecran, position_vaisseau);
+                self.visible = true;
+                // Durée tirée au sort entre la durée minimale et la durée maximale.
+                let duree = if config.duree_max > config.duree_min {
+                    rng.gen_range(config.duree_min..config.duree_max)
                 } else {
-                    if rand::gen_range(0, 10) == 0 {
-                        // 10% de chance toutes les 60s.
-                        self.position = vec2(
-                            rand::gen_range(50.0, screen_width() - 50.0),
-                            rand::gen_range(50.0, screen_height() - 50.0),
-                        );
-                        self.visible = true;
-                        self.timer = rand::gen_range(5.0, 10.0); // Durée de 5-10 secondes
-                    }
-                }
+                    config.duree_min
+                };
+                self.timer = duree + duree_en_plus;
+                self.surcharge = rng.gen_range(0.0..1.0) < config.chance_surcharge;
+                // Pas de tirage sans aimant (voir `ConfigBonus::chance_aimant`).
+                self.aimant = !self.surcharge
+                    && config.chance_aimant > 0.0
+                    && rng.gen_range(0.0..1.0) < config.chance_aimant;
             }
         }
     }
 
+    /// Tire au sort une position pour le bonus, à au moins `MARGE_BORDS` des bords de l'écran (au milieu d'un côté
+    /// trop court) et à au moins `DISTANCE_MIN_VAISSEAU` du vaisseau (on retire au sort si besoin).
+    fn position_aleatoire(rng: &mut impl Rng, ecran: Vec2, position_vaisseau: Vec2) -> Vec2 {
+        let mut position = Vec2::ZERO;
+        for _ in 0..ESSAIS_POSITION {
+            position = vec2(
+                coordonnee_aleatoire(rng, ecran.x),
+                coordonnee_aleatoire(rng, ecran.y),
+            );
+            if position.distance(position_vaisseau) >= DISTANCE_MIN_VAISSEAU {
+                break;
+            }
+        }
+        position
+    }
+
     /// Vérifie s'il y a collision entre le bonus et le vaisseau.
     ///
     /// # Arguments
@@ -108,3 +265,273 @@ impl Bonus {
         false
     }
 }
+
+/// Gère les bonus présents à l'écran : ils apparaissent tant que leur nombre ne dépasse pas `max_simultanes`.
+pub struct GestionnaireBonus {
+    visibles: Vec<Bonus>, // Bonus actuellement à l'écran
+    candidat: Bonus,      // Bonus caché qui sert aux tirages d'apparition
+}
+
+impl GestionnaireBonus {
+    /// Crée un gestionnaire sans aucun bonus à l'écran.
+    /// # Arguments
+    /// - `rng`: Générateur aléatoire.
+    /// - `ecran`: Dimensions de l'écran (largeur, hauteur).
+    /// - `position_vaisseau`: Position actuelle du vaisseau.
+    pub fn new(rng: &mut impl Rng, ecran: Vec2, position_vaisseau: Vec2) -> Self {
+        Self {
+            visibles: Vec::new(),
+            candidat: Bonus::nouveau_bonus(rng, ecran, position_vaisseau),
+        }
+    }
+
+    /// Remet le gestionnaire dans son état de départ : plus aucun bonus à l'écran, et un candidat neuf
+    /// (invisible, timer à zéro). Utilisé quand on recommence une partie.
+    /// Les arguments sont ceux de `GestionnaireBonus::new`.
+    pub fn reinitialiser(&mut self, rng: &mut impl Rng, ecran: Vec2, position_vaisseau: Vec2) {
+        *self = Self::new(rng, ecran, position_vaisseau);
+    }
+
+    /// Met à jour les bonus visibles (disparition après leur durée), puis tente d'en faire apparaître un nouveau
+    /// si le nombre maximal de bonus simultanés n'est pas atteint.
+    /// Les arguments sont ceux de `Bonus::update_bonus`.
+    pub fn update(
+        &mut self,
+        rng: &mut impl Rng,
+        delta_time: f32,
+        bouclier: u8,
+        position_vaisseau: Vec2,
+        ecran: Vec2,
+        config: &ConfigBonus,
+    ) {
+        for bonus in self.visibles.iter_mut() {
+            bonus.update_bonus(rng, delta_time, bouclier, position_vaisseau, ecran, config);
+        }
+        self.visibles.retain(|bonus| bonus.visible);
+
+        if self.visibles.len() < config.max_simultanes {
+            self.candidat
+                .update_bonus(rng, delta_time, bouclier, position_vaisseau, ecran, config);
+            if self.candidat.visible {
+                self.visibles.push(self.candidat.clone());
+                self.candidat.visible = false;
+            }
+        }
+    }
+
+    /// Retourne le nombre de bonus actuellement à l'écran.
+    pub fn nombre_visibles(&self) -> usize {
+        self.visibles.len()
+    }
+
+    /// Dessine tous les bonus visibles.
+    pub fn draw(&self, texture: &Texture2D) {
+        for bonus in &self.visibles {
+            bonus.draw_bonus(texture);
+        }
+    }
+
+    /// Rapproche tous les bonus visibles du vaisseau (effet de l'aimant), sans le dépasser.
+    /// # Arguments
+    /// - `position_vaisseau` - Position du vaisseau.
+    /// - `vitesse` - Distance parcourue par chaque bonus pendant cette frame.
+    pub fn attirer(&mut self, position_vaisseau: Vec2, vitesse: f32) {
+        for bonus in self.visibles.iter_mut() {
+            bonus.position = bonus.position.move_towards(position_vaisseau, vitesse);
+        }
+    }
+
+    /// Ramasse le premier bonus touché par le vaisseau, qui disparaît de l'écran.
+    /// # Retourne le bonus ramassé, ou `None` si le vaisseau n'en touche aucun.
+    pub fn ramasser(&mut self, position_vaisseau: Vec2, rayon_vaisseau: f32) -> Option<Bonus> {
+        let index = self
+            .visibles
+            .iter_mut()
+            .position(|bonus| bonus.verifier_collision(position_vaisseau, rayon_vaisseau))?;
+        Some(self.visibles.remove(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::rand::rngs::SmallRng;
+    use ::rand::SeedableRng;
+
+    const ECRAN: Vec2 = vec2(800.0, 600.0);
+
+    /// Sans bonus, le bonus n'apparaît jamais, quels que soient les tirages, et ne peut pas être ramassé.
+    #[test]
+    fn test_bonus_desactives() {
+        let config = ConfigBonus {
+            actifs: false,
+            ..Default::default()
+        };
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut bonus = Bonus::nouveau_bonus(&mut rng, ECRAN, ECRAN / 2.0);
+        for _ in 0..10_000 {
+            bonus.update_bonus(&mut rng, 10.0, 0, ECRAN / 2.0, ECRAN, &config);
+            assert!(!bonus.visible);
+        }
+        assert!(!bonus.verifier_collision(bonus.position, 15.0));
+    }
+
+    /// Avec les bonus activés, les mêmes conditions finissent par faire apparaître le bonus.
+    #[test]
+    fn test_bonus_actives() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut bonus = Bonus::nouveau_bonus(&mut rng, ECRAN, ECRAN / 2.0);
+        let apparu = (0..10_000).any(|_| {
+            bonus.update_bonus(
+                &mut rng,
+                10.0,
+                0,
+                ECRAN / 2.0,
+                ECRAN,
+                &ConfigBonus::default(),
+            );
+            bonus.visible
+        });
+        assert!(apparu);
+    }
+
+    /// Les positions tirées au sort sont toujours assez loin du vaisseau, même près d'un bord.
+    #[test]
+    fn test_position_loin_du_vaisseau() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        for vaisseau in [ECRAN / 2.0, vec2(60.0, 60.0), vec2(740.0, 300.0)] {
+            for _ in 0..1000 {
+                let position = Bonus::position_aleatoire(&mut rng, ECRAN, vaisseau);
+                assert!(position.distance(vaisseau) >= DISTANCE_MIN_VAISSEAU);
+            }
+        }
+    }
+
+    /// Sur un écran minuscule, le bonus apparaît au milieu du côté trop court au lieu de faire paniquer le tirage.
+    #[test]
+    fn test_position_petit_ecran() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let position = Bonus::position_aleatoire(&mut rng, vec2(80.0, 100.0), Vec2::ZERO);
+        assert_eq!(position, vec2(40.0, 50.0));
+        let position = Bonus::position_aleatoire(&mut rng, vec2(800.0, 60.0), Vec2::ZERO);
+        assert!(position.x >= MARGE_BORDS && position.x < 800.0 - MARGE_BORDS);
+        assert_eq!(position.y, 30.0);
+    }
+
+    /// Avec une chance d'apparition forcée, le bonus apparaît dès la première mise à jour,
+    /// pour une durée comprise dans l'intervalle réglé.
+    #[test]
+    fn test_config_bonus() {
+        let config = ConfigBonus {
+            frequence_tirage: 1000.0,
+            chance_apparition: 1.0,
+            duree_min: 2.0,
+            duree_max: 3.0,
+            ..Default::default()
+        };
+        let mut rng = SmallRng::seed_from_u64(7);
+        for _ in 0..100 {
+            let mut bonus = Bonus::nouveau_bonus(&mut rng, ECRAN, ECRAN / 2.0);
+            bonus.update_bonus(&mut rng, 0.016, 100, ECRAN / 2.0, ECRAN, &config);
+            assert!(bonus.visible);
+            assert!(bonus.timer >= 2.0 && bonus.timer < 3.0);
+        }
+    }
+
+    /// Réglages qui font apparaître un bonus à chaque mise à jour, et qui le laissent longtemps à l'écran.
+    fn config_apparitions_forcees(max_simultanes: usize) -> ConfigBonus {
+        ConfigBonus {
+            frequence_tirage: 1000.0,
+            chance_apparition: 1.0,
+            duree_min: 100.0,
+            duree_max: 200.0,
+            max_simultanes,
+            ..Default::default()
+        }
+    }
+
+    /// Avec un seul bonus autorisé, aucun autre n'apparaît tant qu'il est visible.
+    #[test]
+    fn test_max_un_bonus_simultane() {
+        let config = config_apparitions_forcees(1);
+        let mut rng = SmallRng::seed_from_u64(3);
+        let mut gestionnaire = GestionnaireBonus::new(&mut rng, ECRAN, ECRAN / 2.0);
+        for _ in 0..20 {
+            gestionnaire.update(&mut rng, 0.016, 100, ECRAN / 2.0, ECRAN, &config);
+            assert_eq!(gestionnaire.visibles.len(), 1);
+        }
+    }
+
+    /// Avec trois bonus autorisés, trois bonus peuvent coexister, mais jamais plus.
+    #[test]
+    fn test_max_trois_bonus_simultanes() {
+        let config = config_apparitions_forcees(3);
+        let mut rng = SmallRng::seed_from_u64(3);
+        let mut gestionnaire = GestionnaireBonus::new(&mut rng, ECRAN, ECRAN / 2.0);
+        for _ in 0..20 {
+            gestionnaire.update(&mut rng, 0.016, 100, ECRAN / 2.0, ECRAN, &config);
+            assert!(gestionnaire.visibles.len() <= 3);
+        }
+        assert_eq!(gestionnaire.visibles.len(), 3);
+
+        // Ramasser un bonus libère une place pour un nouveau.
+        let position = gestionnaire.visibles[0].position;
+        assert!(gestionnaire.ramasser(position, 15.0).is_some());
+        assert_eq!(gestionnaire.visibles.len(), 2);
+        gestionnaire.update(&mut rng, 0.016, 100, ECRAN / 2.0, ECRAN, &config);
+        assert_eq!(gestionnaire.visibles.len(), 3);
+    }
+
+    /// L'aimant rapproche tous les bonus du vaisseau, sans jamais le dépasser.
+    #[test]
+    fn test_aimant_attire_les_bonus() {
+        let config = config_apparitions_forcees(3);
+        let mut rng = SmallRng::seed_from_u64(2);
+        let mut gestionnaire = GestionnaireBonus::new(&mut rng, ECRAN, ECRAN / 2.0);
+        for _ in 0..3 {
+            gestionnaire.update(&mut rng, 0.016, 100, ECRAN / 2.0, ECRAN, &config);
+        }
+        let distances = |g: &GestionnaireBonus| -> Vec<f32> {
+            g.visibles
+                .iter()
+                .map(|bonus| bonus.position.distance(ECRAN / 2.0))
+                .collect()
+        };
+        let avant = distances(&gestionnaire);
+        gestionnaire.attirer(ECRAN / 2.0, VITESSE_ATTRACTION_AIMANT);
+        for (apres, avant) in distances(&gestionnaire).iter().zip(&avant) {
+            assert!((avant - apres - VITESSE_ATTRACTION_AIMANT).abs() < 1e-3);
+        }
+
+        for _ in 0..1000 {
+            gestionnaire.attirer(ECRAN / 2.0, VITESSE_ATTRACTION_AIMANT);
+        }
+        assert!(gestionnaire.ramasser(ECRAN / 2.0, 1.0).is_some());
+    }
+
+    #[test]
+    fn test_repulsion_aimant() {
+        let vaisseau = vec2(400.0, 300.0);
+        let proche = repulsion_aimant(vaisseau, vec2(450.0, 300.0));
+        let moins_proche = repulsion_aimant(vaisseau, vec2(400.0, 500.0));
+        assert!(proche.x > 0.0 && proche.y == 0.0); // Vers l'extérieur
+        assert!(moins_proche.y > 0.0 && moins_proche.length() < proche.length());
+        assert_eq!(repulsion_aimant(vaisseau, vec2(700.0, 300.0)), Vec2::ZERO);
+        assert_eq!(repulsion_aimant(vaisseau, vaisseau), Vec2::ZERO);
+    }
+
+    /// Après une réinitialisation (nouvelle partie), aucun bonus n'est visible et le timer est remis à zéro.
+    #[test]
+    fn test_reinitialiser() {
+        let config = config_apparitions_forcees(3);
+        let mut rng = SmallRng::seed_from_u64(5);
+        let mut gestionnaire = GestionnaireBonus::new(&mut rng, ECRAN, ECRAN / 2.0);
+        gestionnaire.update(&mut rng, 0.016, 100, ECRAN / 2.0, ECRAN, &config);
+        assert!(!gestionnaire.visibles.is_empty());
+
+        gestionnaire.reinitialiser(&mut rng, ECRAN, ECRAN / 2.0);
+        assert!(gestionnaire.visibles.is_empty());
+        assert!(!gestionnaire.candidat.visible);
+        assert_eq!(gestionnaire.candidat.timer, 0.0);
+    }
+}
@@ -0,0 +1,209 @@
+//! Module pour le boss qui apparaît tous les quelques niveaux.
+//! Ce module contient la structure `Boss` : un grand vaisseau ennemi qui traverse le haut de l'écran
+//! et tire régulièrement une gerbe de missiles dans toutes les directions. Le joueur doit se faufiler
+//! entre les missiles tout en tirant sur le boss, qui encaisse plusieurs impacts avant d'être détruit.
+
+use crate::missile::Missile;
+use macroquad::prelude::*;
+
+/// Boss ennemi, qui tire des gerbes de missiles à intervalle régulier.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Boss {
+    position: Vec2,    // Centre du boss
+    vitesse: f32, // Vitesse horizontale (en pixels par frame), de signe opposé après chaque rebond
+    points_vie: u32, // Nombre d'impacts que le boss peut encore encaisser
+    minuteur_tir: f32, // Temps (en secondes) avant la prochaine gerbe de missiles
+}
+
+impl Boss {
+    /// Rayon du boss (en pixels), le même pour le dessin et pour les collisions.
+    pub const RAYON: f32 = 40.0;
+
+    /// Nombre d'impacts de missiles nécessaires pour détruire le boss.
+    pub const POINTS_VIE: u32 = 15;
+
+    /// Temps (en secondes) entre deux gerbes de missiles.
+    pub const INTERVALLE_TIR: f32 = 2.0;
+
+    /// Nombre de missiles d'une gerbe.
+    pub const NOMBRE_MISSILES: usize = 12;
+
+    /// Vitesse des missiles du boss (en pixels par frame), plus lente que celle du joueur pour laisser le temps d'esquiver.
+    pub const VITESSE_MISSILES: f32 = 3.0;
+
+    /// Dégâts infligés au vaisseau par un missile du boss.
+    pub const DEGATS_MISSILE: u8 = 15;
+
+    /// Dégâts infligés au vaisseau qui percute le boss.
+    pub const DEGATS_CONTACT: u8 = 25;
+
+    /// Vitesse (en pixels par frame) donnée au vaisseau repoussé après avoir percuté le boss.
+    pub const REBOND_CONTACT: f32 = 3.0;
+
+    /// Vitesse de déplacement horizontal du boss (en pixels par frame).
+    pub const VITESSE: f32 = 1.0;
+
+    /// Points gagnés en détruisant le boss.
+    pub const POINTS: u32 = 2000;
+
+    /// Crée un boss immobile à la position donnée, qui tire sa première gerbe après `INTERVALLE_TIR` secondes.
+    pub fn new(position: Vec2) -> Self {
+        Self {
+            position,
+            vitesse: 0.0,
+            points_vie: Self::POINTS_VIE,
+            minuteur_tir: Self::INTERVALLE_TIR,
+        }
+    }
+
+    /// Crée le boss d'un niveau : il apparaît en haut de l'écran, loin du vaisseau qui part du centre,
+    /// et se déplace de gauche à droite.
+    /// # Arguments
+    /// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+    pub fn apparition(ecran: Vec2) -> Self {
+        Self {
+            vitesse: Self::VITESSE,
+            ..Self::new(vec2(ecran.x / 2.0, ecran.y * 0.2))
+        }
+    }
+
+    /// Retourne la position du centre du boss.
+    pub fn get_position(&self) -> Vec2 {
+        self.position
+    }
+
+    /// Indique si le boss a été détruit.
+    pub fn est_detruit(&self) -> bool {
+        self.points_vie == 0
+    }
+
+    /// Indique si un missile du joueur touche le boss.
+    pub fn est_touche_par(&self, missile: &Missile) -> bool {
+        self.position.distance(missile.get_position()) < Self::RAYON + Missile::RAYON
+    }
+
+    /// Retire un point de vie au boss (impact d'un missile du joueur).
+    pub fn encaisser_impact(&mut self) {
+        self.points_vie = self.points_vie.saturating_sub(1);
+    }
+
+    /// Déplace le boss (il rebondit sur les bords gauche et droit de l'écran) et fait avancer le minuteur de tir.
+    /// # Arguments
+    /// - `dt` - Nombre de frames écoulées (1.0 = une frame normale, voir `Settings::vitesse_simulation`).
+    /// - `delta_time` - Temps écoulé (en secondes) depuis la dernière frame.
+    /// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+    /// # Retourne les missiles de la gerbe tirée pendant cette frame (aucun si le minuteur n'est pas écoulé).
+    pub fn avancer(&mut self, dt: f32, delta_time: f32, ecran: Vec2) -> Vec<Missile> {
+        self.position.x += self.vitesse * dt;
+        if (self.position.x < Self::RAYON && self.vitesse < 0.0)
+            || (self.position.x > ecran.x - Self::RAYON && self.vitesse > 0.0)
+        {
+            self.vitesse = -self.vitesse;
+        }
+
+        self.minuteur_tir -= delta_time;
+        if self.minuteur_tir > 0.0 {
+            return Vec::new();
+        }
+        self.minuteur_tir += Self::INTERVALLE_TIR;
+        tirer_spread(self.position, Self::NOMBRE_MISSILES, Self::VITESSE_MISSILES)
+    }
+
+    /// Dessine le boss et une jauge de ses points de vie au-dessus de lui.
+    pub fn draw(&self) {
+        draw_circle(self.position.x, self.position.y, Self::RAYON, DARKPURPLE);
+        draw_circle_lines(self.position.x, self.position.y, Self::RAYON, 3.0, VIOLET);
+        let largeur = 2.0 * Self::RAYON;
+        let gauche = self.position.x - Self::RAYON;
+        let haut = self.position.y - Self::RAYON - 14.0;
+        draw_rectangle(gauche, haut, largeur, 6.0, DARKGRAY);
+        draw_rectangle(
+            gauche,
+            haut,
+            largeur * self.points_vie as f32 / Self::POINTS_VIE as f32,
+            6.0,
+            VIOLET,
+        );
+    }
+}
+
+/// Tire une gerbe de missiles ennemis dans toutes les directions, régulièrement espacées autour du centre.
+/// # Arguments
+/// - `centre` - Point de départ de tous les missiles.
+/// - `n` - Nombre de missiles de la gerbe.
+/// - `vitesse` - Norme de la vitesse des missiles (en pixels par frame).
+pub fn tirer_spread(centre: Vec2, n: usize, vitesse: f32) -> Vec<Missile> {
+    (0..n)
+        .map(|i| {
+            let angle = i as f32 * std::f32::consts::TAU / n as f32;
+            Missile::nouveau_missile_ennemi(centre, angle, vitesse)
+        })
+        .collect()
+}
+
+/// Retourne le boss du niveau donné : un boss apparaît tous les `intervalle` niveaux.
+/// # Arguments
+/// - `niveau` - Niveau qui commence.
+/// - `intervalle` - Nombre de niveaux entre deux boss (0 = jamais).
+/// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+pub fn boss_niveau(niveau: i32, intervalle: u32, ecran: Vec2) -> Option<Boss> {
+    let intervalle = intervalle as i32;
+    (intervalle != 0 && niveau % intervalle == 0).then(|| Boss::apparition(ecran))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stellarobject::StellarObject;
+
+    /// Une gerbe de n missiles part dans n directions régulièrement espacées, qui font le tour complet du cercle.
+    #[test]
+    fn test_tirer_spread() {
+        let centre = vec2(400.0, 120.0);
+        let missiles = tirer_spread(centre, 8, 3.0);
+        assert_eq!(missiles.len(), 8);
+        assert!(missiles.iter().all(|m| m.est_ennemi()));
+        assert!(missiles.iter().all(|m| m.get_position() == centre));
+        assert!(missiles
+            .iter()
+            .all(|m| (m.get_vitesse().length() - 3.0).abs() < 1e-4));
+
+        // Deux missiles voisins sont toujours séparés du même angle, et les écarts font un tour complet.
+        let directions: Vec<Vec2> = missiles.iter().map(|m| m.get_vitesse()).collect();
+        let ecarts: Vec<f32> = (0..directions.len())
+            .map(|i| {
+                let suivante = directions[(i + 1) % directions.len()];
+                directions[i]
+                    .angle_between(suivante)
+                    .rem_euclid(std::f32::consts::TAU)
+            })
+            .collect();
+        for ecart in &ecarts {
+            assert!((ecart - std::f32::consts::TAU / 8.0).abs() < 1e-4);
+        }
+        assert!((ecarts.iter().sum::<f32>() - std::f32::consts::TAU).abs() < 1e-3);
+        // Régulièrement réparties, les vitesses s'annulent.
+        assert!(directions.iter().sum::<Vec2>().length() < 1e-4);
+    }
+
+    /// Le boss tire une gerbe à chaque fois que son minuteur est écoulé.
+    #[test]
+    fn test_minuteur_tir() {
+        let ecran = vec2(800.0, 600.0);
+        let mut boss = Boss::apparition(ecran);
+        let frames = (Boss::INTERVALLE_TIR * 60.0) as usize;
+        let tirs: Vec<usize> = (0..3 * frames + 3)
+            .map(|_| boss.avancer(1.0, 1.0 / 60.0, ecran).len())
+            .filter(|&n| n > 0)
+            .collect();
+        assert_eq!(tirs, vec![Boss::NOMBRE_MISSILES; 3]);
+    }
+
+    #[test]
+    fn test_boss_niveau() {
+        let ecran = vec2(800.0, 600.0);
+        assert!(boss_niveau(5, 0, ecran).is_none());
+        assert!(boss_niveau(4, 5, ecran).is_none());
+        assert!(boss_niveau(10, 5, ecran).is_some());
+    }
+}
@@ -0,0 +1,183 @@
+//! Module de la boutique entre deux vagues.
+//! Ce module contient la logique d'achat des améliorations : le joueur dépense son score pour améliorer
+//! son vaisseau ou son arme. La boutique s'ouvre quand une vague est terminée (voir `World::boutique_ouverte`),
+//! et chaque amélioration a un niveau maximal au-delà duquel elle n'est plus proposée.
+
+/// Améliorations proposées dans la boutique.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Amelioration {
+    TirRapide,           // Les missiles partent plus vite
+    BouclierRenforce,    // Augmente le bouclier maximal
+    BombeSupplementaire, // Ajoute une bombe
+    MissilesTeleguides,  // Les missiles suivent leur cible
+}
+
+impl Amelioration {
+    /// Liste des améliorations, dans l'ordre d'affichage de la boutique.
+    pub const TOUTES: [Amelioration; 4] = [
+        Amelioration::TirRapide,
+        Amelioration::BouclierRenforce,
+        Amelioration::BombeSupplementaire,
+        Amelioration::MissilesTeleguides,
+    ];
+
+    /// Retourne le prix de l'amélioration (en points de score).
+    pub fn prix(&self) -> u32 {
+        match self {
+            Amelioration::TirRapide => 500,
+            Amelioration::BouclierRenforce => 800,
+            Amelioration::BombeSupplementaire => 300,
+            Amelioration::MissilesTeleguides => 1200,
+        }
+    }
+
+    /// Retourne le nom affiché dans la boutique.
+    pub fn nom(&self) -> &'static str {
+        match self {
+            Amelioration::TirRapide => "Tir rapide",
+            Amelioration::BouclierRenforce => "Bouclier renforcé",
+            Amelioration::BombeSupplementaire => "Bombe supplémentaire",
+            Amelioration::MissilesTeleguides => "Missiles téléguidés",
+        }
+    }
+}
+
+/// Améliorations achetées par le joueur pendant la partie.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Ameliorations {
+    pub niveaux_tir_rapide: u8, // Nombre d'améliorations de la vitesse des missiles
+    pub bonus_bouclier_max: u8, // Points ajoutés au bouclier maximal
+    pub bombes: u8,             // Nombre de bombes disponibles
+    pub missiles_teleguides: bool, // Les missiles sont-ils téléguidés ?
+}
+
+impl Ameliorations {
+    /// Nombre maximal d'améliorations du tir rapide.
+    pub const NIVEAU_MAX_TIR_RAPIDE: u8 = 3;
+
+    /// Vitesse ajoutée aux missiles par niveau de tir rapide (en part de la vitesse par défaut).
+    pub const GAIN_TIR_RAPIDE: f32 = 0.25;
+
+    /// Points ajoutés au bouclier maximal par achat du bouclier renforcé.
+    pub const PAS_BOUCLIER: u8 = 25;
+
+    /// Nombre maximal de points ajoutés au bouclier maximal.
+    pub const BONUS_BOUCLIER_MAX: u8 = 50;
+
+    /// Nombre maximal de bombes en réserve.
+    pub const BOMBES_MAX: u8 = 9;
+
+    /// Indique si l'amélioration a atteint son niveau maximal (elle ne peut plus être achetée).
+    pub fn est_au_maximum(&self, amelioration: Amelioration) -> bool {
+        match amelioration {
+            Amelioration::TirRapide => self.niveaux_tir_rapide >= Self::NIVEAU_MAX_TIR_RAPIDE,
+            Amelioration::BouclierRenforce => self.bonus_bouclier_max >= Self::BONUS_BOUCLIER_MAX,
+            Amelioration::BombeSupplementaire => self.bombes >= Self::BOMBES_MAX,
+            Amelioration::MissilesTeleguides => self.missiles_teleguides,
+        }
+    }
+
+    /// Achète une amélioration avec le score du joueur.
+    /// Si le score est suffisant, le prix est déduit et l'effet est appliqué.
+    /// # Arguments
+    /// - `score` : Score du joueur, diminué du prix en cas d'achat.
+    /// - `amelioration` : Amélioration demandée.
+    /// # Retourne `true` si l'achat a eu lieu, `false` si le score est insuffisant
+    /// ou si l'amélioration est déjà au maximum.
+    pub fn acheter(&mut self, score: &mut u32, amelioration: Amelioration) -> bool {
+        if *score < amelioration.prix() || self.est_au_maximum(amelioration) {
+            return false;
+        }
+        *score -= amelioration.prix();
+
+        match amelioration {
+            Amelioration::TirRapide => {
+                self.niveaux_tir_rapide = self.niveaux_tir_rapide.saturating_add(1)
+            }
+            Amelioration::BouclierRenforce => {
+                self.bonus_bouclier_max = self
+                    .bonus_bouclier_max
+                    .saturating_add(Self::PAS_BOUCLIER)
+                    .min(Self::BONUS_BOUCLIER_MAX)
+            }
+            Amelioration::BombeSupplementaire => self.bombes = self.bombes.saturating_add(1),
+            Amelioration::MissilesTeleguides => self.missiles_teleguides = true,
+        }
+        true
+    }
+
+    /// Retourne le facteur appliqué à la vitesse des missiles tirés par le vaisseau, selon le niveau du tir rapide.
+    pub fn facteur_vitesse_missiles(&self) -> f32 {
+        1.0 + Self::GAIN_TIR_RAPIDE * self.niveaux_tir_rapide as f32
+    }
+
+    /// Consomme une bombe, s'il en reste.
+    /// # Retourne `true` si une bombe a été utilisée.
+    pub fn utiliser_bombe(&mut self) -> bool {
+        if self.bombes == 0 {
+            return false;
+        }
+        self.bombes -= 1;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_achat_avec_score_suffisant() {
+        let mut ameliorations = Ameliorations::default();
+        let mut score = 1000;
+        assert!(ameliorations.acheter(&mut score, Amelioration::BombeSupplementaire));
+        assert_eq!(score, 700); // Le prix est déduit.
+        assert_eq!(ameliorations.bombes, 1); // Et l'effet est appliqué.
+    }
+
+    #[test]
+    fn test_achat_refuse_si_score_insuffisant() {
+        let mut ameliorations = Ameliorations::default();
+        let mut score = 400;
+        assert!(!ameliorations.acheter(&mut score, Amelioration::MissilesTeleguides));
+        assert_eq!(score, 400); // Le score ne change pas.
+        assert_eq!(ameliorations, Ameliorations::default()); // Aucun effet.
+    }
+
+    #[test]
+    fn test_achat_au_prix_exact() {
+        let mut ameliorations = Ameliorations::default();
+        let mut score = Amelioration::TirRapide.prix();
+        assert!(ameliorations.acheter(&mut score, Amelioration::TirRapide));
+        assert_eq!(score, 0);
+        assert_eq!(ameliorations.niveaux_tir_rapide, 1);
+    }
+
+    /// Une amélioration au maximum n'est plus vendue : le score n'est pas débité.
+    #[test]
+    fn test_achat_refuse_au_maximum() {
+        let mut ameliorations = Ameliorations::default();
+        let mut score = 100_000;
+        for _ in 0..Ameliorations::NIVEAU_MAX_TIR_RAPIDE {
+            assert!(ameliorations.acheter(&mut score, Amelioration::TirRapide));
+        }
+        let avant = score;
+        assert!(!ameliorations.acheter(&mut score, Amelioration::TirRapide));
+        assert_eq!(score, avant);
+        assert_eq!(
+            ameliorations.niveaux_tir_rapide,
+            Ameliorations::NIVEAU_MAX_TIR_RAPIDE
+        );
+        assert!((ameliorations.facteur_vitesse_missiles() - 1.75).abs() < 1e-6);
+
+        while ameliorations.acheter(&mut score, Amelioration::BouclierRenforce) {}
+        assert_eq!(
+            ameliorations.bonus_bouclier_max,
+            Ameliorations::BONUS_BOUCLIER_MAX
+        );
+        while ameliorations.acheter(&mut score, Amelioration::BombeSupplementaire) {}
+        assert_eq!(ameliorations.bombes, Ameliorations::BOMBES_MAX);
+        assert!(ameliorations.utiliser_bombe());
+        assert_eq!(ameliorations.bombes, Ameliorations::BOMBES_MAX - 1);
+    }
+}
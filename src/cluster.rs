@@ -0,0 +1,241 @@
+//! Module pour les clusters d'astéroïdes protégés.
+//! Ce module contient la structure `ClusterAsteroide` : un grand astéroïde (le cœur) autour duquel tournent
+//! de petits astéroïdes (les orbiteurs). Tant qu'il reste des orbiteurs, le cœur est protégé : les missiles
+//! qui l'atteignent sont arrêtés sans l'abîmer. Le joueur doit d'abord dégager les orbiteurs, comme un petit puzzle.
+
+use crate::asteroid::Asteroid;
+use crate::stellarobject::StellarObject;
+use macroquad::prelude::*;
+
+/// Calcule la position d'un orbiteur sur son orbite.
+/// # Arguments
+/// - `centre` - Centre de l'orbite (position du cœur).
+/// - `rayon` - Rayon de l'orbite.
+/// - `angle` - Angle de l'orbiteur (en radians, 0 = à droite du centre).
+pub fn position_orbite(centre: Vec2, rayon: f32, angle: f32) -> Vec2 {
+    centre + Vec2::from_angle(angle) * rayon
+}
+
+/// Effet d'un missile sur un cluster.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Impact {
+    Manque,            // Le missile n'a rien touché : il continue sa route
+    Arrete, // Le missile a touché sans rien détruire (résistance restante, ou cœur protégé)
+    Detruit(Vec2, u8), // Le missile a détruit un astéroïde du cluster (position et taille, pour l'explosion)
+}
+
+/// Petit astéroïde en orbite autour du cœur.
+#[derive(Debug, Clone, PartialEq)]
+struct Orbiteur {
+    asteroid: Asteroid, // Astéroïde dessiné et touché par les missiles
+    angle: f32,         // Angle actuel sur l'orbite (en radians)
+}
+
+/// Cluster d'astéroïdes : un cœur protégé par des orbiteurs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClusterAsteroide {
+    coeur: Asteroid, // Grand astéroïde central, qui emmène les orbiteurs avec lui
+    orbiteurs: Vec<Orbiteur>, // Orbiteurs encore en vie
+}
+
+impl ClusterAsteroide {
+    /// Nombre d'orbiteurs d'un nouveau cluster.
+    pub const NOMBRE_ORBITEURS: usize = 6;
+
+    /// Distance entre le centre du cœur et ses orbiteurs.
+    pub const RAYON_ORBITE: f32 = 90.0;
+
+    /// Vitesse de rotation des orbiteurs autour du cœur (en radians par frame).
+    pub const VITESSE_ANGULAIRE: f32 = 0.02;
+
+    /// Crée un cluster : un grand astéroïde entouré d'orbiteurs régulièrement répartis sur l'orbite.
+    /// # Arguments
+    /// - `centre` - Position du cœur.
+    /// - `vitesse` - Vitesse du cœur (les orbiteurs le suivent).
+    /// - `nombre_orbiteurs` - Nombre de petits astéroïdes en orbite.
+    pub fn new(centre: Vec2, vitesse: Vec2, nombre_orbiteurs: usize) -> Self {
+        let orbiteurs = (0..nombre_orbiteurs)
+            .map(|i| {
+                let angle = i as f32 * std::f32::consts::TAU / nombre_orbiteurs as f32;
+                let position = position_orbite(centre, Self::RAYON_ORBITE, angle);
+                Orbiteur {
+                    asteroid: Asteroid::scripté(1, position, vitesse),
+                    angle,
+                }
+            })
+            .collect();
+        Self {
+            coeur: Asteroid::scripté(3, centre, vitesse),
+            orbiteurs,
+        }
+    }
+
+    /// Crée le cluster d'un niveau : il apparaît dans le quart haut gauche de l'écran, loin du vaisseau
+    /// qui part du centre, et dérive lentement.
+    /// # Arguments
+    /// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+    pub fn apparition(ecran: Vec2) -> Self {
+        Self::new(ecran * 0.25, vec2(0.6, 0.3), Self::NOMBRE_ORBITEURS)
+    }
+
+    /// Indique si le cœur est encore protégé par au moins un orbiteur.
+    pub fn est_protege(&self) -> bool {
+        !self.orbiteurs.is_empty()
+    }
+
+    /// Indique si le cœur a été détruit (le cluster peut alors disparaître).
+    pub fn est_detruit(&self) -> bool {
+        self.coeur.est_détruit()
+    }
+
+    /// Retourne tous les astéroïdes du cluster, le cœur en premier.
+    pub fn asteroides(&self) -> impl Iterator<Item = &Asteroid> {
+        std::iter::once(&self.coeur).chain(self.orbiteurs.iter().map(|o| &o.asteroid))
+    }
+
+    /// Retourne tous les astéroïdes du cluster, modifiables (collisions avec le vaisseau), le cœur en premier.
+    /// Les orbiteurs sont replacés sur leur orbite à chaque `avancer` : seul le cœur garde un changement de vitesse.
+    pub fn asteroides_mut(&mut self) -> impl Iterator<Item = &mut Asteroid> {
+        std::iter::once(&mut self.coeur).chain(self.orbiteurs.iter_mut().map(|o| &mut o.asteroid))
+    }
+
+    /// Déplace le cœur et fait tourner les orbiteurs autour de lui.
+    /// # Arguments
+    /// - `facteur` - Facteur appliqué au déplacement de cette frame (1.0 = vitesse normale).
+    /// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+    pub fn avancer(&mut self, facteur: f32, ecran: Vec2) {
+        let centre = self.coeur.move_object(facteur, ecran);
+        for orbiteur in self.orbiteurs.iter_mut() {
+            orbiteur.angle = (orbiteur.angle + Self::VITESSE_ANGULAIRE * facteur)
+                .rem_euclid(std::f32::consts::TAU);
+            orbiteur.asteroid.set_position(position_orbite(
+                centre,
+                Self::RAYON_ORBITE,
+                orbiteur.angle,
+            ));
+        }
+    }
+
+    /// Applique l'impact d'un missile. Les orbiteurs sont testés en premier ; le cœur n'est abîmé
+    /// qu'une fois tous les orbiteurs détruits. Les astéroïdes du cluster ne se divisent pas.
+    /// # Arguments
+    /// - `position` - Position du missile.
+    /// - `rayon_missile` - Rayon du missile.
+    pub fn recevoir_missile(&mut self, position: Vec2, rayon_missile: f32) -> Impact {
+        let touche = |asteroid: &Asteroid| {
+            position.distance(asteroid.get_position()) < asteroid.rayon_asteroid() + rayon_missile
+        };
+        if let Some(index) = self.orbiteurs.iter().position(|o| touche(&o.asteroid)) {
+            let asteroid = &mut self.orbiteurs[index].asteroid;
+            asteroid.diminuer_résistance();
+            if !asteroid.est_détruit() {
+                return Impact::Arrete;
+            }
+            let orbiteur = self.orbiteurs.remove(index);
+            return Impact::Detruit(
+                orbiteur.asteroid.get_position(),
+                orbiteur.asteroid.get_taille(),
+            );
+        }
+        if !touche(&self.coeur) {
+            return Impact::Manque;
+        }
+        if self.est_protege() {
+            return Impact::Arrete; // Le bouclier des orbiteurs arrête le missile.
+        }
+        self.coeur.diminuer_résistance();
+        if self.coeur.est_détruit() {
+            Impact::Detruit(self.coeur.get_position(), self.coeur.get_taille())
+        } else {
+            Impact::Arrete
+        }
+    }
+
+    /// Dessine l'orbite autour du cœur, en bleu tant que le cœur est protégé.
+    /// Les astéroïdes eux-mêmes sont dessinés comme les autres (voir `asteroides`).
+    pub fn dessiner_orbite(&self) {
+        let centre = self.coeur.get_position();
+        let couleur = if self.est_protege() {
+            Color::new(0.4, 0.7, 1.0, 0.5)
+        } else {
+            Color::new(0.5, 0.5, 0.5, 0.3)
+        };
+        draw_circle_lines(centre.x, centre.y, Self::RAYON_ORBITE, 1.5, couleur);
+    }
+}
+
+/// Retourne le cluster du niveau donné : un cluster apparaît tous les `intervalle` niveaux.
+/// # Arguments
+/// - `niveau` - Niveau qui commence.
+/// - `intervalle` - Nombre de niveaux entre deux clusters (0 = jamais).
+/// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+pub fn cluster_niveau(niveau: i32, intervalle: u32, ecran: Vec2) -> Option<ClusterAsteroide> {
+    let intervalle = intervalle as i32;
+    (intervalle != 0 && niveau % intervalle == 0).then(|| ClusterAsteroide::apparition(ecran))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_orbite() {
+        let centre = vec2(400.0, 300.0);
+        assert!(position_orbite(centre, 90.0, 0.0).distance(vec2(490.0, 300.0)) < 1e-3);
+        let quart = position_orbite(centre, 90.0, std::f32::consts::FRAC_PI_2);
+        assert!(quart.distance(vec2(400.0, 390.0)) < 1e-3);
+    }
+
+    /// Les orbiteurs tournent autour du cœur en restant sur l'orbite, et le suivent quand il se déplace.
+    #[test]
+    fn test_orbiteurs_suivent_le_coeur() {
+        let ecran = vec2(800.0, 600.0);
+        let mut cluster = ClusterAsteroide::new(vec2(400.0, 300.0), vec2(1.0, 0.0), 4);
+        let avant: Vec<Vec2> = cluster.asteroides().map(|a| a.get_position()).collect();
+        for _ in 0..10 {
+            cluster.avancer(1.0, ecran);
+        }
+        let centre = cluster.coeur.get_position();
+        assert_eq!(centre, vec2(410.0, 300.0));
+        for (orbiteur, depart) in cluster.orbiteurs.iter().zip(&avant[1..]) {
+            let position = orbiteur.asteroid.get_position();
+            assert!((position.distance(centre) - ClusterAsteroide::RAYON_ORBITE).abs() < 1e-3);
+            assert_ne!(position, *depart + vec2(10.0, 0.0)); // Ils ont aussi tourné.
+        }
+    }
+
+    /// Le cœur ne peut être touché qu'une fois tous les orbiteurs détruits.
+    #[test]
+    fn test_coeur_protege_par_orbiteurs() {
+        let mut cluster = ClusterAsteroide::new(vec2(400.0, 300.0), Vec2::ZERO, 2);
+        let coeur = vec2(400.0, 300.0);
+        assert_eq!(cluster.recevoir_missile(coeur, 5.0), Impact::Arrete);
+        assert_eq!(
+            cluster.coeur.get_resistance(),
+            crate::asteroid::resistance_initiale(3)
+        );
+        assert_eq!(
+            cluster.recevoir_missile(vec2(0.0, 0.0), 5.0),
+            Impact::Manque
+        );
+
+        // On détruit les deux orbiteurs, à droite puis à gauche du cœur.
+        for orbite in [vec2(490.0, 300.0), vec2(310.0, 300.0)] {
+            let impact = loop {
+                match cluster.recevoir_missile(orbite, 5.0) {
+                    Impact::Arrete => continue,
+                    impact => break impact,
+                }
+            };
+            assert!(matches!(impact, Impact::Detruit(_, 1)));
+        }
+        assert!(!cluster.est_protege());
+
+        let mut impacts: u8 = 0;
+        while !cluster.est_detruit() {
+            cluster.recevoir_missile(coeur, 5.0);
+            impacts += 1;
+        }
+        assert_eq!(impacts, crate::asteroid::resistance_initiale(3));
+    }
+}
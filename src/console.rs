@@ -0,0 +1,273 @@
+//! Module pour la console de développement.
+//! Ce module contient la structure `Console`, ouverte et fermée avec la touche `~` : on y tape des commandes
+//! qui modifient la partie ou les paramètres pendant le jeu, pour équilibrer le jeu sans le relancer.
+//! Le jeu est en pause tant que la console est ouverte.
+//!
+//! Commandes reconnues :
+//! - `set <réglage> <valeur>` : change un réglage (`friction`, `acceleration`, `vitesse`, `regeneration`) ;
+//! - `spawn <n>` : ajoute `n` astéroïdes aléatoires ;
+//! - `level <n>` : passe directement au niveau `n` (entre 1 et `NIVEAU_MAX`).
+//!
+//! Chaque réglage n'accepte que les valeurs de son intervalle (voir `Reglage::bornes`), pour qu'une faute de frappe
+//! ne rende pas la partie injouable (par exemple une friction supérieure à 1, qui fait accélérer le vaisseau sans fin).
+
+use crate::settings::Settings;
+use crate::world::World;
+use macroquad::prelude::*;
+
+/// Niveau maximal accepté par la commande `level`.
+pub const NIVEAU_MAX: i32 = 999;
+
+/// Réglage modifiable depuis la console.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Reglage {
+    Friction,             // Part de la vitesse du vaisseau conservée à chaque frame
+    Acceleration,         // Accélération de la poussée du vaisseau
+    VitesseSimulation,    // Multiplicateur de vitesse de tous les déplacements
+    RegenerationBouclier, // Points de bouclier régénérés chaque seconde
+}
+
+impl Reglage {
+    /// Retourne le réglage correspondant à son nom dans la console, ou `None` s'il est inconnu.
+    fn depuis_nom(nom: &str) -> Option<Self> {
+        match nom {
+            "friction" => Some(Self::Friction),
+            "acceleration" => Some(Self::Acceleration),
+            "vitesse" => Some(Self::VitesseSimulation),
+            "regeneration" => Some(Self::RegenerationBouclier),
+            _ => None,
+        }
+    }
+
+    /// Retourne les valeurs minimale et maximale acceptées pour le réglage.
+    pub fn bornes(&self) -> (f32, f32) {
+        match self {
+            Self::Friction => (0.0, 1.0),
+            Self::Acceleration => (0.0, 2.0),
+            Self::VitesseSimulation => (0.0, 5.0),
+            Self::RegenerationBouclier => (0.0, 100.0),
+        }
+    }
+}
+
+/// Commande tapée dans la console.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Commande {
+    Regler(Reglage, f32), // `set <réglage> <valeur>`
+    Ajouter(usize),       // `spawn <n>`
+    Niveau(i32),          // `level <n>`
+}
+
+/// Analyse une ligne tapée dans la console.
+/// # Retourne une erreur décrivant le problème si la commande est inconnue ou mal formée.
+pub fn analyser_commande(ligne: &str) -> Result<Commande, String> {
+    let mots: Vec<&str> = ligne.split_whitespace().collect();
+    fn nombre<T: std::str::FromStr>(mot: &str) -> Result<T, String> {
+        mot.parse()
+            .map_err(|_| format!("Nombre invalide : '{mot}'."))
+    }
+    match mots.as_slice() {
+        ["set", nom, valeur] => {
+            let reglage =
+                Reglage::depuis_nom(nom).ok_or_else(|| format!("Réglage inconnu : '{nom}'."))?;
+            let valeur: f32 = valeur
+                .parse()
+                .ok()
+                .filter(|valeur: &f32| valeur.is_finite())
+                .ok_or_else(|| format!("Valeur invalide : '{valeur}'."))?;
+            let (min, max) = reglage.bornes();
+            if !(min..=max).contains(&valeur) {
+                return Err(format!("{reglage:?} doit être entre {min} et {max}."));
+            }
+            Ok(Commande::Regler(reglage, valeur))
+        }
+        ["spawn", n] => Ok(Commande::Ajouter(nombre(n)?)),
+        ["level", n] => {
+            let niveau: i32 = nombre(n)?;
+            if !(1..=NIVEAU_MAX).contains(&niveau) {
+                return Err(format!("Le niveau doit être entre 1 et {NIVEAU_MAX}."));
+            }
+            Ok(Commande::Niveau(niveau))
+        }
+        [] => Err("Aucune commande.".to_string()),
+        _ => Err(format!("Commande inconnue : '{ligne}'.")),
+    }
+}
+
+impl Commande {
+    /// Applique la commande à la partie en cours ou aux paramètres.
+    /// # Arguments
+    /// - `monde` - Partie en cours.
+    /// - `settings` - Paramètres du jeu.
+    /// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+    /// # Retourne un message décrivant ce qui a été fait.
+    pub fn appliquer(&self, monde: &mut World, settings: &mut Settings, ecran: Vec2) -> String {
+        match *self {
+            Commande::Regler(reglage, valeur) => {
+                match reglage {
+                    Reglage::Friction => settings.friction_vaisseau = valeur,
+                    Reglage::Acceleration => settings.acceleration_vaisseau = valeur,
+                    Reglage::VitesseSimulation => settings.vitesse_simulation = valeur,
+                    Reglage::RegenerationBouclier => settings.regeneration_bouclier = valeur,
+                }
+                format!("{reglage:?} = {valeur}")
+            }
+            Commande::Ajouter(nombre) => {
+                let ajoutes = monde.ajouter_asteroides_aleatoires(nombre, ecran);
+                format!("{ajoutes} astéroïde(s) ajouté(s)")
+            }
+            Commande::Niveau(niveau) => {
+                monde.aller_au_niveau(niveau, settings, ecran);
+                format!("Niveau {niveau}")
+            }
+        }
+    }
+}
+
+/// Console de développement : ligne en cours de saisie et réponse à la dernière commande.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Console {
+    ouverte: bool,   // La console est-elle affichée ?
+    ligne: String,   // Commande en cours de saisie
+    message: String, // Réponse (ou erreur) de la dernière commande
+}
+
+impl Console {
+    /// Ouvre ou ferme la console. La ligne en cours de saisie est effacée.
+    pub fn basculer(&mut self) {
+        self.ouverte = !self.ouverte;
+        self.ligne.clear();
+    }
+
+    /// Indique si la console est ouverte.
+    pub fn est_ouverte(&self) -> bool {
+        self.ouverte
+    }
+
+    /// Ajoute un caractère tapé à la ligne en cours. La touche `~` (qui ferme la console) et les caractères
+    /// de contrôle sont ignorés.
+    pub fn ajouter(&mut self, caractere: char) {
+        if !caractere.is_control() && caractere != '~' && caractere != '`' {
+            self.ligne.push(caractere);
+        }
+    }
+
+    /// Efface le dernier caractère de la ligne en cours.
+    pub fn effacer(&mut self) {
+        self.ligne.pop();
+    }
+
+    /// Exécute la ligne en cours, puis la vide. La réponse est gardée pour être affichée.
+    /// # Arguments
+    /// - `monde` - Partie en cours.
+    /// - `settings` - Paramètres du jeu.
+    /// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+    pub fn valider(&mut self, monde: &mut World, settings: &mut Settings, ecran: Vec2) {
+        self.message = match analyser_commande(&self.ligne) {
+            Ok(commande) => commande.appliquer(monde, settings, ecran),
+            Err(erreur) => erreur,
+        };
+        self.ligne.clear();
+    }
+
+    /// Lit le clavier : caractères tapés, effacement et validation avec "Entrée".
+    pub fn saisir(&mut self, monde: &mut World, settings: &mut Settings, ecran: Vec2) {
+        while let Some(caractere) = get_char_pressed() {
+            self.ajouter(caractere);
+        }
+        if is_key_pressed(KeyCode::Backspace) {
+            self.effacer();
+        }
+        if is_key_pressed(KeyCode::Enter) {
+            self.valider(monde, settings, ecran);
+        }
+    }
+
+    /// Dessine la console en bas de l'écran : la réponse à la dernière commande, puis la ligne en cours.
+    /// # Arguments
+    /// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+    pub fn draw(&self, ecran: Vec2) {
+        let haut = ecran.y - 70.0;
+        draw_rectangle(0.0, haut, ecran.x, 70.0, Color::new(0.0, 0.0, 0.0, 0.8));
+        draw_text(&self.message, 10.0, haut + 25.0, 22.0, GRAY);
+        draw_text(
+            &format!("> {}_", self.ligne),
+            10.0,
+            haut + 55.0,
+            26.0,
+            GREEN,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commande_set() {
+        assert_eq!(
+            analyser_commande("set friction 0.95"),
+            Ok(Commande::Regler(Reglage::Friction, 0.95))
+        );
+        assert_eq!(
+            analyser_commande("  set   vitesse 2 "),
+            Ok(Commande::Regler(Reglage::VitesseSimulation, 2.0))
+        );
+        assert!(analyser_commande("set gravite 1").is_err()); // Réglage inconnu
+        assert!(analyser_commande("set friction beaucoup").is_err());
+        assert!(analyser_commande("set friction").is_err());
+        // Valeurs non finies ou hors de l'intervalle du réglage.
+        assert!(analyser_commande("set friction NaN").is_err());
+        assert!(analyser_commande("set vitesse inf").is_err());
+        assert!(analyser_commande("set friction 1.5").is_err());
+        assert!(analyser_commande("set acceleration -1").is_err());
+        assert_eq!(
+            analyser_commande("set friction 1"),
+            Ok(Commande::Regler(Reglage::Friction, 1.0))
+        );
+    }
+
+    #[test]
+    fn test_commandes_spawn_et_level() {
+        assert_eq!(analyser_commande("spawn 10"), Ok(Commande::Ajouter(10)));
+        assert_eq!(analyser_commande("level 5"), Ok(Commande::Niveau(5)));
+        assert!(analyser_commande("spawn -3").is_err());
+        assert!(analyser_commande("level 0").is_err());
+        assert!(analyser_commande("level 2 3").is_err());
+        assert!(analyser_commande("level -2").is_err());
+        assert!(analyser_commande("level 2147483647").is_err());
+        assert!(analyser_commande("level 3000000000").is_err());
+        assert_eq!(
+            analyser_commande(&format!("level {NIVEAU_MAX}")),
+            Ok(Commande::Niveau(NIVEAU_MAX))
+        );
+    }
+
+    #[test]
+    fn test_commande_invalide() {
+        assert!(analyser_commande("").is_err());
+        assert!(analyser_commande("fly 3").is_err());
+    }
+
+    /// Les commandes validées modifient les paramètres et la partie.
+    #[test]
+    fn test_valider() {
+        let ecran = vec2(800.0, 600.0);
+        let mut settings = Settings::default();
+        let mut monde = World::new(&settings, Some(1), ecran);
+        let mut console = Console::default();
+        for ligne in ["set friction 0.9", "level 4", "spawn 2"] {
+            ligne
+                .chars()
+                .for_each(|caractere| console.ajouter(caractere));
+            console.valider(&mut monde, &mut settings, ecran);
+        }
+        assert_eq!(settings.friction_vaisseau, 0.9);
+        assert_eq!(monde.niveau, 4);
+        assert_eq!(
+            monde.asteroids.len(),
+            crate::asteroid::nombre_asteroides_niveau(4) + 2
+        );
+    }
+}
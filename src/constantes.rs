@@ -0,0 +1,69 @@
+//! Module regroupant les constantes de jeu partagées entre plusieurs modules.
+//! Centraliser ces valeurs permet d'équilibrer le jeu à un seul endroit, sans chercher des nombres magiques
+//! dispersés dans le vaisseau, les missiles et les astéroïdes.
+
+/// Rayon du vaisseau (en pixels). Il sert à la fois au dessin, aux collisions et au ramassage des bonus.
+pub const RAYON_VAISSEAU: f32 = 15.0;
+
+/// Part de la vitesse du vaisseau conservée à chaque frame (frottement).
+pub const FRICTION_VAISSEAU: f32 = 0.97;
+
+/// Accélération par défaut de la poussée du vaisseau (en pixels par frame, à chaque frame).
+pub const ACCELERATION_VAISSEAU: f32 = 0.2;
+
+/// Vitesse par défaut d'un missile (en pixels par frame).
+pub const VITESSE_MISSILE: f32 = 5.0;
+
+/// Vitesse maximale d'un astéroïde (en pixels par frame). Un astéroïde plus rapide pourrait traverser le vaisseau
+/// d'une frame à l'autre sans que la collision soit détectée ; la vitesse est donc bornée partout où elle est donnée
+/// (création, impulsion, rebond), même quand la difficulté augmente.
+pub const VITESSE_MAX_ASTEROID: f32 = 8.0;
+
+/// Rayon d'un missile (en pixels). Il sert à la fois au dessin et à la détection des impacts sur les astéroïdes.
+pub const RAYON_MISSILE: f32 = 3.0;
+
+/// Dégâts infligés au bouclier par un petit astéroïde.
+pub const DEGATS_PETIT_ASTEROIDE: u8 = 10;
+
+/// Dégâts infligés au bouclier par un astéroïde moyen.
+pub const DEGATS_MOYEN_ASTEROIDE: u8 = 15;
+
+/// Dégâts infligés au bouclier par un grand astéroïde.
+pub const DEGATS_GRAND_ASTEROIDE: u8 = 25;
+
+/// Taille de base d'un astéroïde (en pixels), à partir de laquelle son rayon est calculé.
+pub const TAILLE_ASTEROIDE_BASE: f32 = 60.0;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valeurs_attendues() {
+        assert_eq!(RAYON_VAISSEAU, 15.0);
+        assert_eq!(FRICTION_VAISSEAU, 0.97);
+        assert_eq!(ACCELERATION_VAISSEAU, 0.2);
+        assert_eq!(VITESSE_MISSILE, 5.0);
+        assert_eq!(VITESSE_MAX_ASTEROID, 8.0);
+        assert_eq!(RAYON_MISSILE, 3.0);
+        assert_eq!(TAILLE_ASTEROIDE_BASE, 60.0);
+    }
+
+    #[test]
+    fn test_degats_croissants_avec_la_taille() {
+        let degats = [
+            DEGATS_PETIT_ASTEROIDE,
+            DEGATS_MOYEN_ASTEROIDE,
+            DEGATS_GRAND_ASTEROIDE,
+        ];
+        assert!(degats.windows(2).all(|paire| paire[0] < paire[1]));
+        assert_eq!(
+            (
+                DEGATS_PETIT_ASTEROIDE,
+                DEGATS_MOYEN_ASTEROIDE,
+                DEGATS_GRAND_ASTEROIDE
+            ),
+            (10, 15, 25)
+        );
+    }
+}
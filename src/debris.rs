@@ -0,0 +1,136 @@
+//! Module pour les nuages de débris laissés par les grands astéroïdes détruits.
+//! Ce module contient la structure `NuageDebris` : une zone circulaire qui reste quelques secondes à l'endroit
+//! de l'explosion et abîme le vaisseau tant qu'il reste dedans. Le joueur est ainsi poussé à bouger
+//! après avoir détruit un grand astéroïde, plutôt que de rester sur place.
+
+use macroquad::prelude::*;
+
+/// Nuage de débris, qui se dissipe peu à peu.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NuageDebris {
+    position: Vec2,          // Centre du nuage (position de l'astéroïde détruit)
+    rayon: f32,              // Rayon du nuage
+    duree_restante: f32,     // Temps (en secondes) avant que le nuage se dissipe
+    degats_par_seconde: f32, // Dégâts infligés chaque seconde au vaisseau qui reste dans le nuage
+    degats_accumules: f32,   // Dégâts subis mais pas encore infligés (moins d'un point)
+}
+
+impl NuageDebris {
+    /// Durée de vie d'un nuage (en secondes).
+    pub const DUREE: f32 = 2.5;
+
+    /// Rayon d'un nuage laissé par un grand astéroïde.
+    pub const RAYON: f32 = 60.0;
+
+    /// Dégâts infligés chaque seconde au vaisseau qui reste dans un nuage.
+    pub const DEGATS_PAR_SECONDE: f32 = 20.0;
+
+    /// Crée un nuage de débris.
+    /// # Arguments
+    /// - `position` - Centre du nuage.
+    /// - `rayon` - Rayon du nuage.
+    /// - `duree` - Durée de vie du nuage (en secondes).
+    /// - `degats_par_seconde` - Dégâts infligés chaque seconde au vaisseau qui reste dedans.
+    pub fn new(position: Vec2, rayon: f32, duree: f32, degats_par_seconde: f32) -> Self {
+        Self {
+            position,
+            rayon,
+            duree_restante: duree,
+            degats_par_seconde,
+            degats_accumules: 0.0,
+        }
+    }
+
+    /// Crée le nuage laissé par un grand astéroïde détruit, avec les valeurs par défaut.
+    pub fn apres_destruction(position: Vec2) -> Self {
+        Self::new(position, Self::RAYON, Self::DUREE, Self::DEGATS_PAR_SECONDE)
+    }
+
+    /// Indique si le nuage s'est dissipé.
+    pub fn est_dissipe(&self) -> bool {
+        self.duree_restante <= 0.0
+    }
+
+    /// Indique si le vaisseau (un cercle) touche le nuage.
+    /// # Arguments
+    /// - `position` - Centre du vaisseau.
+    /// - `rayon` - Rayon du vaisseau.
+    pub fn contient(&self, position: Vec2, rayon: f32) -> bool {
+        !self.est_dissipe() && self.position.distance(position) < self.rayon + rayon
+    }
+
+    /// Fait vieillir le nuage et calcule les dégâts infligés au vaisseau pendant cette frame.
+    /// Les dégâts s'accumulent tant que le vaisseau reste dans le nuage ; ils sont perdus s'il en sort.
+    /// # Arguments
+    /// - `delta_time` - Temps écoulé (en secondes) depuis la dernière frame.
+    /// - `position_vaisseau` - Centre du vaisseau.
+    /// - `rayon_vaisseau` - Rayon du vaisseau.
+    /// # Retourne les points de dégâts à infliger au vaisseau (0 s'il est hors du nuage).
+    pub fn avancer(&mut self, delta_time: f32, position_vaisseau: Vec2, rayon_vaisseau: f32) -> u8 {
+        let dedans = self.contient(position_vaisseau, rayon_vaisseau);
+        self.duree_restante = (self.duree_restante - delta_time).max(0.0);
+        if !dedans {
+            self.degats_accumules = 0.0;
+            return 0;
+        }
+        self.degats_accumules += self.degats_par_seconde * delta_time;
+        let degats = self.degats_accumules.floor();
+        self.degats_accumules -= degats;
+        degats as u8
+    }
+
+    /// Dessine le nuage, de plus en plus transparent à mesure qu'il se dissipe.
+    pub fn draw(&self) {
+        if self.est_dissipe() {
+            return;
+        }
+        let alpha = (self.duree_restante / Self::DUREE).min(1.0) * 0.35;
+        draw_circle(
+            self.position.x,
+            self.position.y,
+            self.rayon,
+            Color::new(0.55, 0.45, 0.35, alpha),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Le nuage se dissipe après sa durée de vie, et ne fait plus rien ensuite.
+    #[test]
+    fn test_duree_de_vie() {
+        let mut nuage = NuageDebris::apres_destruction(vec2(100.0, 100.0));
+        let loin = vec2(500.0, 500.0);
+        let frames = (NuageDebris::DUREE * 60.0) as usize;
+        for _ in 0..frames - 1 {
+            nuage.avancer(1.0 / 60.0, loin, 15.0);
+        }
+        assert!(!nuage.est_dissipe());
+        for _ in 0..2 {
+            nuage.avancer(1.0 / 60.0, loin, 15.0);
+        }
+        assert!(nuage.est_dissipe());
+        assert!(!nuage.contient(vec2(100.0, 100.0), 15.0));
+        assert_eq!(nuage.avancer(1.0, vec2(100.0, 100.0), 15.0), 0);
+    }
+
+    /// Le vaisseau qui reste dans le nuage subit des dégâts réguliers ; hors du nuage, il n'en subit pas.
+    #[test]
+    fn test_degats_dans_le_nuage() {
+        let mut nuage = NuageDebris::new(vec2(100.0, 100.0), 50.0, 10.0, 20.0);
+        assert!(nuage.contient(vec2(160.0, 100.0), 15.0)); // Le bord du vaisseau touche le nuage.
+        assert!(!nuage.contient(vec2(170.0, 100.0), 15.0));
+
+        let degats: u32 = (0..60)
+            .map(|_| nuage.avancer(1.0 / 60.0, vec2(110.0, 100.0), 15.0) as u32)
+            .sum();
+        assert!((19..=20).contains(&degats)); // 20 points par seconde
+
+        let degats: u32 = (0..60)
+            .map(|_| nuage.avancer(1.0 / 60.0, vec2(300.0, 100.0), 15.0) as u32)
+            .sum();
+        assert_eq!(degats, 0);
+    }
+}
@@ -0,0 +1,238 @@
+//! Module pour les effets visuels du jeu.
+//! Ce module contient la structure `EclatBouclier`, l'effet joué quand le bouclier du vaisseau tombe à 0 :
+//! un flash au centre du vaisseau et un anneau de particules qui s'éloigne avant de disparaître,
+//! et la structure `Explosion`, la gerbe de particules d'un astéroïde détruit.
+//! Les effets sont purement visuels et ne changent rien à la logique du jeu.
+//! Leur part de hasard est tirée d'un générateur initialisé avec la graine de la partie (`World::rng_effets`) :
+//! un replay reproduit donc exactement les mêmes effets.
+
+use ::rand::Rng;
+use macroquad::prelude::*;
+
+/// Effet d'éclatement du bouclier : un anneau de particules qui s'agrandit autour du vaisseau.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EclatBouclier {
+    centre: Vec2, // Position du vaisseau au moment où le bouclier s'est brisé
+    debut: f64,   // Moment (en secondes) où l'effet a commencé
+}
+
+impl EclatBouclier {
+    /// Nombre de particules de l'anneau.
+    pub const NOMBRE_PARTICULES: usize = 24;
+
+    /// Durée totale de l'effet (en secondes).
+    pub const DUREE: f64 = 0.8;
+
+    /// Vitesse à laquelle l'anneau s'éloigne du vaisseau (en pixels par seconde).
+    pub const VITESSE_ANNEAU: f32 = 150.0;
+
+    /// Durée du flash au début de l'effet (en secondes).
+    pub const DUREE_FLASH: f64 = 0.15;
+
+    /// Démarre un nouvel effet autour de la position donnée.
+    /// # Arguments
+    /// - `centre` - Position du vaisseau.
+    /// - `temps` - Temps actuel en secondes (`get_time()`).
+    pub fn nouveau(centre: Vec2, temps: f64) -> Self {
+        Self {
+            centre,
+            debut: temps,
+        }
+    }
+
+    /// Indique si l'effet est terminé.
+    pub fn est_termine(&self, temps: f64) -> bool {
+        temps - self.debut >= Self::DUREE
+    }
+
+    /// Retourne la position des particules de l'anneau au temps donné, réparties régulièrement autour du centre.
+    pub fn particules(&self, temps: f64) -> Vec<Vec2> {
+        let rayon = Self::VITESSE_ANNEAU * (temps - self.debut).max(0.0) as f32;
+        (0..Self::NOMBRE_PARTICULES)
+            .map(|i| {
+                let angle = i as f32 * std::f32::consts::TAU / Self::NOMBRE_PARTICULES as f32;
+                self.centre + vec2(angle.cos(), angle.sin()) * rayon
+            })
+            .collect()
+    }
+
+    /// Dessine l'effet : le flash puis l'anneau de particules, qui s'efface peu à peu.
+    /// Ne dessine rien une fois l'effet terminé.
+    pub fn draw(&self, temps: f64) {
+        if self.est_termine(temps) {
+            return;
+        }
+        let ecoule = temps - self.debut;
+        if ecoule < Self::DUREE_FLASH {
+            let alpha = 1.0 - (ecoule / Self::DUREE_FLASH) as f32;
+            draw_circle(
+                self.centre.x,
+                self.centre.y,
+                40.0,
+                Color::new(1.0, 1.0, 1.0, alpha),
+            );
+        }
+        let alpha = 1.0 - (ecoule / Self::DUREE) as f32;
+        for particule in self.particules(temps) {
+            draw_circle(
+                particule.x,
+                particule.y,
+                2.5,
+                Color::new(0.0, 0.9, 0.3, alpha),
+            );
+        }
+    }
+}
+
+/// Retourne les paramètres de l'explosion d'un astéroïde selon sa taille :
+/// un grand astéroïde projette beaucoup de grosses particules sombres, un petit une légère bouffée claire.
+/// # Retourne le nombre de particules, leur rayon (en pixels) et leur couleur.
+pub fn parametres_explosion(taille: u8) -> (usize, f32, Color) {
+    match taille {
+        1 => (6, 1.5, Color::new(0.9, 0.9, 0.85, 1.0)),
+        2 => (12, 2.5, Color::new(0.7, 0.65, 0.6, 1.0)),
+        3 => (20, 3.5, Color::new(0.45, 0.4, 0.35, 1.0)),
+        _ => (10, 2.0, LIGHTGRAY), // Taille inconnue : explosion moyenne.
+    }
+}
+
+/// Explosion d'un astéroïde détruit : des particules projetées depuis sa position, qui s'effacent peu à peu.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Explosion {
+    centre: Vec2,          // Position de l'astéroïde au moment de sa destruction
+    debut: f64,            // Moment (en secondes) où l'explosion a commencé
+    vitesses: Vec<Vec2>,   // Vitesse de chaque particule projetée (en pixels par seconde)
+    rayon_particules: f32, // Rayon de chaque particule
+    couleur: Color,        // Couleur des particules
+}
+
+impl Explosion {
+    /// Durée totale de l'explosion (en secondes).
+    pub const DUREE: f64 = 0.6;
+
+    /// Vitesse maximale des particules (en pixels par seconde).
+    pub const VITESSE_PARTICULES: f32 = 120.0;
+
+    /// Démarre l'explosion d'un astéroïde, avec les paramètres de `parametres_explosion`.
+    /// Les particules partent dans toutes les directions, chacune légèrement déviée et à une vitesse tirée au sort,
+    /// pour que la gerbe ne forme pas un anneau régulier.
+    /// # Arguments
+    /// - `centre` - Position de l'astéroïde détruit.
+    /// - `taille` - Taille de l'astéroïde détruit (1, 2 ou 3).
+    /// - `temps` - Temps actuel en secondes (`get_time()`).
+    /// - `rng` - Générateur aléatoire des effets : à graine égale, l'explosion est la même.
+    pub fn nouvelle(centre: Vec2, taille: u8, temps: f64, rng: &mut impl Rng) -> Self {
+        let (nombre_particules, rayon_particules, couleur) = parametres_explosion(taille);
+        let ecart = std::f32::consts::TAU / nombre_particules as f32; // Angle entre deux particules voisines
+        let vitesses = (0..nombre_particules)
+            .map(|i| {
+                let angle = (i as f32 + rng.gen_range(-0.4..=0.4)) * ecart;
+                Vec2::from_angle(angle) * Self::VITESSE_PARTICULES * rng.gen_range(0.5..=1.0)
+            })
+            .collect();
+        Self {
+            centre,
+            debut: temps,
+            vitesses,
+            rayon_particules,
+            couleur,
+        }
+    }
+
+    /// Indique si l'explosion est terminée.
+    pub fn est_terminee(&self, temps: f64) -> bool {
+        temps - self.debut >= Self::DUREE
+    }
+
+    /// Retourne la position des particules au temps donné.
+    pub fn particules(&self, temps: f64) -> Vec<Vec2> {
+        let ecoule = (temps - self.debut).max(0.0) as f32;
+        self.vitesses
+            .iter()
+            .map(|vitesse| self.centre + *vitesse * ecoule)
+            .collect()
+    }
+
+    /// Dessine les particules, qui s'effacent peu à peu. Ne dessine rien une fois l'explosion terminée.
+    pub fn draw(&self, temps: f64) {
+        if self.est_terminee(temps) {
+            return;
+        }
+        let alpha = 1.0 - ((temps - self.debut) / Self::DUREE) as f32;
+        let couleur = Color {
+            a: alpha,
+            ..self.couleur
+        };
+        for particule in self.particules(temps) {
+            draw_circle(particule.x, particule.y, self.rayon_particules, couleur);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::rand::rngs::SmallRng;
+    use ::rand::SeedableRng;
+
+    /// L'anneau s'agrandit au cours du temps et l'effet se termine après sa durée.
+    #[test]
+    fn test_anneau_s_agrandit() {
+        let centre = vec2(400.0, 300.0);
+        let eclat = EclatBouclier::nouveau(centre, 10.0);
+        assert_eq!(
+            eclat.particules(10.0).len(),
+            EclatBouclier::NOMBRE_PARTICULES
+        );
+        assert!(eclat.particules(10.0).iter().all(|p| *p == centre));
+
+        let rayon = |t| eclat.particules(t)[0].distance(centre);
+        assert!(rayon(10.2) > 0.0);
+        assert!(rayon(10.5) > rayon(10.2));
+
+        assert!(!eclat.est_termine(10.5));
+        assert!(eclat.est_termine(10.0 + EclatBouclier::DUREE));
+    }
+
+    /// Plus l'astéroïde est grand, plus son explosion a de particules, grosses et sombres.
+    #[test]
+    fn test_parametres_explosion() {
+        let luminosite = |couleur: Color| couleur.r + couleur.g + couleur.b;
+        let (nombre1, rayon1, couleur1) = parametres_explosion(1);
+        let (nombre2, rayon2, couleur2) = parametres_explosion(2);
+        let (nombre3, rayon3, couleur3) = parametres_explosion(3);
+        assert!(nombre1 < nombre2 && nombre2 < nombre3);
+        assert!(rayon1 < rayon2 && rayon2 < rayon3);
+        assert!(luminosite(couleur1) > luminosite(couleur2));
+        assert!(luminosite(couleur2) > luminosite(couleur3));
+
+        // Taille inconnue : explosion par défaut.
+        assert_eq!(parametres_explosion(0), (10, 2.0, LIGHTGRAY));
+        assert_eq!(parametres_explosion(7), parametres_explosion(0));
+
+        // L'explosion utilise ces paramètres.
+        let mut rng = SmallRng::seed_from_u64(1);
+        let explosion = Explosion::nouvelle(vec2(100.0, 100.0), 3, 0.0, &mut rng);
+        assert_eq!(explosion.particules(0.3).len(), nombre3);
+        assert!(explosion.est_terminee(Explosion::DUREE));
+    }
+
+    /// Deux explosions créées avec des générateurs de même graine sont identiques (replays),
+    /// et les particules ne dépassent jamais la vitesse maximale.
+    #[test]
+    fn test_explosion_deterministe() {
+        let explosion = |graine| {
+            let mut rng = SmallRng::seed_from_u64(graine);
+            Explosion::nouvelle(vec2(200.0, 150.0), 2, 5.0, &mut rng)
+        };
+        assert_eq!(explosion(42), explosion(42));
+        assert_eq!(explosion(42).particules(5.3), explosion(42).particules(5.3));
+        assert_ne!(explosion(42), explosion(43));
+
+        let centre = vec2(200.0, 150.0);
+        assert!(explosion(7)
+            .particules(6.0)
+            .iter()
+            .all(|p| p.distance(centre) <= Explosion::VITESSE_PARTICULES + 1e-3));
+    }
+}
@@ -0,0 +1,172 @@
+//! Module pour les formations d'astéroïdes.
+//! Ce module contient l'énumération `Formation` et les fonctions qui placent les astéroïdes d'une vague
+//! en ligne, en V ou en anneau. Tous les astéroïdes d'une formation partent avec la même vitesse :
+//! ils avancent ensemble au début de la vague, jusqu'à ce que les collisions et les tirs les dispersent.
+
+use crate::asteroid::Asteroid;
+use ::rand::Rng;
+use macroquad::prelude::*;
+
+/// Distance entre deux astéroïdes voisins d'une ligne ou d'une branche du V.
+pub const ESPACEMENT: f32 = 90.0;
+
+/// Forme dans laquelle les astéroïdes d'une vague apparaissent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Formation {
+    Ligne,  // Alignés perpendiculairement à leur direction
+    V,      // En V, la pointe en tête
+    Cercle, // En anneau autour du centre de l'écran
+}
+
+impl Formation {
+    /// Retourne la formation correspondant à son nom dans un fichier de vagues (`ligne`, `v` ou `cercle`),
+    /// ou `None` si le nom est inconnu.
+    pub fn depuis_nom(nom: &str) -> Option<Self> {
+        match nom {
+            "ligne" => Some(Self::Ligne),
+            "v" => Some(Self::V),
+            "cercle" => Some(Self::Cercle),
+            _ => None,
+        }
+    }
+
+    /// Génère les astéroïdes de la formation, dans une direction tirée au sort.
+    /// La ligne et le V partent d'un côté de l'écran et le traversent ; l'anneau entoure le centre de l'écran.
+    /// # Arguments
+    /// - `tailles` : Taille de chaque astéroïde.
+    /// - `rng` : Générateur aléatoire (direction de la formation).
+    /// - `ecran` : Dimensions de l'écran (largeur, hauteur).
+    pub fn generer(&self, tailles: &[u8], rng: &mut impl Rng, ecran: Vec2) -> Vec<Asteroid> {
+        let direction = Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU));
+        let centre = ecran / 2.0;
+        let recul = ecran.min_element() * 0.35; // Distance entre le centre de l'écran et le départ de la formation
+        match self {
+            Self::Ligne => formation_ligne(tailles, centre - direction * recul, direction),
+            Self::V => formation_v(tailles, centre - direction * recul, direction),
+            Self::Cercle => formation_cercle(tailles, centre, recul, direction),
+        }
+    }
+}
+
+/// Place les astéroïdes sur une ligne perpendiculaire à leur vitesse, centrée sur le point donné.
+/// # Arguments
+/// - `tailles` : Taille de chaque astéroïde.
+/// - `centre` : Milieu de la ligne.
+/// - `vitesse` : Vitesse commune de tous les astéroïdes.
+pub fn formation_ligne(tailles: &[u8], centre: Vec2, vitesse: Vec2) -> Vec<Asteroid> {
+    let travers = direction_perpendiculaire(vitesse);
+    let milieu = (tailles.len() as f32 - 1.0) / 2.0;
+    tailles
+        .iter()
+        .enumerate()
+        .map(|(i, taille)| {
+            let position = centre + travers * (i as f32 - milieu) * ESPACEMENT;
+            Asteroid::scripté(*taille, position, vitesse)
+        })
+        .collect()
+}
+
+/// Place les astéroïdes en V : le premier à la pointe, les suivants en arrière, un à gauche, un à droite.
+/// # Arguments
+/// - `tailles` : Taille de chaque astéroïde (le premier est à la pointe).
+/// - `pointe` : Position de la pointe du V.
+/// - `vitesse` : Vitesse commune de tous les astéroïdes (la pointe est en tête).
+pub fn formation_v(tailles: &[u8], pointe: Vec2, vitesse: Vec2) -> Vec<Asteroid> {
+    let avant = direction_ou_droite(vitesse);
+    let travers = direction_perpendiculaire(vitesse);
+    tailles
+        .iter()
+        .enumerate()
+        .map(|(i, taille)| {
+            let rang = i.div_ceil(2) as f32; // 0 pour la pointe, puis 1, 1, 2, 2...
+            let cote = if i % 2 == 1 { 1.0 } else { -1.0 };
+            let position = pointe - avant * rang * ESPACEMENT + travers * cote * rang * ESPACEMENT;
+            Asteroid::scripté(*taille, position, vitesse)
+        })
+        .collect()
+}
+
+/// Place les astéroïdes régulièrement sur un cercle.
+/// # Arguments
+/// - `tailles` : Taille de chaque astéroïde.
+/// - `centre` : Centre du cercle.
+/// - `rayon` : Rayon du cercle.
+/// - `vitesse` : Vitesse commune de tous les astéroïdes.
+pub fn formation_cercle(tailles: &[u8], centre: Vec2, rayon: f32, vitesse: Vec2) -> Vec<Asteroid> {
+    tailles
+        .iter()
+        .enumerate()
+        .map(|(i, taille)| {
+            let angle = i as f32 * std::f32::consts::TAU / tailles.len() as f32;
+            Asteroid::scripté(*taille, centre + Vec2::from_angle(angle) * rayon, vitesse)
+        })
+        .collect()
+}
+
+/// Retourne la direction de la vitesse, ou la droite si la vitesse est nulle.
+fn direction_ou_droite(vitesse: Vec2) -> Vec2 {
+    vitesse.try_normalize().unwrap_or(Vec2::X)
+}
+
+/// Retourne une direction perpendiculaire à la vitesse.
+fn direction_perpendiculaire(vitesse: Vec2) -> Vec2 {
+    direction_ou_droite(vitesse).perp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Les astéroïdes d'un anneau sont tous à la même distance du centre, et partent ensemble.
+    #[test]
+    fn test_cercle_equidistant() {
+        let centre = vec2(400.0, 300.0);
+        let asteroids = formation_cercle(&[3, 2, 2, 1, 1, 1], centre, 200.0, vec2(0.0, 1.0));
+        assert_eq!(asteroids.len(), 6);
+        for asteroid in &asteroids {
+            assert!((asteroid.get_position().distance(centre) - 200.0).abs() < 1e-3);
+            assert_eq!(asteroid.get_vitesse(), vec2(0.0, 1.0));
+        }
+        // Ils sont répartis régulièrement : deux voisins sont toujours à la même distance.
+        let ecarts: Vec<f32> = asteroids
+            .windows(2)
+            .map(|paire| paire[0].get_position().distance(paire[1].get_position()))
+            .collect();
+        assert!(ecarts.iter().all(|ecart| (ecart - ecarts[0]).abs() < 1e-3));
+    }
+
+    /// Les astéroïdes d'une ligne sont alignés, perpendiculairement à leur vitesse commune.
+    #[test]
+    fn test_ligne_alignee() {
+        let vitesse = vec2(1.0, 1.0).normalize();
+        let asteroids = formation_ligne(&[2, 2, 2, 2, 2], vec2(300.0, 300.0), vitesse);
+        let positions: Vec<Vec2> = asteroids.iter().map(|a| a.get_position()).collect();
+        let direction = (positions[1] - positions[0]).normalize();
+        for position in &positions[2..] {
+            let decalage = *position - positions[0];
+            assert!(decalage.perp_dot(direction).abs() < 1e-3); // Alignés
+        }
+        assert!(direction.dot(vitesse).abs() < 1e-5); // Perpendiculaire à la vitesse
+        assert!(asteroids.iter().all(|a| a.get_vitesse() == vitesse));
+        assert_eq!(positions[2], vec2(300.0, 300.0)); // Le milieu de la ligne est au centre demandé.
+    }
+
+    /// Dans un V, la pointe est en tête et les branches sont symétriques.
+    #[test]
+    fn test_v_symetrique() {
+        let asteroids = formation_v(&[3, 2, 2, 1, 1], vec2(400.0, 300.0), vec2(1.0, 0.0));
+        let positions: Vec<Vec2> = asteroids.iter().map(|a| a.get_position()).collect();
+        assert_eq!(positions[0], vec2(400.0, 300.0));
+        assert!(positions[1..].iter().all(|p| p.x < 400.0)); // Tous derrière la pointe
+        assert_eq!(positions[1].x, positions[2].x);
+        assert_eq!(positions[1].y - 300.0, 300.0 - positions[2].y);
+    }
+
+    #[test]
+    fn test_noms_formations() {
+        assert_eq!(Formation::depuis_nom("cercle"), Some(Formation::Cercle));
+        assert_eq!(Formation::depuis_nom("v"), Some(Formation::V));
+        assert_eq!(Formation::depuis_nom("ligne"), Some(Formation::Ligne));
+        assert_eq!(Formation::depuis_nom("carre"), None);
+    }
+}
@@ -0,0 +1,106 @@
+//! Module pour le mode "horde", un mode de survie sans fin.
+//! Ce module contient la structure `ModeHorde` : au lieu de vagues successives, les astéroïdes apparaissent
+//! en continu, de plus en plus vite, et le joueur tient le plus longtemps possible.
+//! Le niveau n'a plus de sens dans ce mode : c'est le temps de survie qui compte.
+
+/// Minuteur d'apparition des astéroïdes du mode horde.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModeHorde {
+    temps_ecoule: f32, // Temps de survie (en secondes) depuis le début de la partie
+    prochaine_apparition: f32, // Temps restant (en secondes) avant l'apparition du prochain astéroïde
+}
+
+impl Default for ModeHorde {
+    /// Démarre une nouvelle partie en mode horde : le premier astéroïde apparaît après `INTERVALLE_INITIAL`.
+    fn default() -> Self {
+        Self {
+            temps_ecoule: 0.0,
+            prochaine_apparition: Self::INTERVALLE_INITIAL,
+        }
+    }
+}
+
+impl ModeHorde {
+    /// Intervalle (en secondes) entre deux apparitions au début de la partie.
+    pub const INTERVALLE_INITIAL: f32 = 3.0;
+
+    /// Intervalle minimal entre deux apparitions, atteint après quelques minutes.
+    pub const INTERVALLE_MIN: f32 = 0.5;
+
+    /// Réduction de l'intervalle (en secondes) pour chaque seconde de survie.
+    pub const REDUCTION_PAR_SECONDE: f32 = 0.01;
+
+    /// Nombre maximal d'astéroïdes présents en même temps : au-delà, plus rien n'apparaît.
+    pub const MAX_SIMULTANES: usize = 25;
+
+    /// Retourne le temps de survie (en secondes).
+    pub fn temps_ecoule(&self) -> f32 {
+        self.temps_ecoule
+    }
+
+    /// Retourne l'intervalle actuel entre deux apparitions, qui diminue avec le temps de survie.
+    pub fn intervalle_apparition(&self) -> f32 {
+        (Self::INTERVALLE_INITIAL - self.temps_ecoule * Self::REDUCTION_PAR_SECONDE)
+            .max(Self::INTERVALLE_MIN)
+    }
+
+    /// Fait avancer le temps, et indique si un astéroïde doit apparaître à cette frame.
+    /// Tant que le nombre maximal d'astéroïdes est atteint, l'apparition attend qu'une place se libère.
+    /// # Arguments
+    /// - `delta_time` - Temps écoulé (en secondes) depuis la dernière frame.
+    /// - `nombre_asteroides` - Nombre d'astéroïdes actuellement à l'écran.
+    pub fn avancer(&mut self, delta_time: f32, nombre_asteroides: usize) -> bool {
+        self.temps_ecoule += delta_time;
+        self.prochaine_apparition -= delta_time;
+        if self.prochaine_apparition > 0.0 || nombre_asteroides >= Self::MAX_SIMULTANES {
+            return false;
+        }
+        self.prochaine_apparition = self.intervalle_apparition();
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intervalle_diminue_avec_le_temps() {
+        let mut horde = ModeHorde::default();
+        let mut precedent = horde.intervalle_apparition();
+        assert_eq!(precedent, ModeHorde::INTERVALLE_INITIAL);
+        for _ in 0..10 {
+            for _ in 0..60 {
+                horde.avancer(1.0, 0);
+            }
+            let intervalle = horde.intervalle_apparition();
+            assert!(intervalle <= precedent);
+            precedent = intervalle;
+        }
+        assert!(precedent < ModeHorde::INTERVALLE_INITIAL);
+        assert_eq!(precedent, ModeHorde::INTERVALLE_MIN); // Après 10 minutes, le minimum est atteint.
+    }
+
+    /// Les astéroïdes apparaissent de plus en plus souvent, mais jamais au-delà du nombre maximal.
+    #[test]
+    fn test_nombre_maximal_respecte() {
+        let mut horde = ModeHorde::default();
+        let mut asteroides = 0;
+        let mut apparitions_premiere_minute = 0;
+        for frame in 0..60 * 600 {
+            if horde.avancer(1.0 / 60.0, asteroides) {
+                asteroides += 1;
+                if frame < 60 * 60 {
+                    apparitions_premiere_minute += 1;
+                }
+            }
+            assert!(asteroides <= ModeHorde::MAX_SIMULTANES);
+        }
+        assert_eq!(asteroides, ModeHorde::MAX_SIMULTANES);
+        assert!(apparitions_premiere_minute < ModeHorde::MAX_SIMULTANES);
+
+        // Dès qu'une place se libère, un nouvel astéroïde peut apparaître.
+        asteroides -= 1;
+        assert!(horde.avancer(1.0 / 60.0, asteroides));
+    }
+}
@@ -0,0 +1,91 @@
+//! Module pour gérer les entrées du joueur.
+//! Ce module contient la structure `InputState` qui regroupe l'état des commandes du vaisseau pour une frame.
+//! Séparer la lecture du clavier de la logique du vaisseau permet de tester `Spaceship::maj_pos`
+//! sans fenêtre macroquad, et de fournir des entrées synthétiques (démo, pilote automatique...).
+
+use macroquad::prelude::*;
+
+/// Structure représentant l'état des commandes pour une frame.
+/// Chaque booléen vaut `true` si la commande correspondante est active.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct InputState {
+    pub gauche: bool,         // Tourner à gauche
+    pub droite: bool,         // Tourner à droite
+    pub haut: bool,           // Accélérer
+    pub bas: bool,            // Rétro-poussée
+    pub tir: bool,            // Tirer un missile
+    pub ralentissement: bool, // Déployer un champ de ralentissement
+    pub barriere: bool,       // Déployer une barrière devant le vaisseau
+    pub bombe: bool,          // Faire exploser une bombe (achetée à la boutique)
+    pub visee: Option<Vec2>, // Point visé (souris) : s'il est défini, le vaisseau s'oriente vers lui
+}
+
+impl InputState {
+    /// Lit l'état actuel du clavier.
+    /// # Retourne un 'InputState' correspondant aux touches enfoncées.
+    pub fn clavier() -> Self {
+        Self {
+            gauche: is_key_down(KeyCode::Left),
+            droite: is_key_down(KeyCode::Right),
+            haut: is_key_down(KeyCode::Up),
+            bas: is_key_down(KeyCode::Down),
+            tir: is_key_pressed(KeyCode::Space),
+            ralentissement: is_key_pressed(KeyCode::X),
+            barriere: is_key_pressed(KeyCode::B),
+            bombe: is_key_pressed(KeyCode::V),
+            visee: None,
+        }
+    }
+
+    /// Lit le clavier pour les déplacements et la souris pour viser et tirer (clic gauche).
+    /// Le vaisseau s'oriente vers le curseur : les flèches gauche/droite ne le font plus tourner
+    /// (elles servent seulement en poussée directe).
+    /// # Arguments
+    /// - `origine` - Coin haut gauche de la zone de jeu dans la fenêtre : le point visé est relatif à la zone de jeu.
+    pub fn clavier_et_souris(origine: Vec2) -> Self {
+        let (x, y) = mouse_position();
+        Self {
+            tir: is_key_pressed(KeyCode::Space) || is_mouse_button_pressed(MouseButton::Left),
+            visee: Some(vec2(x, y) - origine),
+            ..Self::clavier()
+        }
+    }
+
+    /// Indique si aucune commande n'est active. Le point visé ne compte pas : la souris est lue à chaque frame,
+    /// même quand le joueur ne joue pas.
+    pub fn est_vide(&self) -> bool {
+        Self {
+            visee: None,
+            ..*self
+        } == Self::default()
+    }
+
+    /// Retourne les mêmes commandes avec gauche et droite échangées.
+    /// Sert aux entrées synthétiques (pilote automatique) quand le réglage `inverser_rotation` est activé,
+    /// pour que le vaisseau tourne quand même du bon côté.
+    pub fn rotation_inversee(self) -> Self {
+        Self {
+            gauche: self.droite,
+            droite: self.gauche,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Le point visé seul ne compte pas comme une commande.
+    #[test]
+    fn test_est_vide() {
+        assert!(InputState::default().est_vide());
+        let visee = InputState {
+            visee: Some(vec2(400.0, 300.0)),
+            ..Default::default()
+        };
+        assert!(visee.est_vide());
+        let tir = InputState { tir: true, ..visee };
+        assert!(!tir.est_vide());
+    }
+}
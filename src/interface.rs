@@ -0,0 +1,250 @@
+//! Module pour la mise à l'échelle de l'interface (HUD).
+//! Les tailles et positions de l'interface sont données en pixels pour un écran de référence (800x600,
+//! la fenêtre par défaut), puis converties selon la taille réelle de l'écran et l'échelle choisie dans les paramètres.
+//! L'interface reste ainsi lisible sur un grand écran en plein écran.
+//! Ce module calcule aussi la disposition de l'indicateur des vies, dont le coin et le style sont réglables,
+//! et la zone de jeu : la partie de la fenêtre où se déroule le jeu quand ses proportions sont imposées.
+
+use macroquad::prelude::*;
+
+/// Dimensions de l'écran pour lesquelles l'interface a été dessinée.
+pub const ECRAN_REFERENCE: Vec2 = vec2(800.0, 600.0);
+
+/// Convertit une longueur de l'écran de référence en longueur pour l'écran actuel.
+/// La même proportion est appliquée horizontalement et verticalement (la plus petite des deux),
+/// pour que l'interface ne soit pas déformée.
+/// # Arguments
+/// - `pixels` - Longueur en pixels sur l'écran de référence.
+/// - `ecran` - Dimensions de l'écran actuel (largeur, hauteur).
+/// - `echelle` - Facteur d'échelle de l'interface choisi dans les paramètres (1.0 = taille normale).
+pub fn taille_relative(pixels: f32, ecran: Vec2, echelle: f32) -> f32 {
+    let proportion = (ecran.x / ECRAN_REFERENCE.x).min(ecran.y / ECRAN_REFERENCE.y);
+    pixels * proportion * echelle
+}
+
+/// Coin de l'écran dans lequel un élément de l'interface est affiché.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CoinEcran {
+    HautGauche,
+    HautDroite,
+    BasGauche,
+    BasDroite,
+}
+
+impl CoinEcran {
+    /// Retourne le coin correspondant à son nom sur la ligne de commande
+    /// (`haut-gauche`, `haut-droite`, `bas-gauche` ou `bas-droite`), ou `None` si le nom est inconnu.
+    pub fn depuis_nom(nom: &str) -> Option<Self> {
+        match nom {
+            "haut-gauche" => Some(Self::HautGauche),
+            "haut-droite" => Some(Self::HautDroite),
+            "bas-gauche" => Some(Self::BasGauche),
+            "bas-droite" => Some(Self::BasDroite),
+            _ => None,
+        }
+    }
+}
+
+/// Style de l'indicateur des vies.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StyleVies {
+    Nombre, // Texte "Vies: N"
+    Icones, // Une petite icône de vaisseau par vie
+}
+
+impl StyleVies {
+    /// Retourne le style correspondant à son nom sur la ligne de commande (`nombre` ou `icones`),
+    /// ou `None` si le nom est inconnu.
+    pub fn depuis_nom(nom: &str) -> Option<Self> {
+        match nom {
+            "nombre" => Some(Self::Nombre),
+            "icones" => Some(Self::Icones),
+            _ => None,
+        }
+    }
+}
+
+/// Distance (sur l'écran de référence) entre l'indicateur des vies et les bords de l'écran.
+pub const MARGE_VIES: f32 = 20.0;
+
+/// Hauteur (sur l'écran de référence) de l'indicateur des vies.
+pub const HAUTEUR_VIES: f32 = 24.0;
+
+/// Espace (sur l'écran de référence) occupé par chaque icône de vie, d'un centre à l'autre.
+pub const ESPACE_ICONES_VIES: f32 = 24.0;
+
+/// Haut de l'indicateur dans les coins du haut (sur l'écran de référence) : à gauche sous le niveau,
+/// à droite sous le bouclier et la jauge de danger.
+fn haut_vies(coin: CoinEcran) -> f32 {
+    match coin {
+        CoinEcran::HautGauche => 40.0,
+        CoinEcran::HautDroite => 100.0,
+        CoinEcran::BasGauche | CoinEcran::BasDroite => 0.0,
+    }
+}
+
+/// Calcule le coin supérieur gauche de l'indicateur des vies, collé au coin demandé.
+/// # Arguments
+/// - `coin` - Coin de l'écran choisi dans les paramètres.
+/// - `largeur` - Largeur de l'indicateur (en pixels de l'écran actuel).
+/// - `ecran` - Dimensions de l'écran actuel (largeur, hauteur).
+/// - `echelle` - Échelle de l'interface.
+pub fn origine_vies(coin: CoinEcran, largeur: f32, ecran: Vec2, echelle: f32) -> Vec2 {
+    let t = |pixels| taille_relative(pixels, ecran, echelle);
+    let x = match coin {
+        CoinEcran::HautGauche | CoinEcran::BasGauche => t(MARGE_VIES),
+        CoinEcran::HautDroite | CoinEcran::BasDroite => ecran.x - t(MARGE_VIES) - largeur,
+    };
+    let y = match coin {
+        CoinEcran::HautGauche | CoinEcran::HautDroite => t(haut_vies(coin)),
+        CoinEcran::BasGauche | CoinEcran::BasDroite => ecran.y - t(MARGE_VIES) - t(HAUTEUR_VIES),
+    };
+    vec2(x, y)
+}
+
+/// Calcule la position du centre de chaque icône de vie, alignées de gauche à droite dans le coin demandé.
+/// # Arguments
+/// - `vies` - Nombre de vies restantes.
+/// - `coin` - Coin de l'écran choisi dans les paramètres.
+/// - `ecran` - Dimensions de l'écran actuel (largeur, hauteur).
+/// - `echelle` - Échelle de l'interface.
+pub fn positions_icones_vies(vies: u8, coin: CoinEcran, ecran: Vec2, echelle: f32) -> Vec<Vec2> {
+    let t = |pixels| taille_relative(pixels, ecran, echelle);
+    let largeur = t(ESPACE_ICONES_VIES) * vies as f32;
+    let origine = origine_vies(coin, largeur, ecran, echelle);
+    (0..vies)
+        .map(|i| {
+            origine
+                + vec2(
+                    t(ESPACE_ICONES_VIES) * (i as f32 + 0.5),
+                    t(HAUTEUR_VIES) / 2.0,
+                )
+        })
+        .collect()
+}
+
+/// Lit des proportions écrites sous la forme `largeur:hauteur` (par exemple `16:9` ou `4:3`).
+/// # Retourne le rapport largeur / hauteur, ou `None` si le texte est mal formé ou une dimension nulle.
+pub fn rapport_depuis_texte(texte: &str) -> Option<f32> {
+    let (largeur, hauteur) = texte.split_once(':')?;
+    let largeur: f32 = largeur.trim().parse().ok()?;
+    let hauteur: f32 = hauteur.trim().parse().ok()?;
+    (largeur > 0.0 && hauteur > 0.0).then_some(largeur / hauteur)
+}
+
+/// Calcule la zone de jeu dans la fenêtre : le plus grand rectangle aux proportions demandées, centré.
+/// Le reste de la fenêtre forme des bandes noires (sur les côtés si la fenêtre est trop large, en haut et en bas
+/// si elle est trop haute). Les distances de rebouclage restent ainsi les mêmes sur tous les écrans.
+/// # Arguments
+/// - `fenetre` - Dimensions de la fenêtre (largeur, hauteur).
+/// - `rapport` - Proportions imposées (largeur / hauteur), ou `None` pour jouer dans toute la fenêtre.
+pub fn zone_de_jeu(fenetre: Vec2, rapport: Option<f32>) -> Rect {
+    let taille = match rapport {
+        Some(rapport) if rapport > 0.0 => {
+            if fenetre.x / fenetre.y > rapport {
+                vec2(fenetre.y * rapport, fenetre.y) // Fenêtre trop large : bandes sur les côtés
+            } else {
+                vec2(fenetre.x, fenetre.x / rapport) // Fenêtre trop haute : bandes en haut et en bas
+            }
+        }
+        _ => fenetre,
+    };
+    let coin = (fenetre - taille) / 2.0;
+    Rect::new(coin.x, coin.y, taille.x, taille.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// La zone de jeu garde les proportions demandées, centrée dans la fenêtre, quelle que soit sa forme.
+    #[test]
+    fn test_zone_de_jeu() {
+        // Écran ultra-large 21:9 avec une zone en 16:9 : bandes sur les côtés.
+        assert_eq!(
+            zone_de_jeu(vec2(2560.0, 1080.0), Some(16.0 / 9.0)),
+            Rect::new(320.0, 0.0, 1920.0, 1080.0)
+        );
+        // Fenêtre 4:3 avec une zone en 16:9 : bandes en haut et en bas.
+        assert_eq!(
+            zone_de_jeu(vec2(800.0, 600.0), Some(16.0 / 9.0)),
+            Rect::new(0.0, 75.0, 800.0, 450.0)
+        );
+        // Fenêtre déjà aux bonnes proportions : toute la fenêtre.
+        assert_eq!(
+            zone_de_jeu(vec2(1920.0, 1080.0), Some(16.0 / 9.0)),
+            Rect::new(0.0, 0.0, 1920.0, 1080.0)
+        );
+        // Fenêtre en portrait avec une zone carrée.
+        assert_eq!(
+            zone_de_jeu(vec2(600.0, 1000.0), Some(1.0)),
+            Rect::new(0.0, 200.0, 600.0, 600.0)
+        );
+        // Sans proportions imposées (ou avec des proportions invalides), le jeu occupe toute la fenêtre.
+        assert_eq!(
+            zone_de_jeu(vec2(2560.0, 1080.0), None),
+            Rect::new(0.0, 0.0, 2560.0, 1080.0)
+        );
+        assert_eq!(
+            zone_de_jeu(vec2(2560.0, 1080.0), Some(0.0)),
+            Rect::new(0.0, 0.0, 2560.0, 1080.0)
+        );
+    }
+
+    #[test]
+    fn test_rapport_depuis_texte() {
+        assert_eq!(rapport_depuis_texte("4:3"), Some(4.0 / 3.0));
+        assert_eq!(rapport_depuis_texte("16:9"), Some(16.0 / 9.0));
+        assert_eq!(rapport_depuis_texte("16/9"), None);
+        assert_eq!(rapport_depuis_texte("16:0"), None);
+        assert_eq!(rapport_depuis_texte("large:9"), None);
+    }
+
+    #[test]
+    fn test_taille_relative() {
+        // Sur l'écran de référence, les tailles ne changent pas.
+        assert_eq!(taille_relative(200.0, ECRAN_REFERENCE, 1.0), 200.0);
+        // En 1600x1200, tout est deux fois plus grand.
+        assert_eq!(taille_relative(200.0, vec2(1600.0, 1200.0), 1.0), 400.0);
+        // En 1920x1080, c'est la hauteur qui limite l'agrandissement (x1.8).
+        assert!((taille_relative(200.0, vec2(1920.0, 1080.0), 1.0) - 360.0).abs() < 1e-3);
+        // L'échelle des paramètres s'ajoute à la proportion de l'écran.
+        assert_eq!(taille_relative(20.0, vec2(1600.0, 1200.0), 1.5), 60.0);
+    }
+
+    /// Les icônes des vies sont alignées dans le coin choisi, sans sortir de l'écran.
+    #[test]
+    fn test_positions_icones_vies() {
+        let ecran = ECRAN_REFERENCE;
+        let positions = |coin| positions_icones_vies(3, coin, ecran, 1.0);
+
+        assert_eq!(
+            positions(CoinEcran::HautGauche),
+            vec![vec2(32.0, 52.0), vec2(56.0, 52.0), vec2(80.0, 52.0)]
+        );
+        assert_eq!(
+            positions(CoinEcran::HautDroite),
+            vec![vec2(720.0, 112.0), vec2(744.0, 112.0), vec2(768.0, 112.0)]
+        );
+        assert_eq!(
+            positions(CoinEcran::BasGauche),
+            vec![vec2(32.0, 568.0), vec2(56.0, 568.0), vec2(80.0, 568.0)]
+        );
+        assert_eq!(
+            positions(CoinEcran::BasDroite),
+            vec![vec2(720.0, 568.0), vec2(744.0, 568.0), vec2(768.0, 568.0)]
+        );
+
+        // Le nombre d'icônes suit le nombre de vies ; à droite, la dernière reste collée à la marge.
+        assert!(positions_icones_vies(0, CoinEcran::BasDroite, ecran, 1.0).is_empty());
+        let cinq = positions_icones_vies(5, CoinEcran::BasDroite, ecran, 1.0);
+        assert_eq!(cinq.len(), 5);
+        assert_eq!(cinq[4], vec2(768.0, 568.0));
+
+        // Sur un écran deux fois plus grand, tout est deux fois plus loin du coin.
+        assert_eq!(
+            positions_icones_vies(1, CoinEcran::HautGauche, ecran * 2.0, 1.0),
+            vec![vec2(64.0, 104.0)]
+        );
+    }
+}
@@ -5,17 +5,68 @@
 //! Le jeu inclut un écran de "Game Over" et la possibilité de recommencer une partie.
 
 // Importation des modules nécessaires
-use asteroid::{positions_asteroides_apres_collision, Asteroid};
-use bonus::Bonus;
+use arguments::Arguments;
+use asteroid::{analyser_disposition, Asteroid};
+use audio::Audio;
+use bonus::RAYON_AIMANT;
+use boutique::Amelioration;
+use console::Console;
+use effets::{EclatBouclier, Explosion};
+use input::InputState;
+use interface::{
+    origine_vies, positions_icones_vies, taille_relative, zone_de_jeu, CoinEcran, StyleVies,
+    HAUTEUR_VIES,
+};
 use macroquad::prelude::*;
 use missile::Missile;
+use pause::{evenement_focus, PauseAuto};
+use photo::{direction_panoramique, PhotoMode};
+use pilote::pilote_auto;
+use replay::{temps_frame, Lecture, Replay, PAS_TEMPS};
+use scores::{SaisieInitiales, TableScores};
+use settings::{Settings, TEXTURE_ASTEROIDS_DEFAUT};
 use spaceship::Spaceship;
+use starfield::Starfield;
+use std::time::Duration;
+use tutoriel::{Astuce, Tutoriel};
+use vague::analyser_vagues;
+use world::World;
 
+mod arguments;
 mod asteroid;
+mod audio;
+mod barriere;
 mod bonus;
+mod boss;
+mod boutique;
+mod cluster;
+mod console;
+mod constantes;
+mod debris;
+mod effets;
+mod formation;
+mod horde;
+mod input;
+mod interface;
 mod missile;
+mod ovni;
+mod pause;
+mod photo;
+mod pilote;
+mod replay;
+mod scores;
+mod settings;
+mod simulation;
 mod spaceship;
+mod starfield;
+mod statistiques;
 mod stellarobject;
+mod transform;
+mod trou_noir;
+mod tutoriel;
+mod vague;
+mod world;
+mod zone;
 
 /// Fonction de configuration du jeu avant son lancement.
 /// Ce `Conf` détermine les paramètres d'affichage de la fenêtre.
@@ -28,46 +79,67 @@ fn window_conf() -> Conf {
     }
 }
 
-/// Fonction pour dessiner les astéroïdes à l'écran ainsi que l'interface du niveau de jeu en haut à gauche.
+/// Fonction pour dessiner les astéroïdes à l'écran.
+/// L'interface (niveau, vies, bouclier...) est dessinée à part par `dessiner_hud`.
 /// # Arguments:
 /// -'asteroids' - Vecteur de référence des astéroïdes présents à l'écran.
-/// -'niveau' - Niveau actuel du jeu
-/// -`background_texture` - Référence à la texture du fond d'écran
+/// -`menace` - Indice de l'astéroïde à entourer (le plus menaçant), s'il y en a un.
+/// -`fond` - Fond d'écran (image ou champ d'étoiles).
 /// - 'texture_asteroids' : Référence à la texture de l'astéroïde.
-fn draw(
-    asteroids: &Vec<Asteroid>,
-    niveau: i32,
-    texture_asteroids: &Texture2D,
-    background_texture: &Texture2D,
-) {
-    draw_background(background_texture); // On dessine le fond d'écran.
-    draw_text(&format!("Level {}", niveau), 20.0, 30.0, 30.0, WHITE); // Affichage du niveau en haut à gauche.
-                                                                      // On parcourt chaque astéroïde présent dans le vecteur asteroids.
+fn draw(asteroids: &[Asteroid], menace: Option<usize>, texture_asteroids: &Texture2D, fond: &Fond) {
+    draw_background(fond); // On dessine le fond d'écran.
+                           // On parcourt chaque astéroïde présent dans le vecteur asteroids.
     for asteroid in asteroids {
         // Pour chaque élément, on va le déssiner.
         draw_asteroids(asteroid, texture_asteroids);
     }
+    // On entoure l'astéroïde le plus menaçant.
+    if let Some(asteroid) = menace.and_then(|index| asteroids.get(index)) {
+        entourer_asteroid(asteroid, ORANGE);
+    }
+}
+
+/// Dessine un cercle de la couleur donnée autour d'un astéroïde pour le mettre en évidence.
+fn entourer_asteroid(asteroid: &Asteroid, couleur: Color) {
+    let position = asteroid.get_position();
+    draw_circle_lines(
+        position.x,
+        position.y,
+        asteroid.rayon_asteroid() + 4.0,
+        2.0,
+        couleur,
+    );
+}
+
+/// Fond d'écran du jeu : une image, ou un champ d'étoiles quand aucune image n'est configurée.
+enum Fond {
+    Image(Texture2D),
+    Etoiles(Starfield),
 }
 
 /// Dessine dans la fênetre de jeu le fond d'écran.
 /// # Arguments
-/// - `texture` : Référence à la texture du fond d'écran.
-fn draw_background(texture: &Texture2D) {
-    // Dessiner la texture redimensionnée à la taille de l'écran
-    draw_texture_ex(
-        texture,
-        0.0,
-        0.0,
-        WHITE,
-        DrawTextureParams {
-            dest_size: Some(vec2(screen_width(), screen_height())),
-            ..Default::default()
-        },
-    );
+/// - `fond` : Fond d'écran à dessiner (image ou champ d'étoiles).
+fn draw_background(fond: &Fond) {
+    match fond {
+        // Dessiner la texture redimensionnée à la taille de l'écran
+        Fond::Image(texture) => draw_texture_ex(
+            texture,
+            0.0,
+            0.0,
+            WHITE,
+            DrawTextureParams {
+                dest_size: Some(vec2(screen_width(), screen_height())),
+                ..Default::default()
+            },
+        ),
+        Fond::Etoiles(champ) => champ.draw(get_time()),
+    }
 }
 
 /// Fonction pour dessiner un astéroïde à l'écran.
 /// La couleur varie en fonction de la résistance de l'astéroïde.
+/// Un astéroïde blindé est entouré d'un anneau métallique qui clignote.
 /// # Arguments
 /// -`asteroid` - Référence à un objet Asteroid à dessiner.
 fn draw_asteroids(asteroid: &Asteroid, texture: &Texture2D) {
@@ -86,9 +158,394 @@ fn draw_asteroids(asteroid: &Asteroid, texture: &Texture2D) {
         WHITE,
         DrawTextureParams {
             dest_size: Some(vec2(rayon * 2.0, rayon * 2.0)), // Redimensionner l'image pour correspondre au diamètre de l'astéroïde
+            rotation: asteroid.get_angle(), // L'astéroïde tourne sur lui-même (autour du centre de l'image)
             ..Default::default()
         },
     );
+
+    if asteroid.est_blinde() {
+        let couleur = couleur_pulse(LIGHTGRAY, get_time());
+        draw_circle_lines(position.x, position.y, rayon + 2.0, 3.0, couleur);
+    }
+}
+
+/// Distance (entre le vaisseau et le bord d'un astéroïde) à partir de laquelle le danger commence à monter.
+const DISTANCE_DANGER: f32 = 300.0;
+
+/// Calcule la distance entre le vaisseau et le bord de l'astéroïde le plus proche (négative s'il le chevauche).
+/// # Arguments
+/// - `position_vaisseau` - Position actuelle du vaisseau.
+/// - `asteroids` - Astéroïdes présents à l'écran.
+/// # Retourne `None` s'il n'y a aucun astéroïde.
+fn distance_plus_proche(position_vaisseau: Vec2, asteroids: &[Asteroid]) -> Option<f32> {
+    asteroids
+        .iter()
+        .map(|asteroid| {
+            position_vaisseau.distance(asteroid.get_position()) - asteroid.rayon_asteroid()
+        })
+        .min_by(f32::total_cmp)
+}
+
+/// Calcule le niveau de danger autour du vaisseau, entre 0 (zone dégagée) et 1 (astéroïde au contact).
+/// Le danger dépend de l'astéroïde le plus proche, en tenant compte de son rayon.
+/// # Arguments
+/// - `position_vaisseau` - Position actuelle du vaisseau.
+/// - `asteroids` - Astéroïdes présents à l'écran.
+fn niveau_danger(position_vaisseau: Vec2, asteroids: &[Asteroid]) -> f32 {
+    distance_plus_proche(position_vaisseau, asteroids).map_or(0.0, |distance_bord| {
+        1.0 - (distance_bord / DISTANCE_DANGER).clamp(0.0, 1.0)
+    })
+}
+
+/// Cherche l'astéroïde qui va percuter le vaisseau le plus tôt, en prolongeant les trajectoires en ligne droite.
+/// Seuls les astéroïdes sur une trajectoire d'interception (qui passeront assez près pour toucher le vaisseau) sont retenus.
+/// # Arguments
+/// - `position_vaisseau` - Position actuelle du vaisseau.
+/// - `vitesse_vaisseau` - Vitesse actuelle du vaisseau.
+/// - `asteroids` - Astéroïdes présents à l'écran.
+/// # Retourne l'indice de cet astéroïde, ou `None` si aucun n'est en route vers le vaisseau.
+fn asteroide_plus_menacant(
+    position_vaisseau: Vec2,
+    vitesse_vaisseau: Vec2,
+    asteroids: &[Asteroid],
+) -> Option<usize> {
+    asteroids
+        .iter()
+        .enumerate()
+        .filter_map(|(index, asteroid)| {
+            // On se place dans le repère du vaisseau : position et vitesse relatives de l'astéroïde.
+            let position = asteroid.get_position() - position_vaisseau;
+            let vitesse = asteroid.get_vitesse() - vitesse_vaisseau;
+            // Moment où l'astéroïde passera au plus près du vaisseau (maintenant s'il s'éloigne).
+            let instant = if vitesse.length_squared() > 0.0 {
+                (-position.dot(vitesse) / vitesse.length_squared()).max(0.0)
+            } else {
+                0.0
+            };
+            let distance_min = (position + vitesse * instant).length();
+            (distance_min < Spaceship::RAYON + asteroid.rayon_asteroid())
+                .then_some((index, instant))
+        })
+        .min_by(|(_, instant1), (_, instant2)| instant1.total_cmp(instant2))
+        .map(|(index, _)| index)
+}
+
+/// Cherche le premier astéroïde que toucherait un missile tiré maintenant, en suivant sa trajectoire en ligne droite.
+/// # Arguments
+/// - `origine` - Point de départ du missile (position du vaisseau).
+/// - `direction` - Direction du tir (pas forcément normalisée).
+/// - `asteroids` - Astéroïdes présents à l'écran.
+/// # Retourne l'indice de l'astéroïde touché en premier, ou `None` si le tir ne touche rien.
+fn premiere_cible(origine: Vec2, direction: Vec2, asteroids: &[Asteroid]) -> Option<usize> {
+    let direction = direction.try_normalize()?;
+    asteroids
+        .iter()
+        .enumerate()
+        .filter_map(|(index, asteroid)| {
+            // Intersection entre la demi-droite du tir et le cercle de collision de l'astéroïde.
+            let rayon = Missile::distance_collision(asteroid); // Même distance que pour les collisions des missiles
+            let vers_centre = asteroid.get_position() - origine;
+            let projection = vers_centre.dot(direction); // Distance le long du tir jusqu'au point le plus proche du centre
+            let ecart_carre = vers_centre.length_squared() - projection * projection;
+            if ecart_carre > rayon * rayon {
+                return None; // La trajectoire passe à côté.
+            }
+            let entree = projection - (rayon * rayon - ecart_carre).sqrt();
+            let sortie = projection + (rayon * rayon - ecart_carre).sqrt();
+            if sortie < 0.0 {
+                return None; // L'astéroïde est derrière le vaisseau.
+            }
+            Some((index, entree.max(0.0)))
+        })
+        .min_by(|(_, distance1), (_, distance2)| distance1.total_cmp(distance2))
+        .map(|(index, _)| index)
+}
+
+/// Demi-angle (en radians) du cône devant le vaisseau dans lequel on peut verrouiller un astéroïde.
+const ANGLE_VERROUILLAGE: f32 = std::f32::consts::FRAC_PI_4;
+
+/// Cherche l'astéroïde le plus proche devant le vaisseau, pour le verrouiller.
+/// # Arguments
+/// - `origine` - Position du vaisseau.
+/// - `direction` - Direction dans laquelle le vaisseau est orienté.
+/// - `asteroids` - Astéroïdes présents à l'écran.
+/// # Retourne l'identifiant de l'astéroïde, ou `None` s'il n'y en a aucun dans le cône de verrouillage.
+fn cible_a_verrouiller(origine: Vec2, direction: Vec2, asteroids: &[Asteroid]) -> Option<u32> {
+    asteroids
+        .iter()
+        .filter(|asteroid| {
+            let vers_asteroid = asteroid.get_position() - origine;
+            vers_asteroid != Vec2::ZERO
+                && direction.angle_between(vers_asteroid).abs() <= ANGLE_VERROUILLAGE
+        })
+        .min_by(|a, b| {
+            a.get_position()
+                .distance(origine)
+                .total_cmp(&b.get_position().distance(origine))
+        })
+        .map(Asteroid::get_id)
+}
+
+/// Garde le verrouillage tant que l'astéroïde verrouillé existe encore, et le relâche une fois qu'il est détruit.
+/// # Arguments
+/// - `verrou` - Identifiant de l'astéroïde verrouillé, s'il y en a un.
+/// - `asteroids` - Astéroïdes présents à l'écran.
+fn maj_verrou(verrou: Option<u32>, asteroids: &[Asteroid]) -> Option<u32> {
+    verrou.filter(|id| asteroids.iter().any(|asteroid| asteroid.get_id() == *id))
+}
+
+/// Dessine le réticule de verrouillage : quatre coins autour de l'astéroïde verrouillé.
+fn dessiner_reticule(asteroid: &Asteroid) {
+    let centre = asteroid.get_position();
+    let demi_cote = asteroid.rayon_asteroid() + 8.0;
+    let longueur = demi_cote / 2.0; // Longueur de chaque branche des coins
+    for (sx, sy) in [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)] {
+        let coin = centre + vec2(sx, sy) * demi_cote;
+        draw_line(coin.x, coin.y, coin.x - sx * longueur, coin.y, 2.0, RED);
+        draw_line(coin.x, coin.y, coin.x, coin.y - sy * longueur, 2.0, RED);
+    }
+}
+
+/// Dessine la jauge de danger en haut à droite, sous la barre du bouclier.
+/// # Arguments
+/// - `danger` - Niveau de danger entre 0 et 1.
+/// - `echelle` - Échelle de l'interface (voir `taille_relative`).
+/// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+fn dessiner_jauge_danger(danger: f32, echelle: f32, ecran: Vec2) {
+    let t = |pixels| taille_relative(pixels, ecran, echelle);
+    // Fond de la jauge, puis remplissage rouge proportionnel au danger.
+    draw_rectangle(ecran.x - t(220.0), t(65.0), t(200.0), t(6.0), DARKGRAY);
+    draw_rectangle(ecran.x - t(220.0), t(65.0), t(200.0) * danger, t(6.0), RED);
+    draw_text("Danger", ecran.x - t(220.0), t(88.0), t(20.0), WHITE);
+}
+
+/// Dessine l'indicateur des vies dans le coin choisi : le texte "Vies: N", ou une icône de vaisseau par vie.
+/// # Arguments
+/// - `vies` - Nombre de vies restantes.
+/// - `coin` - Coin de l'écran où afficher les vies.
+/// - `style` - Style de l'indicateur.
+/// - `echelle` - Échelle de l'interface (voir `taille_relative`).
+/// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+fn dessiner_vies(vies: u8, coin: CoinEcran, style: StyleVies, echelle: f32, ecran: Vec2) {
+    let t = |pixels| taille_relative(pixels, ecran, echelle);
+    match style {
+        StyleVies::Nombre => {
+            let texte = format!("Vies: {vies}");
+            let largeur = measure_text(&texte, None, t(30.0) as u16, 1.0).width;
+            let origine = origine_vies(coin, largeur, ecran, echelle);
+            // Le texte est posé sur sa ligne de base, près du bas de l'indicateur.
+            draw_text(
+                &texte,
+                origine.x,
+                origine.y + t(HAUTEUR_VIES - 4.0),
+                t(30.0),
+                WHITE,
+            );
+        }
+        StyleVies::Icones => {
+            for centre in positions_icones_vies(vies, coin, ecran, echelle) {
+                // Petit vaisseau pointé vers le haut.
+                draw_triangle_lines(
+                    centre + vec2(0.0, -t(9.0)),
+                    centre + vec2(-t(7.0), t(8.0)),
+                    centre + vec2(t(7.0), t(8.0)),
+                    2.0,
+                    WHITE,
+                );
+            }
+        }
+    }
+}
+
+/// Éléments de l'interface (HUD) dessinés par-dessus le jeu.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ElementHud {
+    Niveau,   // Niveau actuel, en haut à gauche
+    Vies,     // Vies restantes, sous le niveau (ou dans le coin choisi)
+    Combo,    // Multiplicateur de combo, sous les vies
+    Charges,  // Champs de ralentissement, barrières et bombes encore disponibles, sous le combo
+    Bouclier, // Pourcentage du bouclier, en haut à droite
+    Danger,   // Jauge de danger, sous le bouclier
+}
+
+/// Tous les éléments de l'interface, dans l'ordre où ils sont dessinés.
+const ELEMENTS_HUD: [ElementHud; 6] = [
+    ElementHud::Niveau,
+    ElementHud::Vies,
+    ElementHud::Combo,
+    ElementHud::Charges,
+    ElementHud::Bouclier,
+    ElementHud::Danger,
+];
+
+/// Retourne les éléments de l'interface à dessiner : tous si le HUD est visible, aucun sinon.
+/// # Arguments
+/// - `hud_visible` - Indique si l'interface est affichée (touche H).
+fn elements_hud(hud_visible: bool) -> &'static [ElementHud] {
+    if hud_visible {
+        &ELEMENTS_HUD
+    } else {
+        &[]
+    }
+}
+
+/// Dessine l'interface du jeu (niveau, vies, combo, charges, bouclier, jauge de danger) si elle est visible.
+/// Cacher l'interface ne change rien à la logique du jeu.
+/// # Arguments
+/// - `hud_visible` - Indique si l'interface est affichée.
+/// - `monde` - État de la partie en cours.
+/// - `settings` - Paramètres du jeu (échelle de l'interface, coin et style des vies).
+fn dessiner_hud(hud_visible: bool, monde: &World, settings: &Settings) {
+    let echelle = settings.echelle_interface;
+    let ecran = dimensions_ecran(settings);
+    let t = |pixels| taille_relative(pixels, ecran, echelle);
+    for element in elements_hud(hud_visible) {
+        match element {
+            ElementHud::Niveau => {
+                // En mode horde, il n'y a plus de niveaux : on affiche le temps de survie.
+                let texte = match &monde.horde {
+                    Some(horde) => format!("Temps {:.0}s", horde.temps_ecoule()),
+                    None => format!("Level {}", monde.niveau),
+                };
+                draw_text(&texte, t(20.0), t(30.0), t(30.0), WHITE);
+            }
+            ElementHud::Vies => dessiner_vies(
+                monde.vies,
+                settings.coin_vies,
+                settings.style_vies,
+                echelle,
+                ecran,
+            ),
+            ElementHud::Combo => {
+                draw_text(
+                    &format!("Combo x{}", monde.get_combo()),
+                    t(20.0),
+                    t(90.0),
+                    t(30.0),
+                    WHITE,
+                );
+            }
+            ElementHud::Charges => {
+                // Champs de ralentissement (touche X) encore disponibles.
+                if settings.charges_ralentissement > 0 {
+                    draw_text(
+                        &format!("Ralentissement x{}", monde.get_charges_ralentissement()),
+                        t(20.0),
+                        t(120.0),
+                        t(24.0),
+                        SKYBLUE,
+                    );
+                }
+                // Barrières (touche B) encore disponibles.
+                if settings.charges_barriere > 0 {
+                    draw_text(
+                        &format!("Barrière x{}", monde.get_charges_barriere()),
+                        t(20.0),
+                        t(145.0),
+                        t(24.0),
+                        GOLD,
+                    );
+                }
+                // Bombes (touche V) achetées à la boutique.
+                let bombes = monde.get_ameliorations().bombes;
+                if bombes > 0 {
+                    draw_text(
+                        &format!("Bombe x{bombes}"),
+                        t(20.0),
+                        t(170.0),
+                        t(24.0),
+                        ORANGE,
+                    );
+                }
+            }
+            ElementHud::Bouclier => monde.vaisseau.dessiner_interface_bouclier(echelle, ecran),
+            ElementHud::Danger => dessiner_jauge_danger(
+                niveau_danger(monde.vaisseau.get_position(), &monde.asteroids),
+                echelle,
+                ecran,
+            ),
+        }
+    }
+}
+
+/// Dessine, en mode debug, le vecteur vitesse de chaque astéroïde.
+/// La longueur du trait est proportionnelle à la vitesse de l'astéroïde.
+/// # Arguments
+/// - `asteroids` - Astéroïdes présents à l'écran.
+fn dessiner_vecteurs_vitesse(asteroids: &[Asteroid]) {
+    for asteroid in asteroids {
+        let debut = asteroid.get_position();
+        let fin = debut + asteroid.get_vitesse() * 30.0; // Déplacement sur 30 frames
+        draw_line(debut.x, debut.y, fin.x, fin.y, 2.0, YELLOW);
+    }
+}
+
+/// Dessine, en mode debug, le vecteur vitesse du vaisseau (même échelle que pour les astéroïdes).
+/// # Arguments
+/// - `vaisseau` - Vaisseau du joueur.
+fn dessiner_vitesse_vaisseau(vaisseau: &Spaceship) {
+    let debut = vaisseau.get_position();
+    let fin = debut + vaisseau.get_vitesse() * 30.0; // Déplacement sur 30 frames
+    draw_line(debut.x, debut.y, fin.x, fin.y, 2.0, SKYBLUE);
+}
+
+/// Fréquence (en Hz) du clignotement des astéroïdes dangereux.
+const FREQUENCE_PULSE: f64 = 2.0;
+
+/// Fait osciller la transparence d'une couleur au cours du temps, pour signaler un astéroïde dangereux.
+/// L'opacité varie entre 40% et 100% de l'opacité de la couleur de base.
+/// # Arguments
+/// - `base` - Couleur de départ.
+/// - `t` - Temps actuel en secondes (`get_time()`).
+fn couleur_pulse(base: Color, t: f64) -> Color {
+    let oscillation = (t * FREQUENCE_PULSE * std::f64::consts::TAU).sin() as f32; // Entre -1 et 1
+    Color {
+        a: base.a * (0.7 + 0.3 * oscillation),
+        ..base
+    }
+}
+
+/// Durée (en secondes) pendant laquelle le sonar montre les positions prévues des astéroïdes.
+const DUREE_SONAR: f64 = 1.0;
+
+/// Temps de recharge (en secondes) du sonar, compté depuis son dernier déclenchement.
+const RECHARGE_SONAR: f64 = 8.0;
+
+/// Nombre de frames d'avance des positions montrées par le sonar (environ une seconde).
+const ANTICIPATION_SONAR: f32 = 60.0;
+
+/// Indique si le sonar peut être déclenché.
+/// # Arguments
+/// - `dernier_sonar` - Temps (`get_time()`) du dernier déclenchement, `None` s'il n'a jamais servi.
+/// - `temps` - Temps actuel.
+fn sonar_disponible(dernier_sonar: Option<f64>, temps: f64) -> bool {
+    dernier_sonar.is_none_or(|dernier| temps - dernier >= RECHARGE_SONAR)
+}
+
+/// Dessine, en transparence, la position prévue de chaque astéroïde dans `ANTICIPATION_SONAR` frames.
+/// # Arguments
+/// - `asteroids` - Astéroïdes présents à l'écran.
+fn dessiner_sonar(asteroids: &[Asteroid]) {
+    for asteroid in asteroids {
+        let prevue = asteroid.position_predite(ANTICIPATION_SONAR);
+        draw_circle_lines(
+            prevue.x,
+            prevue.y,
+            asteroid.rayon_asteroid(),
+            1.0,
+            Color::new(0.4, 0.8, 1.0, 0.35),
+        );
+    }
+}
+
+/// Dessine le viseur à la position du curseur de la souris (visée à la souris).
+/// # Arguments
+/// - `origine` - Coin haut gauche de la zone de jeu dans la fenêtre.
+fn dessiner_viseur(origine: Vec2) {
+    let (x, y) = (Vec2::from(mouse_position()) - origine).into();
+    draw_circle_lines(x, y, 8.0, 1.5, WHITE);
+    draw_line(x - 12.0, y, x + 12.0, y, 1.0, WHITE);
+    draw_line(x, y - 12.0, x, y + 12.0, 1.0, WHITE);
 }
 
 /// Fonction qui gère la touche échap.
@@ -101,13 +558,375 @@ fn handle_input() -> bool {
     false
 }
 
-/// Met à jour le modèle des astéroïdes en déplaçant chaque astéroïde.
+/// Indique si le joueur peut recommencer une partie depuis l'écran "Game Over".
+/// Entrée est ignorée pendant `delai` secondes, pour ne pas relancer une partie par accident.
 /// # Arguments
-/// -`asteroids` - Référence mutable à un vecteur d'astéroïdes à mettre à jour.
-fn update_model(asteroids: &mut Vec<Asteroid>) {
-    // Pour chaque astéroïde à l'écran, on utilise la fonction move_object() du module asteroid pour changer sa position en fonction de la vitesse.
-    for asteroid in asteroids {
-        asteroid.move_object();
+/// - `debut_game_over` - Temps (`get_time()`) auquel l'écran "Game Over" est apparu.
+/// - `temps` - Temps actuel.
+/// - `delai` - Délai minimal avant de pouvoir recommencer.
+fn redemarrage_autorise(debut_game_over: f64, temps: f64, delai: f64) -> bool {
+    temps - debut_game_over >= delai
+}
+
+/// Temps (en secondes) passé sans rien faire sur l'écran "Game Over" avant que la démo se lance toute seule.
+const DELAI_DEMO: f64 = 20.0;
+
+/// Indique si la démo doit se lancer depuis l'écran "Game Over" : le joueur l'a demandée (touche D),
+/// ou il est resté inactif pendant `DELAI_DEMO` secondes.
+/// # Arguments
+/// - `debut_game_over` - Temps (`get_time()`) auquel l'écran "Game Over" est apparu.
+/// - `temps` - Temps actuel.
+/// - `demandee` - Vrai si le joueur a appuyé sur la touche de la démo.
+fn demo_a_lancer(debut_game_over: f64, temps: f64, demandee: bool) -> bool {
+    demandee || temps - debut_game_over >= DELAI_DEMO
+}
+
+/// Calcule le temps à attendre pour ne pas dépasser le nombre d'images par seconde visé.
+/// # Arguments
+/// - `fps_cible` - Nombre maximal d'images par seconde.
+/// - `ecoule` - Durée (en secondes) de la dernière frame.
+/// # Retourne une durée nulle si la frame a déjà duré plus longtemps que la durée visée.
+fn duree_attente_frame(fps_cible: u32, ecoule: f64) -> Duration {
+    let duree_cible = 1.0 / fps_cible.max(1) as f64;
+    Duration::from_secs_f64((duree_cible - ecoule).max(0.0))
+}
+
+/// Attend la fin de la frame si un nombre maximal d'images par seconde est défini dans les paramètres.
+/// # Arguments
+/// - `fps_max` - Limite d'images par seconde (`None` = pas de limite).
+/// - `debut_frame` - Temps (`get_time()`) du début de la frame qui vient de se terminer.
+fn limiter_fps(fps_max: Option<u32>, debut_frame: f64) {
+    if let Some(fps_cible) = fps_max {
+        std::thread::sleep(duree_attente_frame(fps_cible, get_time() - debut_frame));
+    }
+}
+
+/// Retourne les dimensions actuelles de la fenêtre (largeur, hauteur).
+fn dimensions_fenetre() -> Vec2 {
+    vec2(screen_width(), screen_height())
+}
+
+/// Retourne la zone de jeu dans la fenêtre, entourée de bandes noires si ses proportions sont imposées.
+fn zone_fenetre(settings: &Settings) -> Rect {
+    zone_de_jeu(dimensions_fenetre(), settings.rapport_aspect)
+}
+
+/// Retourne les dimensions de l'écran de jeu (largeur, hauteur) : celles de la zone de jeu, pas de la fenêtre.
+fn dimensions_ecran(settings: &Settings) -> Vec2 {
+    zone_fenetre(settings).size()
+}
+
+/// Place la caméra sur la zone de jeu : tout ce qui est dessiné ensuite utilise les coordonnées de la zone de jeu,
+/// (0, 0) étant son coin haut gauche.
+fn placer_camera(settings: &Settings) {
+    if settings.rapport_aspect.is_none() {
+        set_default_camera();
+        return;
+    }
+    let fenetre = dimensions_fenetre();
+    set_camera(&Camera2D {
+        target: fenetre / 2.0 - zone_fenetre(settings).point(),
+        zoom: vec2(2.0 / fenetre.x, 2.0 / fenetre.y),
+        ..Default::default()
+    });
+}
+
+/// Place la caméra pour revoir une partie enregistrée : sa zone de jeu, de dimensions `ecran`, est agrandie ou
+/// réduite pour remplir la zone de jeu de la fenêtre (qui doit avoir les mêmes proportions).
+fn placer_camera_replay(settings: &Settings, ecran: Vec2) {
+    let fenetre = dimensions_fenetre();
+    let zone = zone_fenetre(settings);
+    let echelle = zone.w / ecran.x;
+    set_camera(&Camera2D {
+        target: ecran / 2.0 + (fenetre / 2.0 - zone.center()) / echelle,
+        zoom: vec2(2.0 / fenetre.x, 2.0 / fenetre.y) * echelle,
+        ..Default::default()
+    });
+}
+
+/// Dessine les bandes noires autour de la zone de jeu, par-dessus ce qui dépasse (fond d'écran, astéroïdes
+/// qui sortent de l'écran...). La caméra est ensuite celle de la fenêtre.
+fn dessiner_bandes_noires(settings: &Settings) {
+    if settings.rapport_aspect.is_none() {
+        return;
+    }
+    set_default_camera();
+    let fenetre = dimensions_fenetre();
+    let zone = zone_fenetre(settings);
+    if zone.x > 0.0 {
+        // Bandes sur les côtés
+        draw_rectangle(0.0, 0.0, zone.x, fenetre.y, BLACK);
+        draw_rectangle(
+            zone.right(),
+            0.0,
+            fenetre.x - zone.right(),
+            fenetre.y,
+            BLACK,
+        );
+    }
+    if zone.y > 0.0 {
+        // Bandes en haut et en bas
+        draw_rectangle(0.0, 0.0, fenetre.x, zone.y, BLACK);
+        draw_rectangle(
+            0.0,
+            zone.bottom(),
+            fenetre.x,
+            fenetre.y - zone.bottom(),
+            BLACK,
+        );
+    }
+}
+
+/// Charge une texture depuis `chemin`.
+/// Si l'image ne peut pas être chargée (chemin personnalisé invalide), un message est affiché et on charge `defaut` à la place.
+async fn charger_texture(chemin: &str, defaut: &str) -> Texture2D {
+    match load_texture(chemin).await {
+        Ok(texture) => texture,
+        Err(erreur) => {
+            eprintln!("Impossible de charger la texture '{chemin}' ({erreur}), utilisation de '{defaut}'.");
+            load_texture(defaut).await.unwrap()
+        }
+    }
+}
+
+/// Dessine les missiles, avec ou sans traînée selon les paramètres.
+fn dessiner_missiles(missiles: &[Missile], settings: &Settings) {
+    for missile in missiles {
+        if settings.missiles_trainees {
+            missile.dessiner_missile_streak();
+        } else {
+            missile.dessiner_missile();
+        }
+    }
+}
+
+/// Enregistre ce qui doit l'être avant de quitter le jeu (score de la partie en cours), voir `World::on_quit`.
+/// # Arguments
+/// - `monde` - Partie en cours.
+/// - `score_du_joueur` - Faux si le score n'est pas celui du joueur (il n'a rien fait, ou c'est une partie
+///   de la démo) : rien n'est alors enregistré.
+fn sauvegarder_avant_de_quitter(monde: &World, score_du_joueur: bool) {
+    if !score_du_joueur {
+        return;
+    }
+    if let Err(erreur) = monde.on_quit() {
+        eprintln!("Impossible d'enregistrer le score avant de quitter : {erreur}");
+    }
+}
+
+/// Assombrit l'écran et indique comment reprendre la partie mise en pause automatiquement.
+/// # Arguments
+/// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+fn dessiner_pause_auto(ecran: Vec2) {
+    draw_rectangle(0.0, 0.0, ecran.x, ecran.y, Color::new(0.0, 0.0, 0.0, 0.6));
+    let texte = "PAUSE - appuyez sur une touche pour reprendre";
+    let largeur = measure_text(texte, None, 30, 1.0).width;
+    draw_text(texte, (ecran.x - largeur) / 2.0, ecran.y / 2.0, 30.0, WHITE);
+}
+
+/// Touches d'achat de la boutique, dans l'ordre de `Amelioration::TOUTES`.
+const TOUCHES_BOUTIQUE: [KeyCode; 4] = [KeyCode::Key1, KeyCode::Key2, KeyCode::Key3, KeyCode::Key4];
+
+/// Assombrit l'écran et affiche la boutique entre deux vagues : les améliorations, leur prix et le score à dépenser.
+/// Les améliorations trop chères ou déjà au maximum sont grisées.
+/// # Arguments
+/// - `monde` - Partie en cours.
+/// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+fn dessiner_boutique(monde: &World, ecran: Vec2) {
+    draw_rectangle(0.0, 0.0, ecran.x, ecran.y, Color::new(0.0, 0.0, 0.0, 0.7));
+    let centrer = |texte: &str, y: f32, taille: u16, couleur: Color| {
+        let largeur = measure_text(texte, None, taille, 1.0).width;
+        draw_text(texte, (ecran.x - largeur) / 2.0, y, taille as f32, couleur);
+    };
+    centrer("BOUTIQUE", ecran.y / 2.0 - 120.0, 50, GOLD);
+    centrer(
+        &format!("Score : {}", monde.get_score()),
+        ecran.y / 2.0 - 80.0,
+        30,
+        WHITE,
+    );
+    let ameliorations = monde.get_ameliorations();
+    for (index, amelioration) in Amelioration::TOUTES.iter().enumerate() {
+        let au_maximum = ameliorations.est_au_maximum(*amelioration);
+        let ligne = if au_maximum {
+            format!("{}. {} (maximum)", index + 1, amelioration.nom())
+        } else {
+            format!(
+                "{}. {} - {} points",
+                index + 1,
+                amelioration.nom(),
+                amelioration.prix()
+            )
+        };
+        let disponible = !au_maximum && monde.get_score() >= amelioration.prix();
+        let couleur = if disponible { WHITE } else { GRAY };
+        centrer(
+            &ligne,
+            ecran.y / 2.0 - 30.0 + index as f32 * 30.0,
+            25,
+            couleur,
+        );
+    }
+    centrer(
+        &format!("Bombes : {} (touche V)", ameliorations.bombes),
+        ecran.y / 2.0 + 110.0,
+        22,
+        SKYBLUE,
+    );
+    centrer(
+        "Appuyez sur 'Entrée' pour commencer la vague suivante.",
+        ecran.y / 2.0 + 150.0,
+        22,
+        WHITE,
+    );
+}
+
+/// Dessine les ennemis du niveau (le boss s'il y en a un, et les ovnis) et les missiles qu'ils ont tirés.
+fn dessiner_boss(monde: &World) {
+    if let Some(boss) = &monde.boss {
+        boss.draw();
+    }
+    for ovni in &monde.ovnis {
+        ovni.draw();
+    }
+    for missile in &monde.missiles_ennemis {
+        missile.dessiner_missile();
+    }
+}
+
+/// Dessine le cluster du niveau (s'il y en a un) : son orbite, son cœur et ses orbiteurs.
+fn dessiner_cluster(monde: &World, texture: &Texture2D) {
+    if let Some(cluster) = &monde.cluster {
+        cluster.dessiner_orbite();
+        for asteroid in cluster.asteroides() {
+            draw_asteroids(asteroid, texture);
+        }
+    }
+}
+
+/// Démarre les explosions des astéroïdes qui viennent d'être détruits, dessine celles en cours
+/// et oublie celles qui sont terminées.
+/// # Arguments
+/// - `explosions` - Explosions en cours.
+/// - `destructions` - Position et taille des astéroïdes détruits depuis la frame précédente.
+/// - `temps` - Temps actuel en secondes (`get_time()`).
+/// - `rng` - Générateur des effets visuels de la partie (`World::rng_effets`).
+fn maj_explosions(
+    explosions: &mut Vec<Explosion>,
+    destructions: Vec<(Vec2, u8)>,
+    temps: f64,
+    rng: &mut impl ::rand::Rng,
+) {
+    explosions.extend(
+        destructions
+            .into_iter()
+            .map(|(position, taille)| Explosion::nouvelle(position, taille, temps, rng)),
+    );
+    explosions.retain(|explosion| !explosion.est_terminee(temps));
+    for explosion in explosions.iter() {
+        explosion.draw(temps);
+    }
+}
+
+/// Enregistre la partie en cours (option `--record`) et arrête l'enregistrement.
+/// # Arguments
+/// - `enregistrement` - Partie enregistrée jusqu'ici (`None` si rien n'est enregistré).
+/// - `chemin` - Fichier dans lequel l'écrire.
+fn terminer_enregistrement(enregistrement: &mut Option<Replay>, chemin: Option<&str>) {
+    if let (Some(replay), Some(chemin)) = (enregistrement.take(), chemin) {
+        if let Err(erreur) = replay.sauvegarder(chemin) {
+            eprintln!("Impossible d'enregistrer la partie '{chemin}' : {erreur}");
+        }
+    }
+}
+
+/// Revoit une partie enregistrée (option `--replay`), sans commande du joueur.
+/// La partie est rejouée avec la zone de jeu et les paramètres de l'enregistrement, mise à l'échelle de la fenêtre.
+/// 'P' met la lecture en pause, 'S' avance d'une seule frame pendant la pause, 'Échap' quitte.
+async fn lire_replay(
+    mut lecture: Lecture,
+    fond: &Fond,
+    texture_asteroid: &Texture2D,
+    texture_bouclier: &Texture2D,
+) {
+    let mut monde = lecture.creer_monde();
+    let ecran = lecture.ecran();
+    // La zone de jeu de la fenêtre garde les proportions de celle de l'enregistrement.
+    let settings = &Settings {
+        rapport_aspect: Some(ecran.x / ecran.y),
+        ..lecture.settings().clone()
+    };
+    let mut explosions: Vec<Explosion> = Vec::new();
+    let mut en_pause = false;
+    let mut debut_frame = get_time();
+
+    loop {
+        clear_background(BLACK);
+        placer_camera_replay(settings, ecran);
+        for zone in &monde.zones {
+            zone.draw();
+        }
+        for champ in &monde.zones_ralentissement {
+            champ.draw();
+        }
+        for barriere in &monde.barrieres {
+            barriere.draw();
+        }
+        if let Some(trou_noir) = &monde.trou_noir {
+            trou_noir.draw();
+        }
+        draw(&monde.asteroids, None, texture_asteroid, fond);
+        for nuage in &monde.nuages {
+            nuage.draw();
+        }
+        monde.vaisseau.draw();
+        monde.bonus.draw(texture_bouclier);
+        dessiner_missiles(&monde.missiles, settings);
+        dessiner_boss(&monde);
+        dessiner_cluster(&monde, texture_asteroid);
+        placer_camera(settings);
+        dessiner_hud(true, &monde, settings);
+
+        if is_key_pressed(KeyCode::P) {
+            en_pause = !en_pause;
+        }
+        if !monde.est_terminee() && (!en_pause || is_key_pressed(KeyCode::S)) {
+            lecture.avancer(&mut monde);
+        }
+        maj_explosions(
+            &mut explosions,
+            monde.prendre_destructions(),
+            get_time(),
+            &mut monde.rng_effets,
+        );
+
+        let etat = if lecture.est_terminee() || monde.est_terminee() {
+            "fin"
+        } else if en_pause {
+            "pause ('S' : frame suivante)"
+        } else {
+            "'P' : pause"
+        };
+        draw_text(
+            &format!(
+                "REPLAY {}/{} - {etat}",
+                lecture.frame(),
+                lecture.nombre_frames()
+            ),
+            20.0,
+            dimensions_ecran(settings).y - 20.0,
+            25.0,
+            YELLOW,
+        );
+
+        if handle_input() {
+            break;
+        }
+
+        dessiner_bandes_noires(settings);
+        next_frame().await;
+        limiter_fps(settings.fps_max, debut_frame);
+        debut_frame = get_time();
     }
 }
 
@@ -115,12 +934,35 @@ fn update_model(asteroids: &mut Vec<Asteroid>) {
 /// Utilise `macroquad` pour créer une boucle d'animation et gérer l'affichage.
 #[macroquad::main(window_conf)]
 async fn main() {
-    // On charge le fond d'écran
-    let background_texture: Texture2D =
-        load_texture("ressources/Fond_ecran_jeu.png").await.unwrap();
+    // On lit les arguments de la ligne de commande et on charge les paramètres du jeu
+    let arguments = Arguments::depuis_ligne_de_commande();
+    // Les paramètres peuvent être modifiés pendant la partie depuis la console de développement.
+    let mut settings = Settings::depuis_arguments(&arguments);
 
-    // Charger la texture de l'astéroïde
-    let texture_asteroid: Texture2D = load_texture("ressources/asteroids.png").await.unwrap();
+    // On charge le fond d'écran : l'image des paramètres, ou un champ d'étoiles s'il n'y en a pas
+    // (ou si elle est introuvable).
+    let texture_fond = match &settings.texture_fond {
+        Some(chemin) => load_texture(chemin)
+            .await
+            .map_err(|erreur| eprintln!("Impossible de charger le fond '{chemin}' : {erreur}"))
+            .ok(),
+        None => None,
+    };
+    let fond = match texture_fond {
+        Some(texture) => {
+            texture.set_filter(FilterMode::Nearest);
+            Fond::Image(texture)
+        }
+        None => Fond::Etoiles(Starfield::generer(
+            arguments.graine.unwrap_or(0),
+            Starfield::NOMBRE_ETOILES,
+            dimensions_ecran(&settings),
+        )),
+    };
+
+    // Charger la texture de l'astéroïde (celle des paramètres, ou celle du jeu si elle est introuvable)
+    let texture_asteroid: Texture2D =
+        charger_texture(&settings.texture_asteroids, TEXTURE_ASTEROIDS_DEFAUT).await;
 
     // On charge la texture pour le bonus bouclier
     let texture_bouclier: Texture2D = load_texture("ressources/bouclier.png").await.unwrap();
@@ -128,227 +970,610 @@ async fn main() {
     // On s'assure que les images sont chargées correctement
     texture_asteroid.set_filter(FilterMode::Nearest);
     texture_bouclier.set_filter(FilterMode::Nearest);
-    background_texture.set_filter(FilterMode::Nearest);
 
-    // On crée une variable pour stocker le niveau actuel
-    let mut niveau = 1;
+    // On crée la partie : astéroïdes, vaisseau, missiles, bonus, niveau et vies.
+    let mut monde = World::new(&settings, arguments.graine, dimensions_ecran(&settings));
 
-    // On va créer un Vecteur vide qui va stocker les astéroïdes qui seront à l'écran.
-    // On utilise une boucle pour ajouter des astéroïdes
-    let mut asteroids: Vec<Asteroid> = Vec::new();
-    for _ in 0..8 {
-        asteroids.push(Asteroid::new())
+    // Un fichier de vagues (option --waves) définit la composition de certains niveaux.
+    // Son texte est gardé pour être enregistré avec la partie (option --record).
+    let mut texte_vagues = String::new();
+    if let Some(chemin) = &arguments.vagues {
+        match std::fs::read_to_string(chemin)
+            .map_err(|erreur| erreur.to_string())
+            .and_then(|texte| analyser_vagues(&texte).map(|vagues| (texte, vagues)))
+        {
+            Ok((_, vagues)) if vagues.is_empty() => {}
+            Ok((texte, vagues)) => {
+                monde.charger_vagues(vagues, &settings, dimensions_ecran(&settings));
+                texte_vagues = texte;
+            }
+            Err(erreur) => eprintln!("Impossible de charger les vagues '{chemin}' : {erreur}"),
+        }
     }
 
-    // On va créer un vaisseau
-    let mut vaisseau = Spaceship::new();
+    // Une disposition peut remplacer la première vague (tutoriel : ressources/tutoriel.txt).
+    let mut texte_disposition = String::new();
+    if let Some(chemin) = &arguments.disposition {
+        match std::fs::read_to_string(chemin)
+            .map_err(|erreur| erreur.to_string())
+            .and_then(|texte| analyser_disposition(&texte).map(|asteroids| (texte, asteroids)))
+        {
+            Ok((_, asteroids)) if asteroids.is_empty() => {}
+            Ok((texte, asteroids)) => {
+                monde.charger_disposition(asteroids);
+                texte_disposition = texte;
+            }
+            Err(erreur) => eprintln!("Impossible de charger la disposition '{chemin}' : {erreur}"),
+        }
+    }
+
+    // Revoir une partie enregistrée (option --replay) au lieu de jouer.
+    if let Some(chemin) = &arguments.replay {
+        match Replay::charger(chemin, &settings, dimensions_ecran(&settings)) {
+            Ok(replay) => {
+                lire_replay(
+                    Lecture::new(replay),
+                    &fond,
+                    &texture_asteroid,
+                    &texture_bouclier,
+                )
+                .await;
+                return;
+            }
+            Err(erreur) => eprintln!("Impossible de charger la partie '{chemin}' : {erreur}"),
+        }
+    }
+
+    // Enregistrement de la partie (option --record) : chaque frame dure alors exactement `PAS_TEMPS`,
+    // pour que la partie puisse être rejouée à l'identique.
+    // Les achats de la boutique et les commandes de la console ne font pas partie des commandes enregistrées :
+    // ni boutique ni console pendant un enregistrement, pour que la partie se rejoue à l'identique.
+    if arguments.enregistrement.is_some() {
+        settings.boutique = false;
+    }
+    let mut enregistrement = arguments.enregistrement.as_ref().map(|_| Replay {
+        vagues: texte_vagues,
+        disposition: texte_disposition,
+        ..Replay::nouveau(monde.get_graine(), &settings, dimensions_ecran(&settings))
+    });
+
+    // Mode démo : le vaisseau est piloté automatiquement. La démo se lance depuis l'écran "Game Over"
+    // (touche D, ou toute seule quand personne ne joue) et s'arrête dès que le joueur appuie sur une touche :
+    // une nouvelle partie commence alors. Les parties de la démo ne comptent ni dans les scores ni dans les statistiques.
+    let mut mode_demo = false;
+
+    // Le joueur a-t-il donné au moins une commande pendant la partie ?
+    // Le score n'est enregistré en quittant que s'il a vraiment joué.
+    let mut joueur_a_joue = false;
+
+    // Mode debug : affiche des informations pour régler la physique (touche F3 pour l'activer / le désactiver).
+    // Dans ce mode, la touche N permet de passer au niveau suivant.
+    let mut mode_debug = false;
+
+    // Interface affichée ou cachée (touche H), par exemple pour faire des captures d'écran.
+    let mut hud_visible = true;
 
-    // On va créer un Vecteur vide qui va stocker les missiles qui seront à l'écran.
-    let mut missiles: Vec<Missile> = Vec::new();
+    // Moment où l'écran "Game Over" est apparu (`None` pendant la partie).
+    let mut debut_game_over: Option<f64> = None;
 
-    // On crée une variable pour le bonus
-    let mut bonus = Bonus::nouveau_bonus();
+    // Tableau des meilleurs scores, et saisie des initiales en cours quand le score y a sa place.
+    let mut table_scores = TableScores::charger(&settings.fichier_scores);
+
+    // Sons du jeu (aucun si le jeu est muet, ou compilé sans la fonctionnalité `audio`).
+    let mut audio = Audio::charger(settings.muet).await;
+    let mut saisie_initiales: Option<SaisieInitiales> = None;
+
+    // Effet d'éclatement du bouclier en cours (`None` si aucun effet n'est joué).
+    let mut eclat_bouclier: Option<EclatBouclier> = None;
+
+    // Explosions des astéroïdes détruits, en cours.
+    let mut explosions: Vec<Explosion> = Vec::new();
+
+    // Moment du dernier déclenchement du sonar (touche E), `None` s'il n'a pas encore servi.
+    let mut dernier_sonar: Option<f64> = None;
+
+    // Identifiant de l'astéroïde verrouillé (touche L), `None` si aucun astéroïde n'est verrouillé.
+    let mut verrou: Option<u32> = None;
+
+    // Console de développement (touche ~), pour régler le jeu pendant la partie.
+    let mut console = Console::default();
+
+    // Astuces pour les nouveaux joueurs : celles déjà montrées lors des parties précédentes ne reviennent pas.
+    let mut tutoriel = Tutoriel::charger(&settings.fichier_tutoriel);
+
+    // Pause automatique quand la fenêtre perd le focus.
+    let mut pause_auto = PauseAuto::new(settings.pause_auto);
+
+    // Mode photo (touche F12) : la partie est figée et la caméra se déplace librement (`None` en jeu normal).
+    let mut mode_photo: Option<PhotoMode> = None;
+
+    // Début de la frame en cours, utilisé pour limiter le nombre d'images par seconde.
+    let mut debut_frame = get_time();
 
     loop {
         clear_background(BLACK);
-        draw_background(&background_texture);
+        placer_camera(&settings);
+        let ecran = dimensions_ecran(&settings);
+
+        // Mode photo : flèches pour déplacer la caméra, molette pour zoomer. En le quittant,
+        // la caméra habituelle est rétablie (elle est replacée au début de chaque frame).
+        if is_key_pressed(KeyCode::F12) && !monde.est_terminee() {
+            mode_photo = match mode_photo {
+                Some(_) => None,
+                None => Some(PhotoMode::new(get_time())),
+            };
+        }
+        if let Some(photo) = &mut mode_photo {
+            let direction = direction_panoramique(
+                is_key_down(KeyCode::Left),
+                is_key_down(KeyCode::Right),
+                is_key_down(KeyCode::Up),
+                is_key_down(KeyCode::Down),
+            );
+            photo.deplacer(direction, get_frame_time());
+            let molette = mouse_wheel().1;
+            if molette != 0.0 {
+                photo.zoomer(molette.signum());
+            }
+            set_camera(&photo.camera(dimensions_fenetre(), ecran));
+        }
+        draw_background(&fond);
+        for zone in &monde.zones {
+            zone.draw(); // Nébuleuses du niveau, sous les astéroïdes
+        }
+        for champ in &monde.zones_ralentissement {
+            champ.draw(); // Champs de ralentissement déployés par le joueur
+        }
+        for barriere in &monde.barrieres {
+            barriere.draw(); // Barrières déployées par le joueur
+        }
+        if let Some(trou_noir) = &monde.trou_noir {
+            trou_noir.draw();
+        }
+
+        // Une partie de la démo terminée ne compte pas : le pilote automatique en recommence une autre.
+        if mode_demo && monde.est_terminee() {
+            monde.nouvelle_partie(&settings, ecran);
+        }
 
         // Gestion de l'écran "Game Over"
-        // Si le vaisseau n'a plus de bouclier, à la prochaine collision on affiche l'écran de game over.
-        if vaisseau.get_bouclier() == 0 {
+        // Si le vaisseau n'a plus de bouclier ni de vie, à la prochaine collision on affiche l'écran de game over.
+        if monde.est_terminee() {
+            // L'enregistrement s'arrête avec la partie.
+            terminer_enregistrement(&mut enregistrement, arguments.enregistrement.as_deref());
+            // Dès l'apparition de l'écran, les statistiques de la partie sont exportées (option --stats).
+            if debut_game_over.is_none() {
+                if let Some(chemin) = &settings.fichier_statistiques {
+                    if let Err(erreur) = monde.statistiques().exporter(chemin) {
+                        eprintln!("Impossible d'exporter les statistiques '{chemin}' : {erreur}");
+                    }
+                }
+            }
+            // Dès l'apparition de l'écran, on demande les initiales si le score entre dans le tableau.
+            if debut_game_over.is_none() && table_scores.qualifie(monde.get_score()) {
+                while get_char_pressed().is_some() {} // On oublie les touches tapées pendant la partie.
+                saisie_initiales = Some(SaisieInitiales::default());
+            }
+            let debut = *debut_game_over.get_or_insert(get_time());
+            let pret = redemarrage_autorise(debut, get_time(), settings.delai_redemarrage);
             clear_background(BLACK);
             // On dessine à l'écran le texte "Game Over"
             let taille_texte = measure_text("GAME OVER", None, 80, 1.0).width;
             draw_text(
                 "GAME OVER",
-                (screen_width() - taille_texte) / 2.0,
-                screen_height() / 2.0 - 50.0,
+                (ecran.x - taille_texte) / 2.0,
+                ecran.y / 2.0 - 50.0,
                 80.0,
                 RED,
             );
 
-            // On dessine à l'écran le texte qui indique à quel niveau on est morts.
-            let taille_texte_niveau = measure_text(
-                &format!("Vous êtes mort au niveau {} !", niveau),
-                None,
-                40,
-                1.0,
-            )
-            .width;
+            // On dessine à l'écran le texte qui indique à quel niveau on est morts
+            // (en mode horde, combien de temps on a survécu).
+            let texte_niveau = match &monde.horde {
+                Some(horde) => format!("Vous avez survécu {:.0} secondes !", horde.temps_ecoule()),
+                None => format!("Vous êtes mort au niveau {} !", monde.niveau),
+            };
+            let taille_texte_niveau = measure_text(&texte_niveau, None, 40, 1.0).width;
             draw_text(
-                &format!("Vous êtes mort au niveau {} !", niveau),
-                (screen_width() - taille_texte_niveau) / 2.0,
-                screen_height() / 2.0,
+                &texte_niveau,
+                (ecran.x - taille_texte_niveau) / 2.0,
+                ecran.y / 2.0,
                 40.0,
                 WHITE,
             );
 
+            let texte_score = format!("Score : {}", monde.get_score());
+            let taille_texte_score = measure_text(&texte_score, None, 30, 1.0).width;
+            draw_text(
+                &texte_score,
+                (ecran.x - taille_texte_score) / 2.0,
+                ecran.y / 2.0 + 35.0,
+                30.0,
+                WHITE,
+            );
+
             // On dessine à l'écran le texte avec des indications pour recommencer à jouer.
-            let taille_texte_info = measure_text(
-                "Appuyez sur 'Entrée' pour recommencer ou 'Échap' pour quitter.",
-                None,
-                25,
-                1.0,
-            )
-            .width;
+            // Tant que le délai n'est pas écoulé, on affiche seulement "...".
+            // Si le score entre dans le tableau, on demande d'abord les initiales du joueur.
+            let texte_info = match &saisie_initiales {
+                Some(saisie) => format!(
+                    "Nouveau record ! Vos initiales : {} (puis 'Entrée')",
+                    saisie.texte()
+                ),
+                None if pret => {
+                    "'Entrée' pour recommencer, 'D' pour la démo ou 'Échap' pour quitter."
+                        .to_string()
+                }
+                None => "...".to_string(),
+            };
+            let taille_texte_info = measure_text(&texte_info, None, 25, 1.0).width;
             draw_text(
-                "Appuyez sur 'Entrée' pour recommencer ou 'Échap' pour quitter.",
-                (screen_width() - taille_texte_info) / 2.0,
-                screen_height() / 2.0 + 50.0,
+                &texte_info,
+                (ecran.x - taille_texte_info) / 2.0,
+                ecran.y / 2.0 + 70.0,
                 25.0,
                 WHITE,
             );
 
-            if is_key_pressed(KeyCode::Enter) {
-                // Réinitialiser le jeu si on appuie sur la touche "Entrée".
-                // On nettoye le vecteur avec les astéroïdes.
-                asteroids.clear();
-                // On génère 8 nouveaux astéroïdes qu'on stocke dans ce vecteur.
-                for _ in 0..8 {
-                    asteroids.push(Asteroid::new());
+            // Tableau des meilleurs scores, sous les indications.
+            for (rang, entree) in table_scores.entrees().iter().enumerate() {
+                let ligne = format!("{:>2}. {} {:>7}", rang + 1, entree.nom, entree.score);
+                let largeur = measure_text(&ligne, None, 20, 1.0).width;
+                draw_text(
+                    &ligne,
+                    (ecran.x - largeur) / 2.0,
+                    ecran.y / 2.0 + 110.0 + rang as f32 * 22.0,
+                    20.0,
+                    GRAY,
+                );
+            }
+
+            if let Some(eclat) = &eclat_bouclier {
+                eclat.draw(get_time());
+            }
+
+            if let Some(saisie) = &mut saisie_initiales {
+                while let Some(caractere) = get_char_pressed() {
+                    saisie.ajouter(caractere);
+                }
+                if is_key_pressed(KeyCode::Backspace) {
+                    saisie.effacer();
                 }
-                // On crée un nouveau vaisseau.
-                // On nettoie le vecteur qui stocke les missiles présents à l'écran.
-                // Et on remet le niveau à 1.
-                vaisseau = Spaceship::new();
-                missiles.clear();
-                niveau = 1;
+                // Les initiales complètes sont validées avec "Entrée" : le score est ajouté et enregistré.
+                if saisie.est_complete() && is_key_pressed(KeyCode::Enter) {
+                    table_scores.inserer(saisie.initiales(), monde.get_score());
+                    if let Err(erreur) = table_scores.sauvegarder(&settings.fichier_scores) {
+                        eprintln!(
+                            "Impossible d'enregistrer les scores '{}' : {erreur}",
+                            settings.fichier_scores
+                        );
+                    }
+                    saisie_initiales = None;
+                }
+            } else if pret && is_key_pressed(KeyCode::Enter) {
+                // Réinitialiser le jeu si on appuie sur la touche "Entrée".
+                // Nouvelle vague de départ, nouveau vaisseau, plus de missiles, niveau et vies remis à leur valeur de départ.
+                monde.nouvelle_partie(&settings, ecran);
+                debut_game_over = None;
+                joueur_a_joue = false;
+            } else if pret && demo_a_lancer(debut, get_time(), is_key_pressed(KeyCode::D)) {
+                // La démo joue une nouvelle partie, jusqu'à ce que le joueur appuie sur une touche.
+                monde.nouvelle_partie(&settings, ecran);
+                debut_game_over = None;
+                joueur_a_joue = false;
+                mode_demo = true;
             }
 
             // Cependant, si la touche "Echap" est appuyé, on quitte le jeu.
+            // Si le joueur n'a pas fini de taper ses initiales, son score est quand même enregistré.
             if is_key_down(KeyCode::Escape) {
+                if saisie_initiales.is_some() {
+                    sauvegarder_avant_de_quitter(&monde, joueur_a_joue);
+                }
                 break;
             }
 
+            dessiner_bandes_noires(&settings);
+            next_frame().await;
+            limiter_fps(settings.fps_max, debut_frame);
+            debut_frame = get_time();
+            continue;
+        }
+
+        // Mode photo : la scène figée est dessinée sans l'interface, et la partie n'avance pas.
+        if let Some(photo) = &mode_photo {
+            draw(&monde.asteroids, None, &texture_asteroid, &fond);
+            for nuage in &monde.nuages {
+                nuage.draw();
+            }
+            monde.vaisseau.draw();
+            monde.bonus.draw(&texture_bouclier);
+            dessiner_missiles(&monde.missiles, &settings);
+            dessiner_boss(&monde);
+            dessiner_cluster(&monde, &texture_asteroid);
+            for explosion in &explosions {
+                explosion.draw(photo.instant());
+            }
+            dessiner_bandes_noires(&settings);
+            next_frame().await;
+            limiter_fps(settings.fps_max, debut_frame);
+            debut_frame = get_time();
+            continue;
+        }
+
+        // Pause automatique : la partie reste figée (mais affichée, assombrie) quand la fenêtre a perdu le focus,
+        // jusqu'à ce que le joueur appuie sur une touche ou clique. Cette touche ne fait que reprendre la partie :
+        // la frame où elle est appuyée reste figée.
+        let action_joueur =
+            get_last_key_pressed().is_some() || is_mouse_button_pressed(MouseButton::Left);
+        let figee = match evenement_focus(get_frame_time(), action_joueur) {
+            Some(evenement) => pause_auto.signaler(evenement),
+            None => pause_auto.est_en_pause(),
+        };
+        if figee {
+            draw(&monde.asteroids, None, &texture_asteroid, &fond);
+            monde.vaisseau.draw();
+            dessiner_pause_auto(ecran);
+            dessiner_bandes_noires(&settings);
+            next_frame().await;
+            limiter_fps(settings.fps_max, debut_frame);
+            debut_frame = get_time();
+            continue;
+        }
+
+        // Console de développement (touche ~) : le jeu est en pause tant qu'elle est ouverte.
+        if is_key_pressed(KeyCode::GraveAccent) && enregistrement.is_none() {
+            while get_char_pressed().is_some() {} // On oublie les touches tapées avant l'ouverture.
+            console.basculer();
+        }
+        if console.est_ouverte() {
+            draw(&monde.asteroids, None, &texture_asteroid, &fond);
+            monde.vaisseau.draw();
+            console.saisir(&mut monde, &mut settings, ecran);
+            console.draw(ecran);
+            dessiner_bandes_noires(&settings);
+            next_frame().await;
+            limiter_fps(settings.fps_max, debut_frame);
+            debut_frame = get_time();
+            continue;
+        }
+
+        // Boutique entre deux vagues (touches 1 à 4 pour acheter, Entrée pour continuer) : la partie attend
+        // que le joueur la ferme. Le pilote automatique du mode démo n'achète rien.
+        if monde.boutique_ouverte() && mode_demo {
+            monde.fermer_boutique();
+        }
+        if monde.boutique_ouverte() {
+            for (touche, amelioration) in TOUCHES_BOUTIQUE.iter().zip(Amelioration::TOUTES) {
+                if is_key_pressed(*touche) {
+                    monde.acheter(amelioration);
+                }
+            }
+            if is_key_pressed(KeyCode::Enter) {
+                monde.fermer_boutique();
+            }
+            draw(&monde.asteroids, None, &texture_asteroid, &fond);
+            monde.vaisseau.draw();
+            dessiner_boutique(&monde, ecran);
+            dessiner_bandes_noires(&settings);
             next_frame().await;
+            limiter_fps(settings.fps_max, debut_frame);
+            debut_frame = get_time();
             continue;
         }
 
         // On dessine les éléments à l'écran.
-        draw(&asteroids, niveau, &texture_asteroid, &background_texture);
+        let menace = if settings.indice_menace {
+            asteroide_plus_menacant(
+                monde.vaisseau.get_position(),
+                monde.vaisseau.get_vitesse(),
+                &monde.asteroids,
+            )
+        } else {
+            None
+        };
+        draw(&monde.asteroids, menace, &texture_asteroid, &fond);
+        for nuage in &monde.nuages {
+            nuage.draw(); // Nuages de débris des grands astéroïdes détruits
+        }
 
-        vaisseau.draw(); // On dessine le vaisseau
-        vaisseau.maj_pos(&mut asteroids); // Mise à jour de chaque position et gestion de la collision avec les astéroïdes
-        vaisseau.dessiner_interface_bouclier(); // En haut à droite on affiche le pourcentage restant du bouclier.
+        // Aide à la visée : on entoure l'astéroïde que toucherait un missile tiré maintenant.
+        if settings.aide_visee {
+            let rotation = monde.vaisseau.get_rotation();
+            if let Some(index) = premiere_cible(
+                monde.vaisseau.point_tir(),
+                vec2(rotation.cos(), rotation.sin()),
+                &monde.asteroids,
+            ) {
+                entourer_asteroid(&monde.asteroids[index], SKYBLUE);
+            }
+        }
 
-        // Mettre à jour le bonus (apparition et disparition)
-        bonus.update_bonus(get_frame_time(), vaisseau.get_bouclier());
+        // Verrouillage (touche L) : un réticule suit l'astéroïde le plus proche devant le vaisseau,
+        // jusqu'à sa destruction. Appuyer à nouveau sur L relâche le verrouillage.
+        if is_key_pressed(KeyCode::L) {
+            let rotation = monde.vaisseau.get_rotation();
+            verrou = match verrou {
+                Some(_) => None,
+                None => cible_a_verrouiller(
+                    monde.vaisseau.get_position(),
+                    vec2(rotation.cos(), rotation.sin()),
+                    &monde.asteroids,
+                ),
+            };
+        }
+        verrou = maj_verrou(verrou, &monde.asteroids);
+        if let Some(asteroid) = verrou.and_then(|id| {
+            monde
+                .asteroids
+                .iter()
+                .find(|asteroid| asteroid.get_id() == id)
+        }) {
+            dessiner_reticule(asteroid);
+        }
 
-        // Dessiner le bonus s'il est visible
-        bonus.draw_bonus(&texture_bouclier);
+        // Bip de proximité, de plus en plus rapide quand un astéroïde se rapproche du vaisseau.
+        audio.bip_proximite(
+            distance_plus_proche(monde.vaisseau.get_position(), &monde.asteroids),
+            get_time(),
+        );
 
-        // Vérifier si le vaisseau récupère le bonus
-        if bonus.verifier_collision(vaisseau.get_position(), 15.0) {
-            vaisseau.restaurer_bouclier(); // Remettre le bouclier à 100%
+        // Sonar (touche E) : pendant un instant, montre où seront les astéroïdes un peu plus tard.
+        if is_key_pressed(KeyCode::E) && sonar_disponible(dernier_sonar, get_time()) {
+            dernier_sonar = Some(get_time());
+        }
+        if dernier_sonar.is_some_and(|debut| get_time() - debut < DUREE_SONAR) {
+            dessiner_sonar(&monde.asteroids);
         }
 
-        // Tirs du vaisseau
-        if is_key_pressed(KeyCode::Space) {
-            // Créer un nouveau missile en utilisant la position et la direction du vaisseau
-            let nv_missile =
-                Missile::nouveau_missile(vaisseau.get_position(), vaisseau.get_rotation());
-            missiles.push(nv_missile);
+        if is_key_pressed(KeyCode::F3) {
+            mode_debug = !mode_debug;
+        }
+        if mode_debug {
+            dessiner_vecteurs_vitesse(&monde.asteroids);
+            dessiner_vitesse_vaisseau(&monde.vaisseau);
+            // En mode debug, N passe directement au niveau suivant (sauf pendant un enregistrement,
+            // qui ne pourrait pas le rejouer).
+            if is_key_pressed(KeyCode::N) && enregistrement.is_none() {
+                monde.passer_niveau(&settings, ecran);
+            }
         }
 
-        // Mettre à jour et dessiner les missiles
-        for missile in missiles.iter_mut() {
-            missile.maj_pos_missile();
-            missile.dessiner_missile();
+        // Le joueur reprend la main dès qu'il appuie sur une touche : la démo s'arrête et une vraie partie commence.
+        if mode_demo && get_last_key_pressed().is_some() {
+            mode_demo = false;
+            monde.nouvelle_partie(&settings, ecran);
         }
 
-        // Gestion des collisions entre missiles et astéroïdes
-        let mut asteroids_to_remove = Vec::new(); // Pour stocker les astéroïdes qui vont être enlevés.
-        let mut missiles_to_remove = Vec::new(); // Pour stocker les missiles qui vont devoir être enlevés.
-        let mut new_asteroids = Vec::new(); // Pour stocker les astéroïdes créés lors de la fragmentation
-
-        for (missile_index, missile) in missiles.iter().enumerate() {
-            for (asteroid_index, asteroid) in asteroids.iter_mut().enumerate() {
-                let distance = missile.get_position().distance(asteroid.get_position()); // Calcul de la distance entre le missile et le centre de l'astéroïde
-                let collision_distance = 3.0 + asteroid.rayon_asteroid(); // Calcul de la distance entre le centre de l'astéroïde et le rebord
-                                                                          // Si le missile se trouve entre le centre de l'astéroïde et le rebord = Collision
-                if distance < collision_distance {
-                    // Collision détectée entre un missile et un astéroïde
-                    asteroid.diminuer_résistance(); // Donc on enlève un point de résistance
-                                                    // Si l'astéroïde n'a plus de résistance, il est alors détruit.
-                    asteroid.get_resistance();
-                    if asteroid.est_détruit() {
-                        println!("Astéroïde détruit !");
-                        // Donc on va créer deux nouveaux astéroïdes.
-                        match asteroid.get_taille() {
-                            3 => {
-                                // Créer 2 astéroïdes de taille 2
-                                let (position1, position2) = positions_asteroides_apres_collision(
-                                    missile.get_position(),
-                                    asteroid.get_position(),
-                                );
-                                new_asteroids.push(Asteroid::nouvel_asteroid(2, position1));
-                                new_asteroids.push(Asteroid::nouvel_asteroid(2, position2));
-                            }
-                            2 => {
-                                // Créer 2 astéroïdes de taille 1
-                                let (position1, position2) = positions_asteroides_apres_collision(
-                                    missile.get_position(),
-                                    asteroid.get_position(),
-                                );
-                                new_asteroids.push(Asteroid::nouvel_asteroid(1, position1));
-                                new_asteroids.push(Asteroid::nouvel_asteroid(1, position2));
-                            }
-                            _ => {}
-                        }
-                        // Et on va rajouter les anciens astéroïdes à la liste des astéroïdes qu'on doit enlever.
-                        asteroids_to_remove.push(asteroid_index);
-                    }
-                    // Même principe pour les missiles.
-                    missiles_to_remove.push(missile_index);
-                    break; // Le missile ne peut toucher qu'un astéroïde
-                }
+        // On lit le clavier une seule fois par frame, ou on laisse le pilote automatique jouer en mode démo.
+        let entree = if mode_demo {
+            // En haut au centre, à l'écart des éléments de l'interface.
+            if hud_visible {
+                let largeur = measure_text("DEMO", None, 30, 1.0).width;
+                draw_text("DEMO", (ecran.x - largeur) / 2.0, 30.0, 30.0, YELLOW);
+            }
+            // Le pilote choisit le vrai sens de rotation : on compense l'inversion des commandes.
+            let entree = pilote_auto(&monde.vaisseau, &monde.asteroids);
+            if settings.inverser_rotation {
+                entree.rotation_inversee()
+            } else {
+                entree
             }
+        } else if settings.visee_souris {
+            let origine = zone_fenetre(&settings).point();
+            dessiner_viseur(origine);
+            InputState::clavier_et_souris(origine)
+        } else {
+            InputState::clavier()
+        };
+
+        if !mode_demo && !entree.est_vide() {
+            joueur_a_joue = true;
         }
 
-        // Supprimer les astéroïdes détruits
-        asteroids_to_remove.sort_unstable();
-        for index in asteroids_to_remove.iter().rev() {
-            if *index < asteroids.len() {
-                asteroids.remove(*index);
+        // Toute la partie avance d'une frame : vaisseau, bonus, tirs, missiles, collisions et astéroïdes.
+        // Pendant un enregistrement, le temps avance d'exactement `PAS_TEMPS` par frame, avec la zone de jeu et
+        // les paramètres enregistrés au début de la partie.
+        match &mut enregistrement {
+            Some(replay) => {
+                replay.enregistrer(entree);
+                let temps = temps_frame(replay.entrees.len() - 1);
+                monde.update(&entree, &replay.settings, temps, PAS_TEMPS, replay.ecran);
             }
+            None => monde.update(&entree, &settings, get_time(), get_frame_time(), ecran),
         }
 
-        // Ajouter les nouveaux astéroïdes créés lors de la fragmentation
-        asteroids.extend(new_asteroids);
+        monde.vaisseau.draw(); // On dessine le vaisseau
+        if monde.aimant_actif() {
+            // Zone dans laquelle l'aimant repousse les astéroïdes.
+            let position = monde.vaisseau.get_position();
+            draw_circle_lines(
+                position.x,
+                position.y,
+                RAYON_AIMANT,
+                2.0,
+                Color::new(1.0, 0.0, 1.0, 0.35),
+            );
+        }
+        if monde.vaisseau.prendre_bouclier_brise() {
+            eclat_bouclier = Some(EclatBouclier::nouveau(
+                monde.vaisseau.get_position(),
+                get_time(),
+            ));
+        }
+        if let Some(eclat) = &eclat_bouclier {
+            eclat.draw(get_time());
+        }
+        maj_explosions(
+            &mut explosions,
+            monde.prendre_destructions(),
+            get_time(),
+            &mut monde.rng_effets,
+        );
 
-        // Supprimer les missiles qui ont touché un astéroïde
-        missiles_to_remove.sort_unstable();
-        for index in missiles_to_remove.iter().rev() {
-            if *index < missiles.len() {
-                missiles.remove(*index);
-            }
+        // L'interface : niveau et vies en haut à gauche, bouclier et jauge de danger en haut à droite.
+        if is_key_pressed(KeyCode::H) {
+            hud_visible = !hud_visible;
+        }
+        dessiner_hud(hud_visible, &monde, &settings);
+
+        // Dessiner les bonus visibles
+        monde.bonus.draw(&texture_bouclier);
+
+        // Au début de chaque vague, le compte à rebours s'affiche au centre de l'écran.
+        if let Some(texte) = monde.texte_compte_a_rebours() {
+            let largeur = measure_text(&texte, None, 80, 1.0).width;
+            draw_text(
+                &texte,
+                (ecran.x - largeur) / 2.0,
+                ecran.y / 3.0,
+                80.0,
+                WHITE,
+            );
         }
 
-        // Si tous les astéroïdes sont détruits, passer au niveau suivant.
-        // Premier niveau = 5 astéroïdes, ensuite 1 astéroïde de plus à chaque niveau.
-        if asteroids.is_empty() {
-            niveau += 1;
-            for _ in 0..(4 + niveau) {
-                asteroids.push(Asteroid::new());
+        dessiner_missiles(&monde.missiles, &settings);
+        dessiner_boss(&monde);
+        dessiner_cluster(&monde, &texture_asteroid);
+
+        // Astuces pour les nouveaux joueurs, une à la fois, au moment où elles sont utiles ("Entrée" pour fermer).
+        if !mode_demo {
+            let mut nouvelle_astuce =
+                tutoriel.proposer(Astuce::Diriger) || tutoriel.proposer(Astuce::Tirer);
+            if monde.bonus.nombre_visibles() > 0 {
+                nouvelle_astuce |= tutoriel.proposer(Astuce::Bonus);
             }
-            // On recentre le vaisseau et on enlève tous les missiles qui avaient été tirés avant.
-            vaisseau.recentrer();
-            missiles.clear();
+            if nouvelle_astuce {
+                if let Err(erreur) = tutoriel.sauvegarder(&settings.fichier_tutoriel) {
+                    eprintln!(
+                        "Impossible d'enregistrer le tutoriel '{}' : {erreur}",
+                        settings.fichier_tutoriel
+                    );
+                }
+            }
+            if is_key_pressed(KeyCode::Enter) {
+                tutoriel.fermer();
+            }
+            tutoriel.draw(ecran);
         }
 
         if handle_input() {
+            terminer_enregistrement(&mut enregistrement, arguments.enregistrement.as_deref());
+            sauvegarder_avant_de_quitter(&monde, joueur_a_joue);
             break;
         }
 
-        update_model(&mut asteroids);
-
-        next_frame().await
+        dessiner_bandes_noires(&settings);
+        next_frame().await;
+        limiter_fps(settings.fps_max, debut_frame);
+        debut_frame = get_time();
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ::rand::thread_rng;
 
     /// Les tests unitaires ont utilisant macroquad génèrent des erreurs.
     /// On simule alors la création des asteroids avec une nouvelle structure qui créé un astéroïde avec des variables prenant des f32 au lieu de Vec2 (fonction de Macroquad).
@@ -376,6 +1601,178 @@ mod tests {
         }
     }
 
+    /// Le temps d'attente complète la frame jusqu'à la durée visée, et vaut zéro si la frame a été trop longue.
+    #[test]
+    fn test_duree_attente_frame() {
+        let attente = duree_attente_frame(50, 0.005); // Frame visée : 20ms, écoulé : 5ms.
+        assert!((attente.as_secs_f64() - 0.015).abs() < 1e-9);
+        assert_eq!(duree_attente_frame(50, 0.030), Duration::ZERO);
+    }
+
+    /// Le danger augmente quand un astéroïde se rapproche du vaisseau.
+    #[test]
+    fn test_danger_augmente_quand_asteroide_approche() {
+        let vaisseau = vec2(400.0, 300.0);
+        let mut precedent = -1.0;
+        for x in [1000.0, 700.0, 550.0, 450.0] {
+            let danger = niveau_danger(
+                vaisseau,
+                &[Asteroid::nouvel_asteroid(
+                    &mut thread_rng(),
+                    1,
+                    vec2(x, 300.0),
+                )],
+            );
+            assert!((0.0..=1.0).contains(&danger));
+            assert!(danger > precedent);
+            precedent = danger;
+        }
+    }
+
+    /// Le danger est nul quand tous les astéroïdes sont loin (ou absents).
+    #[test]
+    fn test_danger_nul_si_asteroides_loin() {
+        let vaisseau = vec2(400.0, 300.0);
+        let asteroids = [
+            Asteroid::nouvel_asteroid(&mut thread_rng(), 3, vec2(1200.0, 300.0)),
+            Asteroid::nouvel_asteroid(&mut thread_rng(), 1, vec2(400.0, -600.0)),
+        ];
+        assert!(niveau_danger(vaisseau, &asteroids) < 1e-6);
+        assert_eq!(niveau_danger(vaisseau, &[]), 0.0);
+    }
+
+    /// L'opacité pulsée reste entre 40% et 100% de l'opacité de base et parcourt toute cette plage.
+    #[test]
+    fn test_couleur_pulse() {
+        let (mut min, mut max) = (f32::MAX, f32::MIN);
+        for i in 0..100 {
+            let couleur = couleur_pulse(RED, i as f64 / 100.0);
+            assert!(couleur.a >= 0.4 - 1e-6 && couleur.a <= 1.0 + 1e-6);
+            assert_eq!((couleur.r, couleur.g, couleur.b), (RED.r, RED.g, RED.b));
+            min = min.min(couleur.a);
+            max = max.max(couleur.a);
+        }
+        assert!(min < 0.45 && max > 0.95);
+    }
+
+    /// Le verrouillage choisit l'astéroïde le plus proche devant le vaisseau, et ignore ceux qui sont derrière.
+    #[test]
+    fn test_cible_a_verrouiller() {
+        let derriere = Asteroid::scripté(2, vec2(350.0, 300.0), Vec2::ZERO);
+        let loin_devant = Asteroid::scripté(2, vec2(700.0, 320.0), Vec2::ZERO);
+        let devant = Asteroid::scripté(2, vec2(500.0, 250.0), Vec2::ZERO);
+        let asteroids = vec![derriere, loin_devant, devant.clone()];
+        let cible = cible_a_verrouiller(vec2(400.0, 300.0), vec2(1.0, 0.0), &asteroids);
+        assert_eq!(cible, Some(devant.get_id()));
+        assert_eq!(
+            cible_a_verrouiller(vec2(400.0, 300.0), vec2(-1.0, 0.0), &asteroids[1..]),
+            None
+        );
+    }
+
+    /// Le verrouillage est relâché dès que l'astéroïde verrouillé n'est plus à l'écran.
+    #[test]
+    fn test_verrou_relache_apres_destruction() {
+        let mut asteroids = vec![
+            Asteroid::scripté(2, vec2(100.0, 100.0), Vec2::ZERO),
+            Asteroid::scripté(3, vec2(500.0, 300.0), Vec2::ZERO),
+        ];
+        let verrou = Some(asteroids[1].get_id());
+        assert_eq!(maj_verrou(verrou, &asteroids), verrou);
+
+        // Un autre astéroïde disparaît avant lui : les indices changent, mais le verrouillage tient.
+        asteroids.remove(0);
+        assert_eq!(maj_verrou(verrou, &asteroids), verrou);
+
+        asteroids.remove(0);
+        assert_eq!(maj_verrou(verrou, &asteroids), None);
+    }
+
+    /// Entrée est ignorée tant que le délai après le game over n'est pas écoulé.
+    #[test]
+    fn test_redemarrage_autorise() {
+        assert!(!redemarrage_autorise(10.0, 10.0, 1.0));
+        assert!(!redemarrage_autorise(10.0, 10.5, 1.0));
+        assert!(redemarrage_autorise(10.0, 11.0, 1.0));
+        assert!(redemarrage_autorise(10.0, 10.0, 0.0)); // Sans délai, on peut recommencer tout de suite.
+    }
+
+    /// La démo se lance à la demande du joueur, ou toute seule après un moment d'inactivité.
+    #[test]
+    fn test_demo_a_lancer() {
+        assert!(!demo_a_lancer(10.0, 11.0, false));
+        assert!(demo_a_lancer(10.0, 11.0, true));
+        assert!(!demo_a_lancer(10.0, 10.0 + DELAI_DEMO - 0.1, false));
+        assert!(demo_a_lancer(10.0, 10.0 + DELAI_DEMO, false));
+    }
+
+    /// Le sonar est disponible au départ, puis seulement une fois rechargé.
+    #[test]
+    fn test_sonar_disponible() {
+        assert!(sonar_disponible(None, 0.0));
+        assert!(!sonar_disponible(Some(10.0), 10.0));
+        assert!(!sonar_disponible(Some(10.0), 10.0 + RECHARGE_SONAR - 0.1));
+        assert!(sonar_disponible(Some(10.0), 10.0 + RECHARGE_SONAR));
+    }
+
+    /// L'astéroïde qui fonce droit sur le vaisseau est choisi, pas un astéroïde plus proche qui passe à côté.
+    #[test]
+    fn test_asteroide_plus_menacant() {
+        let vaisseau = vec2(400.0, 300.0);
+        let mut a_cote = Asteroid::nouvel_asteroid(&mut thread_rng(), 1, vec2(400.0, 200.0));
+        a_cote.nouvelle_vitesse(vec2(1.0, 0.0)); // Passe au-dessus du vaisseau sans le toucher.
+        let mut loin = Asteroid::nouvel_asteroid(&mut thread_rng(), 1, vec2(100.0, 300.0));
+        loin.nouvelle_vitesse(vec2(2.0, 0.0)); // Fonce droit sur le vaisseau.
+        let mut lent = Asteroid::nouvel_asteroid(&mut thread_rng(), 1, vec2(700.0, 300.0));
+        lent.nouvelle_vitesse(vec2(-0.5, 0.0)); // Fonce aussi sur le vaisseau, mais arrivera plus tard.
+        let asteroids = vec![a_cote, loin, lent];
+
+        assert_eq!(
+            asteroide_plus_menacant(vaisseau, Vec2::ZERO, &asteroids),
+            Some(1)
+        );
+    }
+
+    /// Le tir touche l'astéroïde le plus proche sur sa trajectoire, et ignore ceux à côté ou derrière.
+    #[test]
+    fn test_premiere_cible() {
+        let origine = vec2(400.0, 300.0);
+        let asteroids = vec![
+            Asteroid::nouvel_asteroid(&mut thread_rng(), 1, vec2(400.0, 100.0)), // Hors de la trajectoire
+            Asteroid::nouvel_asteroid(&mut thread_rng(), 2, vec2(700.0, 300.0)), // Sur la trajectoire, derrière le suivant
+            Asteroid::nouvel_asteroid(&mut thread_rng(), 1, vec2(550.0, 305.0)), // Sur la trajectoire, le plus proche
+            Asteroid::nouvel_asteroid(&mut thread_rng(), 3, vec2(100.0, 300.0)), // Derrière le vaisseau
+        ];
+        assert_eq!(premiere_cible(origine, vec2(1.0, 0.0), &asteroids), Some(2));
+        assert_eq!(
+            premiere_cible(origine, vec2(-1.0, 0.0), &asteroids),
+            Some(3)
+        );
+        assert_eq!(premiere_cible(origine, vec2(0.0, 1.0), &asteroids), None);
+        assert_eq!(
+            premiere_cible(origine, vec2(1.0, 0.0), &asteroids[..2]),
+            Some(1)
+        );
+    }
+
+    /// Sans astéroïde, aucune menace.
+    #[test]
+    fn test_aucune_menace() {
+        assert_eq!(
+            asteroide_plus_menacant(vec2(400.0, 300.0), Vec2::ZERO, &[]),
+            None
+        );
+    }
+
+    /// Cacher l'interface retire tous ses éléments, l'afficher les dessine tous.
+    #[test]
+    fn test_elements_hud() {
+        assert!(elements_hud(false).is_empty());
+        assert_eq!(elements_hud(true), &ELEMENTS_HUD);
+        assert!(elements_hud(true).contains(&ElementHud::Bouclier));
+        assert!(elements_hud(true).contains(&ElementHud::Charges));
+    }
+
     /// Test pour `update_model`, utilisant des astéroïdes simplifiés.
     #[test]
     fn test_update_model() {
@@ -1,43 +1,215 @@
 //! Module pour gérer les missiles dans le jeu.
 //! Ce module contient la structure `Missile` et les méthodes associées pour créer et manipuler les missiles.
 
+use crate::asteroid::Asteroid;
+use crate::constantes;
+use crate::stellarobject::StellarObject;
+use crate::transform::Transform;
 use macroquad::prelude::*;
 
 /// Structure représentant un missile tiré par un vaisseau.
 /// Un 'Missile' a une position, une direction et une vitesse.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Missile {
-    position: Vec2, // Position actuelle du missile
-    vitesse: Vec2,  // Vecteur vitesse du missile
+    transform: Transform, // Position actuelle et vecteur vitesse du missile
+    durabilite: u8,       // Nombre d'astéroïdes que le missile peut encore toucher
+    en_contact: bool, // Vrai si le missile se trouvait dans un astéroïde à la dernière vérification
+    gravite: f32, // Accélération vers le bas ajoutée à la vitesse à chaque frame (0 = trajectoire droite)
+    ennemi: bool, // Vrai si le missile a été tiré par un ennemi (boss) : il touche le vaisseau, pas les astéroïdes
 }
 
 impl Missile {
+    /// Vitesse par défaut d'un missile (en pixels par frame).
+    pub const VITESSE_DEFAUT: f32 = constantes::VITESSE_MISSILE;
+
+    /// Rayon du missile (en pixels), le même pour le dessin et pour les collisions.
+    pub const RAYON: f32 = constantes::RAYON_MISSILE;
+
+    /// Longueur (en pixels) de la traînée d'un missile dessiné comme un trait laser.
+    pub const LONGUEUR_TRAINEE: f32 = 10.0;
+
     /// Méthode pour créer un nouveau missile en utilisant la position du vaisseau et sa direction.
     /// # Paramètres:
     ///     - position: position initiale du missile quand il est tiré.
     ///     - rotation: angle de rotation du vaisseau lors du tir
     /// # Retourne un nouvel objet 'Missile'.
     pub fn nouveau_missile(position: Vec2, direction: f32) -> Self {
-        let vitesse = vec2(direction.cos(), direction.sin()) * 5.0; // Vitesse de base d'un missile
-        Self { position, vitesse } // Renvoie un element missile avec une positon et une vitesse (en fonction de la direction du vaisseau)
+        Self::nouveau_missile_a_vitesse(position, direction, Self::VITESSE_DEFAUT)
+    }
+
+    /// Crée un nouveau missile avec une vitesse choisie (tir chargé, amélioration d'arme...).
+    /// # Paramètres:
+    ///     - position: position initiale du missile quand il est tiré.
+    ///     - rotation: angle de rotation du vaisseau lors du tir
+    ///     - vitesse: norme de la vitesse du missile (en pixels par frame)
+    pub fn nouveau_missile_a_vitesse(position: Vec2, direction: f32, vitesse: f32) -> Self {
+        let vitesse = vec2(direction.cos(), direction.sin()) * vitesse;
+        Self {
+            transform: Transform::new(position, vitesse),
+            durabilite: 1, // Un missile classique disparaît au premier impact
+            en_contact: false,
+            gravite: 0.0, // Un missile classique avance en ligne droite
+            ennemi: false,
+        } // Renvoie un element missile avec une positon et une vitesse (en fonction de la direction du vaisseau)
+    }
+
+    /// Crée un missile tiré par un ennemi (boss), qui vise le vaisseau du joueur.
+    /// # Paramètres:
+    ///     - position: position initiale du missile quand il est tiré.
+    ///     - direction: angle de la trajectoire du missile
+    ///     - vitesse: norme de la vitesse du missile (en pixels par frame)
+    pub fn nouveau_missile_ennemi(position: Vec2, direction: f32, vitesse: f32) -> Self {
+        Self {
+            ennemi: true,
+            ..Self::nouveau_missile_a_vitesse(position, direction, vitesse)
+        }
+    }
+
+    /// Indique si le missile a été tiré par un ennemi.
+    pub fn est_ennemi(&self) -> bool {
+        self.ennemi
+    }
+
+    /// Indique si le missile est sorti de l'écran (les missiles ne réapparaissent pas de l'autre côté).
+    /// # Arguments
+    /// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+    pub fn est_hors_ecran(&self, ecran: Vec2) -> bool {
+        let position = self.transform.position;
+        position.x < -Self::RAYON
+            || position.y < -Self::RAYON
+            || position.x > ecran.x + Self::RAYON
+            || position.y > ecran.y + Self::RAYON
     }
 
     /// Méthode pour mettre à jour la position du missile en foction de sa vitesse.
-    /// Cette méthode est appelée à chaque image pour déplacer le missile, qui avance en ligne droite.
-    pub fn maj_pos_missile(&mut self) {
-        self.position += self.vitesse;
+    /// Cette méthode est appelée à chaque image pour déplacer le missile, qui avance en ligne droite
+    /// (ou en arc de cercle vers le bas s'il est soumis à la gravité).
+    /// # Arguments
+    /// - `dt` - Nombre de frames écoulées (1.0 = une frame normale, voir `Settings::vitesse_simulation`).
+    pub fn maj_pos_missile(&mut self, dt: f32) {
+        self.transform.vitesse.y += self.gravite * dt;
+        self.transform.advance(dt);
+    }
+
+    /// Missile téléguidé : tourne la vitesse du missile vers la cible, d'un angle limité, sans changer sa norme.
+    /// # Paramètres:
+    ///     - cible: point vers lequel le missile se dirige
+    ///     - angle_max: angle maximal (en radians) dont la trajectoire tourne pendant cet appel
+    pub fn orienter_vers(&mut self, cible: Vec2, angle_max: f32) {
+        let vitesse = self.transform.vitesse;
+        let direction = cible - self.transform.position;
+        if vitesse == Vec2::ZERO || direction == Vec2::ZERO {
+            return;
+        }
+        let ecart = vitesse
+            .angle_between(direction)
+            .clamp(-angle_max, angle_max);
+        self.transform.vitesse = Vec2::from_angle(ecart).rotate(vitesse);
+    }
+
+    /// Définit la gravité du missile (mode mortier) : accélération vers le bas à chaque frame.
+    pub fn set_gravite(&mut self, gravite: f32) {
+        self.gravite = gravite;
     }
 
     /// Méthode pour obtenir la position actuelle du missile
     /// # Retourne un vecteur avec les positions x,y du missile
     pub fn get_position(&self) -> Vec2 {
-        self.position
+        self.transform.position
+    }
+
+    /// Définit le nombre d'astéroïdes que le missile peut toucher avant de disparaître.
+    pub fn set_durabilite(&mut self, durabilite: u8) {
+        self.durabilite = durabilite;
+    }
+
+    /// Retourne le nombre d'astéroïdes que le missile peut encore toucher.
+    pub fn get_durabilite(&self) -> u8 {
+        self.durabilite
+    }
+
+    /// Retire un point de durabilité au missile (lors de l'impact avec un astéroïde).
+    pub fn encaisser_impact(&mut self) {
+        self.durabilite = self.durabilite.saturating_sub(1);
+    }
+
+    /// Indique si le missile doit disparaître (s'il n'a plus de durabilité).
+    pub fn est_detruit(&self) -> bool {
+        self.durabilite == 0
+    }
+
+    /// Indique si le missile se trouvait dans un astéroïde lors de la dernière vérification des collisions.
+    /// Permet de ne compter qu'un seul impact pendant que le missile traverse un astéroïde.
+    pub fn est_en_contact(&self) -> bool {
+        self.en_contact
+    }
+
+    /// Mémorise si le missile se trouve actuellement dans un astéroïde.
+    pub fn set_en_contact(&mut self, en_contact: bool) {
+        self.en_contact = en_contact;
+    }
+
+    /// Distance entre le centre du missile et celui de l'astéroïde en dessous de laquelle le missile le touche.
+    pub fn distance_collision(asteroid: &Asteroid) -> f32 {
+        Self::RAYON + asteroid.rayon_asteroid()
+    }
+
+    /// Fait ricocher le missile : sa vitesse est réfléchie par rapport à la normale de la surface touchée.
+    /// # Paramètres:
+    ///     - normale: normale de la surface au point d'impact (pas forcément normalisée)
+    pub fn reflechir(&mut self, normale: Vec2) {
+        let normale = normale.normalize_or_zero();
+        self.transform.vitesse -= 2.0 * self.transform.vitesse.dot(normale) * normale;
     }
 
     /// Dessine le missile à l'écran.
     /// Utilise la fonction draw_circle de 'macroquad' pour dessiner un cercle rouge représentant le missile.
     /// Cette méthode est appellée à chaque frame pour afichier le missile à sa nouvelle position.
     pub fn dessiner_missile(&self) {
-        draw_circle(self.position.x, self.position.y, 2.0, RED);
+        draw_circle(
+            self.transform.position.x,
+            self.transform.position.y,
+            Self::RAYON,
+            if self.est_ennemi() { ORANGE } else { RED },
+        );
+    }
+
+    /// Calcule l'extrémité arrière de la traînée du missile, à l'opposé de sa direction de déplacement.
+    /// # Retourne la position du missile si celui-ci est immobile.
+    pub fn extremite_trainee(&self) -> Vec2 {
+        self.transform.position
+            - self.transform.vitesse.normalize_or_zero() * Self::LONGUEUR_TRAINEE
+    }
+
+    /// Dessine le missile sous forme de trait orienté selon sa vitesse (effet laser).
+    pub fn dessiner_missile_streak(&self) {
+        let fin = self.extremite_trainee();
+        draw_line(
+            self.transform.position.x,
+            self.transform.position.y,
+            fin.x,
+            fin.y,
+            2.0,
+            RED,
+        );
+    }
+}
+
+impl StellarObject for Missile {
+    fn get_position(&self) -> Vec2 {
+        self.transform.position
+    }
+
+    fn set_position(&mut self, new_position: Vec2) {
+        self.transform.position = new_position;
+    }
+
+    fn get_vitesse(&self) -> Vec2 {
+        self.transform.vitesse
+    }
+
+    fn set_vitesse(&mut self, new_vitesse: Vec2) {
+        self.transform.vitesse = new_vitesse;
     }
 }
 
@@ -51,11 +223,22 @@ mod tests {
         assert_eq!(missile.get_position(), vec2(10.0, 20.0));
     }
 
+    #[test]
+    fn test_distance_collision() {
+        for taille in 1..=3 {
+            let asteroid = Asteroid::scripté(taille, Vec2::ZERO, Vec2::ZERO);
+            assert_eq!(
+                Missile::distance_collision(&asteroid),
+                Missile::RAYON + asteroid.rayon_asteroid()
+            );
+        }
+    }
+
     #[test]
     fn test_mouvement_missile() {
         let mut missile = Missile::nouveau_missile(vec2(0.0, 0.0), 0.0);
-        missile.maj_pos_missile();
-        assert_eq!(missile.get_position(), vec2(5.0, 0.0));
+        missile.maj_pos_missile(1.0);
+        assert_eq!(missile.get_position(), vec2(Missile::VITESSE_DEFAUT, 0.0));
     }
 
     #[test]
@@ -63,15 +246,82 @@ mod tests {
         let missile = Missile::nouveau_missile(vec2(0.0, 0.0), 0.0); // Angle de rotation 0 (vers la droite)
         assert_eq!(missile.get_position(), vec2(0.0, 0.0));
         let mut missile_moving = missile;
-        missile_moving.maj_pos_missile();
-        assert_eq!(missile_moving.get_position(), vec2(5.0, 0.0)); // Vérifie qu'il avance dans la bonne direction
+        missile_moving.maj_pos_missile(1.0);
+        assert_eq!(
+            missile_moving.get_position(),
+            vec2(Missile::VITESSE_DEFAUT, 0.0)
+        ); // Vérifie qu'il avance dans la bonne direction
     }
 
     #[test]
     fn test_mouvement_apres_plusieurs_frames() {
         let mut missile = Missile::nouveau_missile(vec2(0.0, 0.0), 0.0);
-        missile.maj_pos_missile(); // 1ère mise à jour
-        missile.maj_pos_missile(); // 2ème mise à jour
-        assert_eq!(missile.get_position(), vec2(10.0, 0.0)); // Vérifie que le missile a bien avancé de 10 unités (5.0 par mise à jour)
+        missile.maj_pos_missile(1.0); // 1ère mise à jour
+        missile.maj_pos_missile(1.0); // 2ème mise à jour
+        assert_eq!(
+            missile.get_position(),
+            vec2(2.0 * Missile::VITESSE_DEFAUT, 0.0)
+        ); // Vérifie que le missile a bien avancé de 2 fois sa vitesse par défaut
+    }
+
+    #[test]
+    fn test_missile_vitesse_personnalisee() {
+        let mut missile = Missile::nouveau_missile_a_vitesse(vec2(0.0, 0.0), 0.0, 12.0);
+        missile.maj_pos_missile(1.0);
+        assert_eq!(missile.get_position(), vec2(12.0, 0.0)); // Déplacement de 12 unités par frame.
+    }
+
+    #[test]
+    fn test_durabilite_missile() {
+        let mut missile = Missile::nouveau_missile(vec2(0.0, 0.0), 0.0);
+        missile.set_durabilite(2);
+        missile.encaisser_impact();
+        assert!(!missile.est_detruit()); // Il survit au premier impact.
+        missile.encaisser_impact();
+        assert!(missile.est_detruit()); // Mais pas au second.
+    }
+
+    #[test]
+    fn test_extremite_trainee() {
+        let missile = Missile::nouveau_missile(vec2(100.0, 50.0), std::f32::consts::FRAC_PI_2); // Vers le bas
+        let fin = missile.extremite_trainee();
+        assert!((fin - vec2(100.0, 50.0 - Missile::LONGUEUR_TRAINEE)).length() < 1e-4);
+    }
+
+    /// Avec de la gravité, la vitesse vers le bas augmente à chaque frame ; sans gravité, le missile va tout droit.
+    #[test]
+    fn test_gravite_missile() {
+        let mut mortier = Missile::nouveau_missile(vec2(0.0, 0.0), 0.0);
+        mortier.set_gravite(0.1);
+        let mut precedente = mortier.transform.vitesse.y;
+        for _ in 0..10 {
+            mortier.maj_pos_missile(1.0);
+            assert!(mortier.transform.vitesse.y > precedente);
+            precedente = mortier.transform.vitesse.y;
+        }
+        assert!(mortier.get_position().y > 0.0);
+
+        let mut normal = Missile::nouveau_missile(vec2(0.0, 0.0), 0.0);
+        for _ in 0..10 {
+            normal.maj_pos_missile(1.0);
+        }
+        assert_eq!(
+            normal.get_position(),
+            vec2(10.0 * Missile::VITESSE_DEFAUT, 0.0)
+        );
+    }
+
+    /// Un missile téléguidé tourne vers sa cible d'un angle limité, sans accélérer ; une fois aligné, il va tout droit.
+    #[test]
+    fn test_orienter_vers() {
+        let mut missile = Missile::nouveau_missile(vec2(0.0, 0.0), 0.0); // Vers la droite
+        missile.orienter_vers(vec2(0.0, 100.0), 0.1); // Cible en bas
+        let vitesse = missile.transform.vitesse;
+        assert!((vitesse.to_angle() - 0.1).abs() < 1e-5);
+        assert!((vitesse.length() - Missile::VITESSE_DEFAUT).abs() < 1e-4);
+
+        let mut aligne = Missile::nouveau_missile(vec2(0.0, 0.0), 0.0);
+        aligne.orienter_vers(vec2(100.0, 0.0), 0.1);
+        assert!((aligne.transform.vitesse - vec2(Missile::VITESSE_DEFAUT, 0.0)).length() < 1e-5);
     }
 }
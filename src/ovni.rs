@@ -0,0 +1,160 @@
+//! Module pour les ovnis, les petits vaisseaux ennemis des vagues.
+//! Ce module contient la structure `Ovni` : un petit vaisseau qui entre par un bord de l'écran, le traverse
+//! horizontalement en tirant de temps en temps un missile vers le vaisseau du joueur, puis ressort de l'autre côté.
+//! Un seul missile suffit à le détruire.
+
+use crate::missile::Missile;
+use ::rand::Rng;
+use macroquad::prelude::*;
+
+/// Ovni ennemi, qui traverse l'écran en visant le vaisseau du joueur.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ovni {
+    position: Vec2,    // Centre de l'ovni
+    vitesse: f32,      // Vitesse horizontale (en pixels par frame), positive s'il va vers la droite
+    minuteur_tir: f32, // Temps (en secondes) avant le prochain tir
+}
+
+impl Ovni {
+    /// Rayon de l'ovni (en pixels), le même pour le dessin et pour les collisions.
+    pub const RAYON: f32 = 15.0;
+
+    /// Vitesse de déplacement horizontal de l'ovni (en pixels par frame).
+    pub const VITESSE: f32 = 1.5;
+
+    /// Temps (en secondes) entre les apparitions de deux ovnis d'un même niveau.
+    pub const INTERVALLE_APPARITION: f32 = 6.0;
+
+    /// Temps (en secondes) entre deux tirs.
+    pub const INTERVALLE_TIR: f32 = 1.5;
+
+    /// Vitesse des missiles de l'ovni (en pixels par frame).
+    pub const VITESSE_MISSILES: f32 = 4.0;
+
+    /// Dégâts infligés au vaisseau qui percute l'ovni (l'ovni est détruit par le choc).
+    pub const DEGATS_CONTACT: u8 = 20;
+
+    /// Points gagnés en détruisant un ovni.
+    pub const POINTS: u32 = 500;
+
+    /// Crée un ovni à la position donnée, qui tire son premier missile après `INTERVALLE_TIR` secondes.
+    /// # Arguments
+    /// - `position` - Position de départ.
+    /// - `vitesse` - Vitesse horizontale (en pixels par frame).
+    pub fn new(position: Vec2, vitesse: f32) -> Self {
+        Self {
+            position,
+            vitesse,
+            minuteur_tir: Self::INTERVALLE_TIR,
+        }
+    }
+
+    /// Crée un ovni juste derrière le bord gauche ou droit de l'écran (au hasard), à une hauteur aléatoire,
+    /// qui se dirige vers l'autre bord.
+    /// # Arguments
+    /// - `rng` - Générateur aléatoire.
+    /// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+    pub fn apparition(rng: &mut impl Rng, ecran: Vec2) -> Self {
+        let y = ecran.y * rng.gen_range(0.15..0.85);
+        if rng.gen_bool(0.5) {
+            Self::new(vec2(-Self::RAYON, y), Self::VITESSE)
+        } else {
+            Self::new(vec2(ecran.x + Self::RAYON, y), -Self::VITESSE)
+        }
+    }
+
+    /// Retourne la position du centre de l'ovni.
+    pub fn get_position(&self) -> Vec2 {
+        self.position
+    }
+
+    /// Indique si un missile du joueur touche l'ovni.
+    pub fn est_touche_par(&self, missile: &Missile) -> bool {
+        self.position.distance(missile.get_position()) < Self::RAYON + Missile::RAYON
+    }
+
+    /// Indique si l'ovni a fini de traverser l'écran (il est sorti par le bord opposé à son entrée).
+    /// # Arguments
+    /// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+    pub fn est_sorti(&self, ecran: Vec2) -> bool {
+        (self.vitesse > 0.0 && self.position.x > ecran.x + Self::RAYON)
+            || (self.vitesse < 0.0 && self.position.x < -Self::RAYON)
+    }
+
+    /// Déplace l'ovni et fait avancer son minuteur de tir.
+    /// # Arguments
+    /// - `dt` - Nombre de frames écoulées (1.0 = une frame normale, voir `Settings::vitesse_simulation`).
+    /// - `delta_time` - Temps écoulé (en secondes) depuis la dernière frame.
+    /// - `cible` - Position visée (le vaisseau du joueur).
+    /// # Retourne le missile tiré pendant cette frame, s'il y en a un.
+    pub fn avancer(&mut self, dt: f32, delta_time: f32, cible: Vec2) -> Option<Missile> {
+        self.position.x += self.vitesse * dt;
+
+        self.minuteur_tir -= delta_time;
+        if self.minuteur_tir > 0.0 {
+            return None;
+        }
+        self.minuteur_tir += Self::INTERVALLE_TIR;
+        let direction = cible - self.position;
+        Some(Missile::nouveau_missile_ennemi(
+            self.position,
+            direction.y.atan2(direction.x),
+            Self::VITESSE_MISSILES,
+        ))
+    }
+
+    /// Dessine l'ovni : une soucoupe surmontée d'un dôme.
+    pub fn draw(&self) {
+        let (x, y) = (self.position.x, self.position.y);
+        draw_circle(x, y - Self::RAYON * 0.3, Self::RAYON * 0.45, SKYBLUE);
+        draw_ellipse(x, y, Self::RAYON, Self::RAYON * 0.4, 0.0, DARKGREEN);
+        draw_ellipse_lines(x, y, Self::RAYON, Self::RAYON * 0.4, 0.0, 2.0, GREEN);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stellarobject::StellarObject;
+    use ::rand::rngs::SmallRng;
+    use ::rand::SeedableRng;
+
+    const ECRAN: Vec2 = vec2(800.0, 600.0);
+
+    /// L'ovni apparaît hors de l'écran, le traverse, et n'est sorti qu'une fois passé de l'autre côté.
+    #[test]
+    fn test_traversee() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..10 {
+            let mut ovni = Ovni::apparition(&mut rng, ECRAN);
+            assert!(ovni.position.x < 0.0 || ovni.position.x > ECRAN.x);
+            assert!(!ovni.est_sorti(ECRAN));
+
+            let frames = ((ECRAN.x + 2.0 * Ovni::RAYON) / Ovni::VITESSE) as usize;
+            for _ in 0..frames - 1 {
+                ovni.avancer(1.0, 0.0, ECRAN / 2.0);
+            }
+            assert!(!ovni.est_sorti(ECRAN));
+            ovni.avancer(1.0, 0.0, ECRAN / 2.0);
+            ovni.avancer(1.0, 0.0, ECRAN / 2.0);
+            assert!(ovni.est_sorti(ECRAN));
+        }
+    }
+
+    /// L'ovni tire à intervalle régulier un missile ennemi dirigé vers sa cible.
+    #[test]
+    fn test_tir_vise() {
+        let mut ovni = Ovni::new(vec2(100.0, 100.0), 0.0);
+        let cible = vec2(400.0, 500.0);
+        assert!(ovni
+            .avancer(1.0, Ovni::INTERVALLE_TIR / 2.0, cible)
+            .is_none());
+        let missile = ovni
+            .avancer(1.0, Ovni::INTERVALLE_TIR / 2.0, cible)
+            .unwrap();
+        assert!(missile.est_ennemi());
+        let attendue = (cible - ovni.get_position()).normalize() * Ovni::VITESSE_MISSILES;
+        assert!(missile.get_vitesse().distance(attendue) < 1e-4);
+        assert!(ovni.avancer(1.0, 0.1, cible).is_none());
+    }
+}
@@ -0,0 +1,128 @@
+//! Module pour la pause automatique quand la fenêtre perd le focus.
+//! Ce module contient la structure `PauseAuto` : la partie est figée dès que la fenêtre n'est plus au premier plan
+//! (alt-tab, fenêtre réduite), pour éviter de mourir sans pouvoir jouer et pour que les minuteurs (bonus, horde...)
+//! ne fassent pas un bond quand le jeu reprend.
+//!
+//! macroquad ne donne pas accès à l'état du focus de la fenêtre : miniquad signale bien la réduction et la
+//! restauration de la fenêtre (`window_minimized_event`, `window_restored_event`), mais macroquad 0.4 les traite
+//! dans son propre gestionnaire sans les transmettre au jeu, et `utils::repeat_all_miniquad_input` ne répète que
+//! les événements du clavier, de la souris et de l'écran tactile. On déduit donc le focus de ce que macroquad
+//! fournit : le système cesse d'afficher les frames d'une fenêtre cachée ou réduite, donc une frame anormalement
+//! longue signale une perte de focus. Le joueur signale son retour en appuyant sur une touche ou en cliquant ;
+//! cette touche ne sert qu'à reprendre, elle n'est pas prise pour une commande.
+//!
+//! Limite : une fenêtre qui passe à l'arrière-plan mais dont les frames continuent d'être affichées normalement
+//! (alt-tab sur certains systèmes) n'est pas détectée, et la partie continue.
+
+/// Durée (en secondes) au-delà de laquelle une frame signale que la fenêtre a été cachée ou réduite.
+pub const SEUIL_FRAME_GELEE: f32 = 0.5;
+
+/// Changement du focus de la fenêtre.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EvenementFocus {
+    Perdu,    // La fenêtre n'est plus au premier plan
+    Retrouve, // Le joueur est revenu sur la fenêtre
+}
+
+/// Déduit un changement de focus de la durée de la frame et des actions du joueur.
+/// # Arguments
+/// - `delta_time` - Durée (en secondes) de la dernière frame.
+/// - `action_joueur` - Vrai si le joueur a appuyé sur une touche ou cliqué pendant cette frame.
+/// # Retourne `None` si rien n'indique un changement de focus.
+pub fn evenement_focus(delta_time: f32, action_joueur: bool) -> Option<EvenementFocus> {
+    if delta_time > SEUIL_FRAME_GELEE {
+        Some(EvenementFocus::Perdu)
+    } else if action_joueur {
+        Some(EvenementFocus::Retrouve)
+    } else {
+        None
+    }
+}
+
+/// État de la pause automatique.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PauseAuto {
+    active: bool,   // La pause automatique est-elle activée dans les paramètres ?
+    en_pause: bool, // La partie est-elle actuellement figée ?
+}
+
+impl PauseAuto {
+    /// Crée l'état de la pause automatique, sans pause en cours.
+    /// # Arguments
+    /// - `active` - Réglage `pause_auto` des paramètres. Si faux, la partie n'est jamais mise en pause.
+    pub fn new(active: bool) -> Self {
+        Self {
+            active,
+            en_pause: false,
+        }
+    }
+
+    /// Prend en compte un changement de focus : la partie se fige quand le focus est perdu
+    /// et reprend quand il est retrouvé.
+    /// # Retourne vrai si la partie doit rester figée pendant cette frame : elle est en pause, ou elle vient
+    /// de reprendre (la touche ou le clic qui a ramené le joueur ne doit pas être pris pour une commande).
+    pub fn signaler(&mut self, evenement: EvenementFocus) -> bool {
+        let reprise = self.en_pause && evenement == EvenementFocus::Retrouve;
+        self.en_pause = self.active && evenement == EvenementFocus::Perdu;
+        self.en_pause || reprise
+    }
+
+    /// Indique si la partie est figée.
+    pub fn est_en_pause(&self) -> bool {
+        self.en_pause
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// La partie se fige à la perte du focus, reste figée tant qu'il n'est pas retrouvé, puis reprend.
+    #[test]
+    fn test_transitions() {
+        let mut pause = PauseAuto::new(true);
+        assert!(!pause.est_en_pause());
+        assert!(!pause.signaler(EvenementFocus::Retrouve));
+        assert!(pause.signaler(EvenementFocus::Perdu));
+        assert!(pause.signaler(EvenementFocus::Perdu)); // Une nouvelle perte ne change rien.
+        assert!(pause.est_en_pause());
+        // La frame de la reprise reste figée : la touche qui a ramené le joueur n'est pas une commande.
+        assert!(pause.signaler(EvenementFocus::Retrouve));
+        assert!(!pause.est_en_pause());
+        // Les touches suivantes sont des commandes ordinaires.
+        assert!(!pause.signaler(EvenementFocus::Retrouve));
+    }
+
+    /// Sans pause automatique, la perte du focus ne fige pas la partie.
+    #[test]
+    fn test_desactivee() {
+        let mut pause = PauseAuto::new(false);
+        assert!(!pause.signaler(EvenementFocus::Perdu));
+        assert!(!pause.est_en_pause());
+    }
+
+    /// Limite de la détection : seule la durée des frames trahit une fenêtre cachée. Une fenêtre à l'arrière-plan
+    /// dont les frames restent régulières, ou une courte saccade, ne mettent pas la partie en pause.
+    #[test]
+    fn test_limite_detection() {
+        let mut pause = PauseAuto::new(true);
+        for _ in 0..600 {
+            if let Some(evenement) = evenement_focus(1.0 / 60.0, false) {
+                pause.signaler(evenement);
+            }
+        }
+        assert!(!pause.est_en_pause());
+        assert_eq!(evenement_focus(SEUIL_FRAME_GELEE, false), None);
+    }
+
+    #[test]
+    fn test_evenement_focus() {
+        assert_eq!(evenement_focus(1.0 / 60.0, false), None);
+        assert_eq!(evenement_focus(2.0, false), Some(EvenementFocus::Perdu));
+        assert_eq!(evenement_focus(2.0, true), Some(EvenementFocus::Perdu));
+        assert_eq!(
+            evenement_focus(1.0 / 60.0, true),
+            Some(EvenementFocus::Retrouve)
+        );
+    }
+}
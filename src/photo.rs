@@ -0,0 +1,160 @@
+//! Module pour le mode photo.
+//! Ce module contient la structure `PhotoMode` : pendant le mode photo (touche F12), la partie est figée et l'interface
+//! cachée, et le joueur déplace et zoome librement la caméra sur la scène pour faire des captures d'écran.
+//! En quittant le mode photo, la caméra habituelle est rétablie.
+
+use macroquad::prelude::*;
+
+/// État du mode photo : position et zoom de la caméra.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhotoMode {
+    decalage: Vec2, // Décalage du centre de la vue par rapport au centre de l'écran (en pixels du jeu)
+    zoom: f32,      // Grossissement de la vue (1.0 = vue habituelle)
+    instant: f64, // Moment où la partie a été figée, auquel les effets (explosions...) sont dessinés
+}
+
+impl PhotoMode {
+    /// Zoom minimal (vue éloignée).
+    pub const ZOOM_MIN: f32 = 0.5;
+
+    /// Zoom maximal (vue rapprochée).
+    pub const ZOOM_MAX: f32 = 4.0;
+
+    /// Facteur de zoom d'un cran de la molette.
+    pub const PAS_ZOOM: f32 = 1.25;
+
+    /// Vitesse de déplacement de la caméra (en pixels de l'écran par seconde, quel que soit le zoom).
+    pub const VITESSE_PANORAMIQUE: f32 = 400.0;
+
+    /// Entre dans le mode photo, avec la vue habituelle.
+    /// # Arguments
+    /// - `instant` - Moment où la partie est figée.
+    pub fn new(instant: f64) -> Self {
+        Self {
+            decalage: Vec2::ZERO,
+            zoom: 1.0,
+            instant,
+        }
+    }
+
+    /// Retourne le moment où la partie a été figée.
+    pub fn instant(&self) -> f64 {
+        self.instant
+    }
+
+    /// Déplace la caméra. Plus le zoom est fort, plus le déplacement dans la scène est petit,
+    /// pour que la vue défile toujours à la même vitesse à l'écran.
+    /// # Arguments
+    /// - `direction` - Direction du déplacement (par exemple (1, 0) pour aller à droite), de norme 1 au plus.
+    /// - `delta_time` - Temps écoulé (en secondes) depuis la dernière frame.
+    pub fn deplacer(&mut self, direction: Vec2, delta_time: f32) {
+        self.decalage += direction * Self::VITESSE_PANORAMIQUE * delta_time / self.zoom;
+    }
+
+    /// Zoome ou dézoome la caméra, dans les limites `ZOOM_MIN` et `ZOOM_MAX`.
+    /// # Arguments
+    /// - `crans` - Nombre de crans de molette (positif pour se rapprocher, négatif pour s'éloigner).
+    pub fn zoomer(&mut self, crans: f32) {
+        self.zoom = (self.zoom * Self::PAS_ZOOM.powf(crans)).clamp(Self::ZOOM_MIN, Self::ZOOM_MAX);
+    }
+
+    /// Retourne le point de la scène affiché au centre de la zone de jeu.
+    /// # Arguments
+    /// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+    pub fn cible(&self, ecran: Vec2) -> Vec2 {
+        ecran / 2.0 + self.decalage
+    }
+
+    /// Retourne la caméra du mode photo, à appliquer avec `set_camera`.
+    /// La zone de jeu étant centrée dans la fenêtre, la cible est affichée au centre de la fenêtre.
+    /// # Arguments
+    /// - `fenetre` - Dimensions de la fenêtre.
+    /// - `ecran` - Dimensions de l'écran de jeu (la zone de jeu, sans les bandes noires).
+    pub fn camera(&self, fenetre: Vec2, ecran: Vec2) -> Camera2D {
+        Camera2D {
+            target: self.cible(ecran),
+            zoom: vec2(2.0 / fenetre.x, 2.0 / fenetre.y) * self.zoom,
+            ..Default::default()
+        }
+    }
+}
+
+/// Retourne la direction de déplacement de la caméra selon les touches enfoncées (de norme 1 au plus).
+/// # Arguments
+/// - `gauche`, `droite`, `haut`, `bas` - Vrai si la touche correspondante est enfoncée.
+pub fn direction_panoramique(gauche: bool, droite: bool, haut: bool, bas: bool) -> Vec2 {
+    let axe = |negatif: bool, positif: bool| positif as i32 as f32 - negatif as i32 as f32;
+    vec2(axe(gauche, droite), axe(haut, bas)).normalize_or_zero()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Le zoom reste dans ses limites, quel que soit le nombre de crans.
+    #[test]
+    fn test_zoom_borne() {
+        let mut photo = PhotoMode::new(0.0);
+        photo.zoomer(1.0);
+        assert!((photo.zoom - PhotoMode::PAS_ZOOM).abs() < 1e-5);
+        photo.zoomer(-1.0);
+        assert!((photo.zoom - 1.0).abs() < 1e-5);
+        photo.zoomer(50.0);
+        assert_eq!(photo.zoom, PhotoMode::ZOOM_MAX);
+        photo.zoomer(-50.0);
+        assert_eq!(photo.zoom, PhotoMode::ZOOM_MIN);
+    }
+
+    /// Les déplacements s'additionnent, et sont plus petits dans la scène quand le zoom est fort.
+    #[test]
+    fn test_deplacements_cumules() {
+        let ecran = vec2(800.0, 600.0);
+        let mut photo = PhotoMode::new(0.0);
+        assert_eq!(photo.cible(ecran), vec2(400.0, 300.0));
+        photo.deplacer(vec2(1.0, 0.0), 0.5);
+        photo.deplacer(vec2(1.0, 0.0), 0.5);
+        photo.deplacer(vec2(0.0, -1.0), 0.25);
+        assert_eq!(photo.cible(ecran), vec2(800.0, 200.0));
+
+        photo.zoomer(50.0); // Zoom 4 : le même déplacement parcourt quatre fois moins de scène.
+        photo.deplacer(vec2(-1.0, 0.0), 1.0);
+        assert_eq!(photo.cible(ecran), vec2(700.0, 200.0));
+    }
+
+    /// La cible est au centre de la vue, et le zoom grossit la scène autour d'elle.
+    #[test]
+    fn test_camera() {
+        let fenetre = vec2(1000.0, 600.0);
+        let ecran = vec2(800.0, 600.0); // Zone de jeu avec des bandes noires sur les côtés
+        let mut photo = PhotoMode::new(0.0);
+        photo.deplacer(vec2(1.0, 0.0), 0.25);
+        photo.zoomer(1.0);
+        let camera = photo.camera(fenetre, ecran);
+        let projection = |point: Vec2| camera.matrix().project_point3(point.extend(0.0)).truncate();
+
+        let cible = photo.cible(ecran);
+        assert!(projection(cible).length() < 1e-5);
+        // Un point à 100 pixels à droite de la cible est affiché à 100 * zoom pixels du centre de la fenêtre.
+        let ecart = projection(cible + vec2(100.0, 0.0)).x * fenetre.x / 2.0;
+        assert!((ecart - 100.0 * PhotoMode::PAS_ZOOM).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_direction_panoramique() {
+        assert_eq!(
+            direction_panoramique(false, false, false, false),
+            Vec2::ZERO
+        );
+        assert_eq!(
+            direction_panoramique(true, false, false, false),
+            vec2(-1.0, 0.0)
+        );
+        assert_eq!(
+            direction_panoramique(true, true, false, true),
+            vec2(0.0, 1.0)
+        );
+        let diagonale = direction_panoramique(false, true, true, false);
+        assert!((diagonale.length() - 1.0).abs() < 1e-5);
+        assert!(diagonale.x > 0.0 && diagonale.y < 0.0);
+    }
+}
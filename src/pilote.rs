@@ -0,0 +1,149 @@
+//! Module du pilote automatique.
+//! Ce module permet de faire voler le vaisseau tout seul (mode démo) : le pilote vise l'astéroïde
+//! le plus proche et tire lorsqu'il est à peu près aligné. Il produit un `InputState` synthétique,
+//! consommé par `Spaceship::maj_pos` exactement comme les entrées clavier.
+
+use crate::asteroid::Asteroid;
+use crate::input::InputState;
+use crate::spaceship::{angle_vers, Spaceship};
+use std::f32::consts::PI;
+
+/// Écart d'angle maximal (en radians) entre l'orientation du vaisseau et la cible pour autoriser le tir.
+pub const SEUIL_ALIGNEMENT: f32 = 0.1;
+
+/// Distance au-delà de laquelle le pilote accélère vers sa cible.
+const DISTANCE_APPROCHE: f32 = 300.0;
+
+/// Calcule les commandes du pilote automatique pour la frame actuelle.
+/// Le vaisseau tourne vers l'astéroïde le plus proche, tire quand il est aligné avec lui
+/// et s'en rapproche s'il est trop loin.
+/// # Arguments
+/// - `vaisseau` : Le vaisseau piloté.
+/// - `asteroids` : Les astéroïdes présents à l'écran.
+/// # Retourne un `InputState` vide s'il n'y a aucun astéroïde.
+pub fn pilote_auto(vaisseau: &Spaceship, asteroids: &[Asteroid]) -> InputState {
+    let position = vaisseau.get_position();
+
+    // On cherche l'astéroïde le plus proche du vaisseau.
+    let cible = asteroids.iter().min_by(|a, b| {
+        position
+            .distance(a.get_position())
+            .total_cmp(&position.distance(b.get_position()))
+    });
+
+    let Some(cible) = cible else {
+        return InputState::default();
+    };
+
+    let direction = cible.get_position() - position;
+    let ecart = ecart_angle(
+        vaisseau.get_rotation(),
+        angle_vers(position, cible.get_position()),
+    );
+    let aligne = ecart.abs() < SEUIL_ALIGNEMENT;
+
+    InputState {
+        gauche: !aligne && ecart < 0.0,
+        droite: !aligne && ecart > 0.0,
+        haut: aligne && direction.length() > DISTANCE_APPROCHE,
+        bas: false,
+        tir: aligne,
+        ralentissement: false,
+        barriere: false,
+        bombe: false,
+        visee: None,
+    }
+}
+
+/// Retourne l'écart signé (entre -PI et PI) pour passer de l'angle `depuis` à l'angle `vers`.
+fn ecart_angle(depuis: f32, vers: f32) -> f32 {
+    (vers - depuis + PI).rem_euclid(2.0 * PI) - PI
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::rand::thread_rng;
+    use macroquad::prelude::*;
+
+    /// Le vaisseau de test est en (400, 300), orienté vers la droite (rotation 0).
+    fn vaisseau_test() -> Spaceship {
+        Spaceship::avec_position(vec2(400.0, 300.0))
+    }
+
+    #[test]
+    fn test_tourne_vers_la_cible() {
+        let vaisseau = vaisseau_test();
+
+        // Astéroïde en dessous : l'angle augmente, on tourne à droite.
+        let entree = pilote_auto(
+            &vaisseau,
+            &[Asteroid::nouvel_asteroid(
+                &mut thread_rng(),
+                1,
+                vec2(400.0, 500.0),
+            )],
+        );
+        assert!(entree.droite && !entree.gauche);
+
+        // Astéroïde au-dessus : on tourne à gauche.
+        let entree = pilote_auto(
+            &vaisseau,
+            &[Asteroid::nouvel_asteroid(
+                &mut thread_rng(),
+                1,
+                vec2(400.0, 100.0),
+            )],
+        );
+        assert!(entree.gauche && !entree.droite);
+    }
+
+    #[test]
+    fn test_vise_l_asteroide_le_plus_proche() {
+        let vaisseau = vaisseau_test();
+        let asteroids = [
+            Asteroid::nouvel_asteroid(&mut thread_rng(), 1, vec2(400.0, 50.0)), // Loin, au-dessus.
+            Asteroid::nouvel_asteroid(&mut thread_rng(), 1, vec2(400.0, 350.0)), // Proche, en dessous.
+        ];
+        assert!(pilote_auto(&vaisseau, &asteroids).droite);
+    }
+
+    #[test]
+    fn test_tir_seulement_si_aligne() {
+        let vaisseau = vaisseau_test();
+
+        // Cible droit devant : on tire sans tourner.
+        let entree = pilote_auto(
+            &vaisseau,
+            &[Asteroid::nouvel_asteroid(
+                &mut thread_rng(),
+                1,
+                vec2(600.0, 300.0),
+            )],
+        );
+        assert!(entree.tir && !entree.gauche && !entree.droite);
+
+        // Cible légèrement décalée, mais au-delà du seuil : pas de tir.
+        let entree = pilote_auto(
+            &vaisseau,
+            &[Asteroid::nouvel_asteroid(
+                &mut thread_rng(),
+                1,
+                vec2(600.0, 340.0),
+            )],
+        );
+        assert!(!entree.tir);
+    }
+
+    #[test]
+    fn test_sans_asteroide() {
+        assert_eq!(pilote_auto(&vaisseau_test(), &[]), InputState::default());
+    }
+
+    #[test]
+    fn test_ecart_angle() {
+        assert!((ecart_angle(0.0, PI / 2.0) - PI / 2.0).abs() < 1e-5);
+        // On passe par le chemin le plus court, même en traversant -PI/PI.
+        assert!((ecart_angle(3.0, -3.0) - (2.0 * PI - 6.0)).abs() < 1e-5);
+    }
+}
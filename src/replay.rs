@@ -0,0 +1,642 @@
+//! Module pour enregistrer et revoir une partie.
+//! Ce module contient la structure `Replay` : la graine de la partie, la taille de la zone de jeu, les paramètres
+//! qui influent sur la simulation, et les commandes du joueur à chaque frame.
+//! Comme `World::update` ne dépend que de l'état du monde et des entrées, rejouer ces commandes sur un monde
+//! créé avec la même graine, la même zone de jeu et les mêmes paramètres redonne exactement la même partie.
+//! Pendant l'enregistrement (`--record`) comme pendant la lecture (`--replay`), chaque frame dure `PAS_TEMPS`.
+//!
+//! Les vagues (`--waves`) et la disposition de départ (`--layout`) de la partie sont enregistrées avec elle.
+//!
+//! Format du fichier : une première ligne `graine <u64>`, une ligne `ecran <largeur> <hauteur>`, une ligne
+//! `reglage <nom> <valeur>` par paramètre de la simulation, une ligne `vague <ligne du fichier de vagues>`
+//! et `disposition <ligne du fichier de disposition>` par ligne de ces fichiers, puis une ligne par frame avec les commandes actives
+//! (`g` gauche, `d` droite, `h` haut, `b` bas, `t` tir, `r` champ de ralentissement, `m` barrière, `e` bombe, `-` si aucune), éventuellement suivies du point visé
+//! à la souris, par exemple `ht@400,300`.
+
+use crate::asteroid::analyser_disposition;
+use crate::input::InputState;
+use crate::settings::Settings;
+use crate::vague::analyser_vagues;
+use crate::world::World;
+use macroquad::prelude::*;
+
+/// Durée (en secondes) d'une frame enregistrée.
+pub const PAS_TEMPS: f32 = 1.0 / 60.0;
+
+/// Retourne le temps (en secondes) au début d'une frame enregistrée.
+/// # Arguments
+/// - `frame` - Numéro de la frame (0 = première frame de la partie).
+pub fn temps_frame(frame: usize) -> f64 {
+    frame as f64 * PAS_TEMPS as f64
+}
+
+/// Retourne les paramètres qui changent le déroulement d'une partie, avec leur nom dans les fichiers enregistrés.
+/// Les paramètres d'affichage (textures, interface, aides visuelles...) n'en font pas partie.
+fn reglages_simulation(settings: &Settings) -> Vec<(&'static str, String)> {
+    let bonus = &settings.bonus;
+    vec![
+        (
+            "asteroides_initiaux",
+            settings.asteroides_initiaux.to_string(),
+        ),
+        (
+            "durabilite_missiles",
+            settings.durabilite_missiles.to_string(),
+        ),
+        ("gravite_missiles", settings.gravite_missiles.to_string()),
+        ("balayage_tir", settings.balayage_tir.to_string()),
+        (
+            "acceleration_vaisseau",
+            settings.acceleration_vaisseau.to_string(),
+        ),
+        (
+            "retention_vitesse_collision",
+            settings.retention_vitesse_collision.to_string(),
+        ),
+        (
+            "force_impulsion_collision",
+            settings.force_impulsion_collision.to_string(),
+        ),
+        ("poussee_directe", settings.poussee_directe.to_string()),
+        (
+            "rotation_inertielle",
+            settings.rotation_inertielle.to_string(),
+        ),
+        ("inverser_rotation", settings.inverser_rotation.to_string()),
+        ("freinage", settings.freinage.to_string()),
+        ("vies_initiales", settings.vies_initiales.to_string()),
+        (
+            "rejouer_vague_apres_vie",
+            settings.rejouer_vague_apres_vie.to_string(),
+        ),
+        ("graine_fixe", settings.graine_fixe.to_string()),
+        (
+            "intervalle_vie_bonus",
+            settings.intervalle_vie_bonus.to_string(),
+        ),
+        ("vies_max", settings.vies_max.to_string()),
+        ("coque_vaisseau", settings.coque_vaisseau.to_string()),
+        (
+            "regeneration_bouclier",
+            settings.regeneration_bouclier.to_string(),
+        ),
+        ("friction_vaisseau", settings.friction_vaisseau.to_string()),
+        ("rayon_assistance", settings.rayon_assistance.to_string()),
+        (
+            "facteur_assistance",
+            settings.facteur_assistance.to_string(),
+        ),
+        (
+            "vitesse_simulation",
+            settings.vitesse_simulation.to_string(),
+        ),
+        ("bonus.actifs", bonus.actifs.to_string()),
+        ("bonus.frequence_tirage", bonus.frequence_tirage.to_string()),
+        (
+            "bonus.chance_apparition",
+            bonus.chance_apparition.to_string(),
+        ),
+        (
+            "bonus.chance_bouclier_faible",
+            bonus.chance_bouclier_faible.to_string(),
+        ),
+        (
+            "bonus.seuil_bouclier_faible",
+            bonus.seuil_bouclier_faible.to_string(),
+        ),
+        ("bonus.duree_min", bonus.duree_min.to_string()),
+        ("bonus.duree_max", bonus.duree_max.to_string()),
+        (
+            "bonus.duree_bouclier_faible",
+            bonus.duree_bouclier_faible.to_string(),
+        ),
+        ("bonus.chance_surcharge", bonus.chance_surcharge.to_string()),
+        ("bonus.chance_aimant", bonus.chance_aimant.to_string()),
+        ("bonus.max_simultanes", bonus.max_simultanes.to_string()),
+        ("trou_noir", settings.trou_noir.to_string()),
+        (
+            "intervalle_nebuleuse",
+            settings.intervalle_nebuleuse.to_string(),
+        ),
+        ("intervalle_boss", settings.intervalle_boss.to_string()),
+        (
+            "intervalle_cluster",
+            settings.intervalle_cluster.to_string(),
+        ),
+        ("mode_facile", settings.mode_facile.to_string()),
+        ("nuages_debris", settings.nuages_debris.to_string()),
+        ("mode_horde", settings.mode_horde.to_string()),
+        (
+            "heritage_fragments",
+            settings.heritage_fragments.to_string(),
+        ),
+        (
+            "fragments_vers_vaisseau",
+            settings.fragments_vers_vaisseau.to_string(),
+        ),
+        (
+            "charges_ralentissement",
+            settings.charges_ralentissement.to_string(),
+        ),
+        ("charges_barriere", settings.charges_barriere.to_string()),
+        ("boutique", settings.boutique.to_string()),
+        (
+            "decroissance_score",
+            settings.decroissance_score.to_string(),
+        ),
+        ("hitstop_frames", settings.hitstop_frames.to_string()),
+        ("compte_a_rebours", settings.compte_a_rebours.to_string()),
+    ]
+}
+
+/// Applique un paramètre lu dans un fichier enregistré (ligne `reglage <nom> <valeur>`).
+/// # Retourne `None` si le paramètre est inconnu ou si sa valeur est invalide.
+fn appliquer_reglage(settings: &mut Settings, nom: &str, valeur: &str) -> Option<()> {
+    let bonus = &mut settings.bonus;
+    match nom {
+        "asteroides_initiaux" => settings.asteroides_initiaux = valeur.parse().ok()?,
+        "durabilite_missiles" => settings.durabilite_missiles = valeur.parse().ok()?,
+        "gravite_missiles" => settings.gravite_missiles = valeur.parse().ok()?,
+        "balayage_tir" => settings.balayage_tir = valeur.parse().ok()?,
+        "acceleration_vaisseau" => settings.acceleration_vaisseau = valeur.parse().ok()?,
+        "retention_vitesse_collision" => {
+            settings.retention_vitesse_collision = valeur.parse().ok()?
+        }
+        "force_impulsion_collision" => settings.force_impulsion_collision = valeur.parse().ok()?,
+        "poussee_directe" => settings.poussee_directe = valeur.parse().ok()?,
+        "rotation_inertielle" => settings.rotation_inertielle = valeur.parse().ok()?,
+        "inverser_rotation" => settings.inverser_rotation = valeur.parse().ok()?,
+        "freinage" => settings.freinage = valeur.parse().ok()?,
+        "vies_initiales" => settings.vies_initiales = valeur.parse().ok()?,
+        "rejouer_vague_apres_vie" => settings.rejouer_vague_apres_vie = valeur.parse().ok()?,
+        "graine_fixe" => settings.graine_fixe = valeur.parse().ok()?,
+        "intervalle_vie_bonus" => settings.intervalle_vie_bonus = valeur.parse().ok()?,
+        "vies_max" => settings.vies_max = valeur.parse().ok()?,
+        "coque_vaisseau" => settings.coque_vaisseau = valeur.parse().ok()?,
+        "regeneration_bouclier" => settings.regeneration_bouclier = valeur.parse().ok()?,
+        "friction_vaisseau" => settings.friction_vaisseau = valeur.parse().ok()?,
+        "rayon_assistance" => settings.rayon_assistance = valeur.parse().ok()?,
+        "facteur_assistance" => settings.facteur_assistance = valeur.parse().ok()?,
+        "vitesse_simulation" => settings.vitesse_simulation = valeur.parse().ok()?,
+        "bonus.actifs" => bonus.actifs = valeur.parse().ok()?,
+        "bonus.frequence_tirage" => bonus.frequence_tirage = valeur.parse().ok()?,
+        "bonus.chance_apparition" => bonus.chance_apparition = valeur.parse().ok()?,
+        "bonus.chance_bouclier_faible" => bonus.chance_bouclier_faible = valeur.parse().ok()?,
+        "bonus.seuil_bouclier_faible" => bonus.seuil_bouclier_faible = valeur.parse().ok()?,
+        "bonus.duree_min" => bonus.duree_min = valeur.parse().ok()?,
+        "bonus.duree_max" => bonus.duree_max = valeur.parse().ok()?,
+        "bonus.duree_bouclier_faible" => bonus.duree_bouclier_faible = valeur.parse().ok()?,
+        "bonus.chance_surcharge" => bonus.chance_surcharge = valeur.parse().ok()?,
+        "bonus.chance_aimant" => bonus.chance_aimant = valeur.parse().ok()?,
+        "bonus.max_simultanes" => bonus.max_simultanes = valeur.parse().ok()?,
+        "trou_noir" => settings.trou_noir = valeur.parse().ok()?,
+        "intervalle_nebuleuse" => settings.intervalle_nebuleuse = valeur.parse().ok()?,
+        "intervalle_boss" => settings.intervalle_boss = valeur.parse().ok()?,
+        "intervalle_cluster" => settings.intervalle_cluster = valeur.parse().ok()?,
+        "mode_facile" => settings.mode_facile = valeur.parse().ok()?,
+        "nuages_debris" => settings.nuages_debris = valeur.parse().ok()?,
+        "mode_horde" => settings.mode_horde = valeur.parse().ok()?,
+        "heritage_fragments" => settings.heritage_fragments = valeur.parse().ok()?,
+        "fragments_vers_vaisseau" => settings.fragments_vers_vaisseau = valeur.parse().ok()?,
+        "charges_ralentissement" => settings.charges_ralentissement = valeur.parse().ok()?,
+        "charges_barriere" => settings.charges_barriere = valeur.parse().ok()?,
+        "boutique" => settings.boutique = valeur.parse().ok()?,
+        "decroissance_score" => settings.decroissance_score = valeur.parse().ok()?,
+        "hitstop_frames" => settings.hitstop_frames = valeur.parse().ok()?,
+        "compte_a_rebours" => settings.compte_a_rebours = valeur.parse().ok()?,
+        _ => return None,
+    }
+    Some(())
+}
+
+/// Partie enregistrée : graine de départ, zone de jeu, paramètres et commandes du joueur, frame par frame.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Replay {
+    pub graine: u64,              // Graine du monde au début de la partie
+    pub ecran: Vec2,              // Dimensions de la zone de jeu pendant l'enregistrement
+    pub settings: Settings,       // Paramètres de la partie enregistrée
+    pub vagues: String, // Texte du fichier de vagues de la partie (vide s'il n'y en a pas)
+    pub disposition: String, // Texte du fichier de disposition de départ (vide s'il n'y en a pas)
+    pub entrees: Vec<InputState>, // Commandes du joueur, une par frame
+}
+
+impl Replay {
+    /// Commence un enregistrement vide.
+    /// # Arguments
+    /// - `graine` : Graine du monde enregistré (`World::get_graine`).
+    /// - `settings` : Paramètres de la partie enregistrée.
+    /// - `ecran` : Dimensions de la zone de jeu (largeur, hauteur).
+    pub fn nouveau(graine: u64, settings: &Settings, ecran: Vec2) -> Self {
+        Self {
+            graine,
+            ecran,
+            settings: settings.clone(),
+            vagues: String::new(),
+            disposition: String::new(),
+            entrees: Vec::new(),
+        }
+    }
+
+    /// Ajoute les commandes d'une frame à l'enregistrement.
+    pub fn enregistrer(&mut self, entree: InputState) {
+        self.entrees.push(entree);
+    }
+
+    /// Charge une partie enregistrée depuis un fichier.
+    /// # Arguments
+    /// - `chemin` : Chemin du fichier enregistré.
+    /// - `settings` : Paramètres utilisés pour ceux qui ne sont pas dans le fichier (anciens enregistrements).
+    /// - `ecran` : Zone de jeu utilisée si le fichier ne précise pas la sienne (anciens enregistrements).
+    pub fn charger(chemin: &str, settings: &Settings, ecran: Vec2) -> Result<Self, String> {
+        std::fs::read_to_string(chemin)
+            .map_err(|erreur| erreur.to_string())
+            .and_then(|texte| Self::analyser(&texte, settings, ecran))
+    }
+
+    /// Enregistre la partie dans un fichier.
+    /// # Arguments
+    /// - `chemin` : Chemin du fichier à écrire.
+    pub fn sauvegarder(&self, chemin: &str) -> std::io::Result<()> {
+        std::fs::write(chemin, self.en_texte())
+    }
+
+    /// Lit une partie au format des fichiers enregistrés.
+    /// # Arguments
+    /// - `texte` : Contenu du fichier.
+    /// - `settings` : Paramètres utilisés pour ceux qui ne sont pas dans le fichier (anciens enregistrements).
+    /// - `ecran` : Zone de jeu utilisée si le fichier ne précise pas la sienne (anciens enregistrements).
+    /// # Retourne une erreur décrivant la première ligne invalide.
+    pub fn analyser(texte: &str, settings: &Settings, ecran: Vec2) -> Result<Self, String> {
+        let mut lignes = texte
+            .lines()
+            .enumerate()
+            .filter(|(_, ligne)| !ligne.trim().is_empty())
+            .peekable();
+        let graine = lignes
+            .next()
+            .and_then(|(_, ligne)| ligne.trim().strip_prefix("graine "))
+            .and_then(|valeur| valeur.trim().parse().ok())
+            .ok_or("La première ligne doit être 'graine <nombre>'.".to_string())?;
+
+        let mut replay = Self::nouveau(graine, settings, ecran);
+        while let Some((numero, ligne)) = lignes.next_if(|(_, ligne)| {
+            ["ecran ", "reglage ", "vague ", "disposition "]
+                .iter()
+                .any(|prefixe| ligne.trim().starts_with(prefixe))
+        }) {
+            let ligne = ligne.trim();
+            let invalide = || format!("Ligne {} invalide : '{ligne}'.", numero + 1);
+            if let Some(dimensions) = ligne.strip_prefix("ecran ") {
+                let (largeur, hauteur) = dimensions.trim().split_once(' ').ok_or_else(invalide)?;
+                let largeur: f32 = largeur.parse().map_err(|_| invalide())?;
+                let hauteur: f32 = hauteur.trim().parse().map_err(|_| invalide())?;
+                if largeur <= 0.0 || hauteur <= 0.0 {
+                    return Err(invalide());
+                }
+                replay.ecran = vec2(largeur, hauteur);
+            } else if let Some(vague) = ligne.strip_prefix("vague ") {
+                replay.vagues += &format!("{}\n", vague.trim());
+            } else if let Some(asteroide) = ligne.strip_prefix("disposition ") {
+                replay.disposition += &format!("{}\n", asteroide.trim());
+            } else {
+                let (nom, valeur) = ligne["reglage ".len()..]
+                    .trim()
+                    .split_once(' ')
+                    .ok_or_else(invalide)?;
+                appliquer_reglage(&mut replay.settings, nom, valeur.trim()).ok_or_else(invalide)?;
+            }
+        }
+        analyser_vagues(&replay.vagues)?;
+        analyser_disposition(&replay.disposition)?;
+        for (numero, ligne) in lignes {
+            let ligne = ligne.trim();
+            let entree = analyser_entree(ligne)
+                .ok_or_else(|| format!("Ligne {} invalide : '{ligne}'.", numero + 1))?;
+            replay.enregistrer(entree);
+        }
+        Ok(replay)
+    }
+
+    /// Retourne la partie au format des fichiers enregistrés.
+    pub fn en_texte(&self) -> String {
+        let mut texte = format!(
+            "graine {}\necran {} {}\n",
+            self.graine, self.ecran.x, self.ecran.y
+        );
+        for (nom, valeur) in reglages_simulation(&self.settings) {
+            texte += &format!("reglage {nom} {valeur}\n");
+        }
+        for (prefixe, contenu) in [("vague", &self.vagues), ("disposition", &self.disposition)] {
+            for ligne in lignes_utiles(contenu) {
+                texte += &format!("{prefixe} {ligne}\n");
+            }
+        }
+        for entree in &self.entrees {
+            let commandes: String = [
+                (entree.gauche, 'g'),
+                (entree.droite, 'd'),
+                (entree.haut, 'h'),
+                (entree.bas, 'b'),
+                (entree.tir, 't'),
+                (entree.ralentissement, 'r'),
+                (entree.barriere, 'm'),
+                (entree.bombe, 'e'),
+            ]
+            .iter()
+            .filter(|(active, _)| *active)
+            .map(|(_, lettre)| lettre)
+            .collect();
+            texte += if commandes.is_empty() {
+                "-"
+            } else {
+                &commandes
+            };
+            if let Some(visee) = entree.visee {
+                texte += &format!("@{},{}", visee.x, visee.y);
+            }
+            texte.push('\n');
+        }
+        texte
+    }
+}
+
+/// Lit les commandes d'une frame (par exemple `ht` ou `-@400,300`).
+/// # Retourne `None` si la ligne contient une lettre inconnue ou un point visé mal formé.
+fn analyser_entree(ligne: &str) -> Option<InputState> {
+    let (commandes, visee) = match ligne.split_once('@') {
+        Some((commandes, visee)) => {
+            let (x, y) = visee.split_once(',')?;
+            (commandes, Some(vec2(x.parse().ok()?, y.parse().ok()?)))
+        }
+        None => (ligne, None),
+    };
+    let mut entree = InputState {
+        visee,
+        ..Default::default()
+    };
+    for lettre in commandes.chars() {
+        match lettre {
+            'g' => entree.gauche = true,
+            'd' => entree.droite = true,
+            'h' => entree.haut = true,
+            'b' => entree.bas = true,
+            't' => entree.tir = true,
+            'r' => entree.ralentissement = true,
+            'm' => entree.barriere = true,
+            'e' => entree.bombe = true,
+            '-' => {}
+            _ => return None,
+        }
+    }
+    Some(entree)
+}
+
+/// Retourne les lignes d'un fichier de vagues ou de disposition qui décrivent quelque chose
+/// (sans les lignes vides ni les commentaires).
+fn lignes_utiles(texte: &str) -> impl Iterator<Item = &str> {
+    texte
+        .lines()
+        .map(str::trim)
+        .filter(|ligne| !ligne.is_empty() && !ligne.starts_with('#'))
+}
+
+/// Lecture d'une partie enregistrée : rejoue les commandes, frame par frame, sur un monde.
+pub struct Lecture {
+    replay: Replay, // Partie rejouée
+    frame: usize,   // Numéro de la prochaine frame à rejouer
+}
+
+impl Lecture {
+    /// Prépare la lecture d'une partie, à partir de sa première frame.
+    pub fn new(replay: Replay) -> Self {
+        Self { replay, frame: 0 }
+    }
+
+    /// Crée le monde dans lequel la partie est rejouée (même graine, zone de jeu, paramètres, vagues
+    /// et disposition de départ que l'enregistrement).
+    pub fn creer_monde(&self) -> World {
+        let replay = &self.replay;
+        let mut monde = World::new(&replay.settings, Some(replay.graine), replay.ecran);
+        // Les vagues et la disposition ont été vérifiées au chargement du fichier.
+        if let Ok(vagues) = analyser_vagues(&replay.vagues) {
+            if !vagues.is_empty() {
+                monde.charger_vagues(vagues, &replay.settings, replay.ecran);
+            }
+        }
+        if let Ok(asteroids) = analyser_disposition(&replay.disposition) {
+            if !asteroids.is_empty() {
+                monde.charger_disposition(asteroids);
+            }
+        }
+        monde
+    }
+
+    /// Retourne les paramètres de la partie enregistrée.
+    pub fn settings(&self) -> &Settings {
+        &self.replay.settings
+    }
+
+    /// Retourne les dimensions de la zone de jeu de la partie enregistrée.
+    pub fn ecran(&self) -> Vec2 {
+        self.replay.ecran
+    }
+
+    /// Retourne le numéro de la prochaine frame à rejouer.
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    /// Retourne le nombre de frames enregistrées.
+    pub fn nombre_frames(&self) -> usize {
+        self.replay.entrees.len()
+    }
+
+    /// Indique si toutes les frames enregistrées ont été rejouées.
+    pub fn est_terminee(&self) -> bool {
+        self.frame >= self.nombre_frames()
+    }
+
+    /// Rejoue la frame suivante : le monde avance d'une frame avec les commandes enregistrées.
+    /// # Arguments
+    /// - `monde` : Monde créé par `creer_monde`.
+    /// # Retourne `false` (sans rien faire) si la lecture est terminée.
+    pub fn avancer(&mut self, monde: &mut World) -> bool {
+        let Some(entree) = self.replay.entrees.get(self.frame) else {
+            return false;
+        };
+        let replay = &self.replay;
+        monde.update(
+            entree,
+            &replay.settings,
+            temps_frame(self.frame),
+            PAS_TEMPS,
+            replay.ecran,
+        );
+        self.frame += 1;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ECRAN: Vec2 = vec2(800.0, 600.0);
+
+    /// Résume l'état d'un monde (vaisseau, astéroïdes, missiles, ovnis, score, niveau) pour pouvoir le comparer.
+    fn etat(monde: &World) -> String {
+        let asteroids: Vec<_> = monde
+            .asteroids
+            .iter()
+            .map(|a| (a.get_position(), a.get_vitesse(), a.get_taille()))
+            .collect();
+        format!(
+            "{:?} {} {asteroids:?} {} {:?} {} {}",
+            monde.vaisseau.get_position(),
+            monde.vaisseau.get_rotation(),
+            monde.missiles.len(),
+            monde.ovnis,
+            monde.get_score(),
+            monde.niveau,
+        )
+    }
+
+    #[test]
+    fn test_format_texte() {
+        // Ancien format, sans zone de jeu ni paramètres : ceux donnés à la lecture sont utilisés.
+        let settings = Settings::default();
+        let texte = "graine 42\n-\nht\ngdbtr@400.5,300\n";
+        let replay = Replay::analyser(texte, &settings, ECRAN).unwrap();
+        assert_eq!(replay.graine, 42);
+        assert_eq!(replay.ecran, ECRAN);
+        assert_eq!(replay.settings, settings);
+        assert_eq!(replay.entrees.len(), 3);
+        assert_eq!(replay.entrees[0], InputState::default());
+        assert!(replay.entrees[1].haut && replay.entrees[1].tir && !replay.entrees[1].gauche);
+        assert_eq!(replay.entrees[2].visee, Some(vec2(400.5, 300.0)));
+        assert!(replay.entrees[2].ralentissement);
+        assert_eq!(
+            Replay::analyser(&replay.en_texte(), &settings, ECRAN),
+            Ok(replay)
+        );
+
+        let analyser = |texte| Replay::analyser(texte, &settings, ECRAN);
+        assert!(analyser("ht\n").is_err()); // Pas de graine
+        assert!(analyser("graine 1\nhx\n").is_err());
+        assert!(analyser("graine 1\nh@12\n").is_err());
+        assert!(analyser("graine 1\necran 800\n-\n").is_err());
+        assert!(analyser("graine 1\necran 0 600\n-\n").is_err());
+        assert!(analyser("graine 1\nreglage inconnu 3\n-\n").is_err());
+        assert!(analyser("graine 1\nreglage mode_facile 3\n-\n").is_err());
+        assert!(Replay::charger("fichier/inexistant.txt", &settings, ECRAN).is_err());
+    }
+
+    /// La zone de jeu et tous les paramètres de la simulation sont enregistrés dans le fichier, et relus à l'identique.
+    #[test]
+    fn test_entete_zone_et_parametres() {
+        let mut settings = Settings {
+            friction_vaisseau: 0.95,
+            mode_facile: true,
+            intervalle_boss: 3,
+            heritage_fragments: 0.25,
+            ..Default::default()
+        };
+        settings.bonus.max_simultanes = 4;
+        let replay = Replay::nouveau(9, &settings, vec2(1024.0, 768.0));
+        let texte = replay.en_texte();
+        assert!(texte.starts_with("graine 9\necran 1024 768\n"));
+        assert!(texte.contains("reglage friction_vaisseau 0.95\n"));
+        assert!(texte.contains("reglage bonus.max_simultanes 4\n"));
+
+        let relu = Replay::analyser(&texte, &Settings::default(), ECRAN).unwrap();
+        assert_eq!(relu.ecran, vec2(1024.0, 768.0));
+        assert_eq!(
+            reglages_simulation(&relu.settings),
+            reglages_simulation(&settings)
+        );
+        // Chaque paramètre enregistré est bien relu.
+        for (nom, valeur) in reglages_simulation(&settings) {
+            assert!(appliquer_reglage(&mut Settings::default(), nom, &valeur).is_some());
+        }
+    }
+
+    /// Une partie enregistrée, sauvegardée puis rechargée et rejouée frame par frame arrive au même état final.
+    #[test]
+    fn test_lecture_reproduit_la_partie() {
+        // Zone de jeu et paramètres différents de ceux de la lecture : ce sont ceux de l'enregistrement qui comptent.
+        let ecran = vec2(640.0, 480.0);
+        let settings = Settings {
+            friction_vaisseau: 0.95,
+            durabilite_missiles: 2,
+            ..Default::default()
+        };
+        let mut monde = World::new(&settings, Some(7), ecran);
+        let mut enregistrement = Replay::nouveau(monde.get_graine(), &settings, ecran);
+        for frame in 0..600 {
+            let entree = InputState {
+                gauche: frame % 120 < 30,
+                haut: frame % 90 < 20,
+                tir: frame % 15 == 0,
+                ..Default::default()
+            };
+            enregistrement.enregistrer(entree);
+            monde.update(&entree, &settings, temps_frame(frame), PAS_TEMPS, ecran);
+        }
+        assert!(monde.get_score() > 0); // La partie enregistrée a bien détruit des astéroïdes.
+
+        let chemin = std::env::temp_dir().join(format!("replay_test_{}.txt", std::process::id()));
+        let chemin = chemin.to_str().unwrap();
+        enregistrement.sauvegarder(chemin).unwrap();
+        let replay = Replay::charger(chemin, &Settings::default(), ECRAN);
+        std::fs::remove_file(chemin).unwrap();
+
+        let mut lecture = Lecture::new(replay.unwrap());
+        let mut rejoue = lecture.creer_monde();
+        while lecture.avancer(&mut rejoue) {}
+        assert!(lecture.est_terminee());
+        assert_eq!(lecture.frame(), 600);
+        assert_eq!(etat(&rejoue), etat(&monde));
+    }
+
+    /// Les vagues et la disposition de départ sont enregistrées avec la partie, qui se rejoue à l'identique.
+    #[test]
+    fn test_lecture_avec_vagues_et_disposition() {
+        let settings = Settings::default();
+        let texte_vagues = "# Vagues\n1: 3 3 ovni\n2: 2B 2 | ligne\n";
+        let texte_disposition = "2 100 100 1 0\n1 600 400 -1 0.5\n";
+        let mut monde = World::new(&settings, Some(3), ECRAN);
+        monde.charger_vagues(analyser_vagues(texte_vagues).unwrap(), &settings, ECRAN);
+        monde.charger_disposition(analyser_disposition(texte_disposition).unwrap());
+        let mut enregistrement = Replay {
+            vagues: texte_vagues.to_string(),
+            disposition: texte_disposition.to_string(),
+            ..Replay::nouveau(monde.get_graine(), &settings, ECRAN)
+        };
+        for frame in 0..900 {
+            let entree = InputState {
+                droite: frame % 100 < 25,
+                tir: frame % 10 == 0,
+                ..Default::default()
+            };
+            enregistrement.enregistrer(entree);
+            monde.update(&entree, &settings, temps_frame(frame), PAS_TEMPS, ECRAN);
+        }
+
+        let texte = enregistrement.en_texte();
+        assert!(texte.contains("vague 1: 3 3 ovni\n"));
+        assert!(texte.contains("disposition 1 600 400 -1 0.5\n"));
+        assert!(!texte.contains("# Vagues"));
+        let replay = Replay::analyser(&texte, &settings, ECRAN).unwrap();
+        let mut lecture = Lecture::new(replay);
+        let mut rejoue = lecture.creer_monde();
+        assert_eq!(rejoue.asteroids.len(), 2); // La disposition remplace la première vague.
+        while lecture.avancer(&mut rejoue) {}
+        assert_eq!(etat(&rejoue), etat(&monde));
+
+        // Des vagues invalides dans le fichier sont refusées au chargement.
+        assert!(Replay::analyser("graine 1\nvague x: 3\n-\n", &settings, ECRAN).is_err());
+        assert!(
+            Replay::analyser("graine 1\ndisposition 4 0 0 0 0\n-\n", &settings, ECRAN).is_err()
+        );
+    }
+}
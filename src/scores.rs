@@ -0,0 +1,224 @@
+//! Module pour le tableau des meilleurs scores.
+//! Ce module contient la structure `TableScores`, qui garde les 10 meilleurs scores (initiales et points)
+//! et les enregistre dans un fichier texte, ainsi que `SaisieInitiales`, qui gère la saisie des 3 lettres
+//! sur l'écran "Game Over".
+//!
+//! Format du fichier : une ligne par score, les initiales puis le score, par exemple `ABC 4200`.
+
+/// Nombre de scores gardés dans le tableau.
+pub const TAILLE_TABLE: usize = 10;
+
+/// Nombre de lettres des initiales du joueur.
+pub const NOMBRE_INITIALES: usize = 3;
+
+/// Nom enregistré pour un score sauvegardé sans que le joueur ait tapé ses initiales (partie quittée en cours).
+pub const NOM_ANONYME: &str = "???";
+
+/// Une ligne du tableau des scores.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntreeScore {
+    pub nom: String, // Initiales du joueur
+    pub score: u32,  // Score obtenu
+}
+
+/// Tableau des meilleurs scores, trié du plus grand au plus petit.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TableScores {
+    entrees: Vec<EntreeScore>, // Au plus `TAILLE_TABLE` entrées, triées par score décroissant
+}
+
+impl TableScores {
+    /// Charge le tableau depuis un fichier.
+    /// Si le fichier n'existe pas encore ou est invalide, on part d'un tableau vide.
+    /// # Arguments
+    /// - `chemin` : Chemin du fichier des scores.
+    pub fn charger(chemin: &str) -> Self {
+        std::fs::read_to_string(chemin)
+            .map_err(|erreur| erreur.to_string())
+            .and_then(|texte| Self::analyser(&texte))
+            .unwrap_or_else(|erreur| {
+                if std::path::Path::new(chemin).exists() {
+                    eprintln!("Impossible de charger les scores '{chemin}' : {erreur}");
+                }
+                Self::default()
+            })
+    }
+
+    /// Enregistre le tableau dans un fichier.
+    /// # Arguments
+    /// - `chemin` : Chemin du fichier des scores.
+    pub fn sauvegarder(&self, chemin: &str) -> std::io::Result<()> {
+        std::fs::write(chemin, self.en_texte())
+    }
+
+    /// Lit un tableau au format du fichier des scores.
+    /// # Retourne une erreur décrivant la première ligne invalide.
+    pub fn analyser(texte: &str) -> Result<Self, String> {
+        let mut table = Self::default();
+        for (numero, ligne) in texte.lines().enumerate() {
+            let ligne = ligne.trim();
+            if ligne.is_empty() {
+                continue;
+            }
+            let erreur = || format!("Ligne {} invalide : '{ligne}'.", numero + 1);
+            let (nom, score) = ligne.split_once(' ').ok_or_else(erreur)?;
+            let score = score.trim().parse().map_err(|_| erreur())?;
+            table.inserer(nom, score);
+        }
+        Ok(table)
+    }
+
+    /// Retourne le tableau au format du fichier des scores.
+    pub fn en_texte(&self) -> String {
+        self.entrees
+            .iter()
+            .map(|entree| format!("{} {}\n", entree.nom, entree.score))
+            .collect()
+    }
+
+    /// Indique si un score a sa place dans le tableau (tableau pas encore plein, ou score meilleur que le dernier).
+    pub fn qualifie(&self, score: u32) -> bool {
+        score > 0
+            && (self.entrees.len() < TAILLE_TABLE
+                || self
+                    .entrees
+                    .last()
+                    .is_some_and(|dernier| score > dernier.score))
+    }
+
+    /// Ajoute un score au tableau, qui reste trié et limité à `TAILLE_TABLE` entrées.
+    /// À score égal, le score le plus ancien reste devant.
+    /// # Retourne le rang (0 = premier) du score ajouté, ou `None` s'il ne rentre pas dans le tableau.
+    pub fn inserer(&mut self, nom: &str, score: u32) -> Option<usize> {
+        let rang = self
+            .entrees
+            .iter()
+            .position(|entree| score > entree.score)
+            .unwrap_or(self.entrees.len());
+        if rang >= TAILLE_TABLE {
+            return None;
+        }
+        self.entrees.insert(
+            rang,
+            EntreeScore {
+                nom: nom.to_string(),
+                score,
+            },
+        );
+        self.entrees.truncate(TAILLE_TABLE);
+        Some(rang)
+    }
+
+    /// Retourne les scores du tableau, du meilleur au moins bon.
+    pub fn entrees(&self) -> &[EntreeScore] {
+        &self.entrees
+    }
+}
+
+/// Saisie des initiales du joueur au clavier.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SaisieInitiales {
+    lettres: String, // Lettres déjà tapées (en majuscules)
+}
+
+impl SaisieInitiales {
+    /// Ajoute une lettre tapée par le joueur. Les caractères qui ne sont pas des lettres sont ignorés,
+    /// ainsi que les lettres en trop.
+    pub fn ajouter(&mut self, caractere: char) {
+        if caractere.is_ascii_alphabetic() && !self.est_complete() {
+            self.lettres.push(caractere.to_ascii_uppercase());
+        }
+    }
+
+    /// Efface la dernière lettre tapée.
+    pub fn effacer(&mut self) {
+        self.lettres.pop();
+    }
+
+    /// Indique si les trois initiales ont été tapées.
+    pub fn est_complete(&self) -> bool {
+        self.lettres.len() == NOMBRE_INITIALES
+    }
+
+    /// Retourne les initiales tapées, complétées par des `_` pour les lettres manquantes.
+    pub fn texte(&self) -> String {
+        format!("{:_<NOMBRE_INITIALES$}", self.lettres)
+    }
+
+    /// Retourne les initiales tapées.
+    pub fn initiales(&self) -> &str {
+        &self.lettres
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insertion_triee() {
+        let mut table = TableScores::default();
+        assert_eq!(table.inserer("AAA", 300), Some(0));
+        assert_eq!(table.inserer("BBB", 500), Some(0));
+        assert_eq!(table.inserer("CCC", 400), Some(1));
+        assert_eq!(table.inserer("DDD", 400), Some(2)); // À égalité, le plus ancien reste devant.
+        let noms: Vec<&str> = table.entrees().iter().map(|e| e.nom.as_str()).collect();
+        assert_eq!(noms, ["BBB", "CCC", "DDD", "AAA"]);
+    }
+
+    /// Le tableau ne garde que les 10 meilleurs scores.
+    #[test]
+    fn test_limite_a_dix_scores() {
+        let mut table = TableScores::default();
+        for score in 1..=15 {
+            table.inserer("AAA", score * 100);
+        }
+        assert_eq!(table.entrees().len(), TAILLE_TABLE);
+        assert_eq!(table.entrees()[0].score, 1500);
+        assert_eq!(table.entrees()[TAILLE_TABLE - 1].score, 600);
+
+        // Un score trop faible ne rentre plus dans le tableau.
+        assert!(!table.qualifie(600));
+        assert_eq!(table.inserer("ZZZ", 100), None);
+        assert!(table.qualifie(650));
+    }
+
+    /// Un tableau enregistré puis rechargé est identique.
+    #[test]
+    fn test_sauvegarde_et_chargement() {
+        let mut table = TableScores::default();
+        table.inserer("ABC", 4200);
+        table.inserer("XYZ", 1300);
+        let chemin = std::env::temp_dir().join(format!("scores_test_{}.txt", std::process::id()));
+        let chemin = chemin.to_str().unwrap();
+
+        table.sauvegarder(chemin).unwrap();
+        let rechargee = TableScores::charger(chemin);
+        std::fs::remove_file(chemin).unwrap();
+        assert_eq!(rechargee, table);
+    }
+
+    #[test]
+    fn test_fichier_invalide() {
+        assert!(TableScores::analyser("ABC beaucoup").is_err());
+        assert_eq!(
+            TableScores::charger("fichier/inexistant.txt"),
+            TableScores::default()
+        );
+    }
+
+    #[test]
+    fn test_saisie_initiales() {
+        let mut saisie = SaisieInitiales::default();
+        for caractere in ['a', '1', 'b'] {
+            saisie.ajouter(caractere);
+        }
+        assert_eq!(saisie.texte(), "AB_");
+        saisie.effacer();
+        for caractere in ['x', 'y', 'z'] {
+            saisie.ajouter(caractere);
+        }
+        assert!(saisie.est_complete());
+        assert_eq!(saisie.initiales(), "AXY");
+    }
+}
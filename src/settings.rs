@@ -0,0 +1,367 @@
+//! Module regroupant les paramètres réglables du jeu.
+//! Ce module contient la structure `Settings`, créée au lancement du jeu et consultée par la boucle principale.
+//! Les valeurs par défaut correspondent au comportement d'origine du jeu, avec quelques ajouts qui ne changent
+//! pas la façon de jouer (hitstop, compte à rebours en début de vague, bonus aimant) ; les modes et options
+//! qui la changent (boutique, mode horde, trou noir...) sont désactivés.
+
+use crate::arguments::Arguments;
+use crate::asteroid::nombre_asteroides_niveau;
+use crate::bonus::ConfigBonus;
+use crate::constantes;
+use crate::interface::{CoinEcran, StyleVies};
+use crate::spaceship::Spaceship;
+
+/// Chemin de la texture des astéroïdes fournie avec le jeu.
+pub const TEXTURE_ASTEROIDS_DEFAUT: &str = "ressources/asteroids.png";
+
+/// Chemin de l'image de fond fournie avec le jeu.
+pub const TEXTURE_FOND_DEFAUT: &str = "ressources/Fond_ecran_jeu.png";
+
+/// Fichier où sont enregistrés les meilleurs scores.
+pub const FICHIER_SCORES_DEFAUT: &str = "scores.txt";
+
+/// Fichier où sont enregistrées les astuces déjà montrées aux nouveaux joueurs.
+pub const FICHIER_TUTORIEL_DEFAUT: &str = "tutoriel_vu.txt";
+
+/// Structure qui regroupe les paramètres du jeu.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    /// Nombre d'astéroïdes générés au début d'une partie.
+    pub asteroides_initiaux: usize,
+    /// Nombre d'astéroïdes qu'un missile peut toucher avant de disparaître.
+    pub durabilite_missiles: u8,
+    /// Gravité appliquée aux missiles à chaque frame (0.0 = tir en ligne droite, plus = tir en cloche façon mortier).
+    pub gravite_missiles: f32,
+    /// Demi-amplitude (en radians) du balayage de l'arme "spray" : chaque tir décale un peu l'angle (0.0 = tir droit).
+    pub balayage_tir: f32,
+    /// Nombre maximal d'images par seconde (`None` = pas de limite).
+    pub fps_max: Option<u32>,
+    /// Accélération du vaisseau à chaque frame de poussée (réactivité des commandes).
+    pub acceleration_vaisseau: f32,
+    /// Part de sa vitesse que le vaisseau conserve après une collision (de 0.0 = arrêt net à 0.9 = presque élastique).
+    pub retention_vitesse_collision: f32,
+    /// Force de l'impulsion donnée à un astéroïde percuté par le vaisseau en mouvement
+    /// (faible = simple poussée, élevée = l'astéroïde est projeté au loin).
+    pub force_impulsion_collision: f32,
+    /// Dessine les missiles comme des traits laser orientés selon leur vitesse plutôt que comme des points.
+    pub missiles_trainees: bool,
+    /// Le vaisseau vise le curseur de la souris et tire avec le clic gauche.
+    pub visee_souris: bool,
+    /// Les flèches accélèrent le vaisseau directement dans leur direction à l'écran, sans tenir compte de son orientation.
+    pub poussee_directe: bool,
+    /// Les flèches gauche/droite font accélérer la rotation au lieu de tourner d'un angle fixe :
+    /// le vaisseau continue de tourner un court instant après avoir relâché la touche.
+    pub rotation_inertielle: bool,
+    /// Inverse les flèches gauche/droite pour la rotation : gauche tourne dans le sens des aiguilles d'une montre.
+    /// Sans effet en poussée directe, où les flèches donnent une direction à l'écran.
+    pub inverser_rotation: bool,
+    /// La flèche bas freine le vaisseau jusqu'à l'arrêt au lieu de le faire accélérer en arrière (rétro-poussée).
+    /// Sans effet en poussée directe, où la flèche bas pousse vers le bas de l'écran.
+    pub freinage: bool,
+    /// Nombre de vies en début de partie.
+    pub vies_initiales: u8,
+    /// Après la perte d'une vie, la vague actuelle recommence avec de nouveaux astéroïdes.
+    pub rejouer_vague_apres_vie: bool,
+    /// Recommencer une partie rejoue exactement la même disposition (même graine), pour s'entraîner.
+    /// Sinon, chaque nouvelle partie a des astéroïdes différents.
+    pub graine_fixe: bool,
+    /// Une vie est offerte chaque fois que le niveau atteint un multiple de cette valeur.
+    pub intervalle_vie_bonus: u8,
+    /// Nombre maximal de vies.
+    pub vies_max: u8,
+    /// Intégrité de la coque du vaisseau, entamée une fois le bouclier vide (0 = pas de coque).
+    pub coque_vaisseau: u8,
+    /// Points de bouclier régénérés chaque seconde (0.0 = pas de régénération).
+    pub regeneration_bouclier: f32,
+    /// Part de la vitesse du vaisseau conservée à chaque frame (frottement, 1.0 = pas de frottement).
+    pub friction_vaisseau: f32,
+    /// Rayon autour du vaisseau dans lequel les astéroïdes sont ralentis (0.0 = assistance désactivée).
+    pub rayon_assistance: f32,
+    /// Facteur appliqué au déplacement des astéroïdes dans le rayon d'assistance (1.0 = pas de ralentissement).
+    pub facteur_assistance: f32,
+    /// Multiplicateur de vitesse appliqué à tous les déplacements et aux minuteurs de la partie (1.0 = normal, 2.0 = deux fois plus rapide,
+    /// 0.5 = ralenti). Utile pour déboguer, faire une démonstration, ou jouer plus lentement.
+    pub vitesse_simulation: f32,
+    /// Réglages du bonus de bouclier (activation, fréquence d'apparition, durée).
+    pub bonus: ConfigBonus,
+    /// Ajoute un trou noir qui attire les astéroïdes et le vaisseau, et engloutit les astéroïdes.
+    pub trou_noir: bool,
+    /// Nombre de niveaux entre deux niveaux avec une nébuleuse qui gêne les missiles (0 = jamais).
+    pub intervalle_nebuleuse: u32,
+    /// Nombre de niveaux entre deux niveaux avec un boss qui tire des gerbes de missiles (0 = jamais).
+    pub intervalle_boss: u32,
+    /// Nombre de niveaux entre deux niveaux avec un cluster d'astéroïdes protégé par des orbiteurs (0 = jamais).
+    pub intervalle_cluster: u32,
+    /// Met la partie en pause quand la fenêtre perd le focus (alt-tab, fenêtre réduite).
+    pub pause_auto: bool,
+    /// Mode facile (jeunes joueurs) : un seul impact détruit n'importe quel astéroïde, qui ne se divise pas.
+    pub mode_facile: bool,
+    /// Les grands astéroïdes détruits laissent un nuage de débris qui abîme le vaisseau s'il reste dedans.
+    pub nuages_debris: bool,
+    /// Mode horde : les astéroïdes apparaissent en continu, de plus en plus vite, au lieu d'arriver par vagues.
+    pub mode_horde: bool,
+    /// Part de la vitesse d'un astéroïde détruit transmise à ses fragments (0.0 = vitesse aléatoire, 1.0 = même vitesse).
+    pub heritage_fragments: f32,
+    /// Mode difficile : part de la direction des fragments tournée vers le vaisseau (0.0 = aucune, 1.0 = droit dessus).
+    pub fragments_vers_vaisseau: f32,
+    /// Nombre de champs de ralentissement (touche X) que le joueur peut déployer pendant une partie.
+    pub charges_ralentissement: u8,
+    /// Nombre de barrières (touche B) que le joueur peut déployer pendant une partie.
+    pub charges_barriere: u8,
+    /// Ouvre la boutique des améliorations entre deux vagues.
+    pub boutique: bool,
+    /// Contre-la-montre : points de score perdus chaque seconde passée sans détruire d'astéroïde (0.0 = désactivé).
+    pub decroissance_score: f32,
+    /// Nombre de frames pendant lesquelles le jeu se fige quand un astéroïde est détruit (0 = désactivé).
+    pub hitstop_frames: u32,
+    /// Affiche un compte à rebours "3, 2, 1, GO!" au début de chaque partie et de chaque niveau, astéroïdes immobiles.
+    pub compte_a_rebours: bool,
+    /// Délai (en secondes) après un game over pendant lequel la touche Entrée est ignorée.
+    pub delai_redemarrage: f64,
+    /// Échelle de l'interface, en plus de l'adaptation automatique à la taille de l'écran (1.0 = taille normale).
+    pub echelle_interface: f32,
+    /// Proportions (largeur / hauteur) imposées à la zone de jeu, centrée entre des bandes noires :
+    /// tous les joueurs ont alors la même surface de jeu, quelle que soit leur fenêtre (`None` = toute la fenêtre).
+    pub rapport_aspect: Option<f32>,
+    /// Coin de l'écran où sont affichées les vies.
+    pub coin_vies: CoinEcran,
+    /// Affichage des vies : un nombre, ou une icône de vaisseau par vie.
+    pub style_vies: StyleVies,
+    /// Coupe tous les sons du jeu (qui ne sont joués que si le jeu est compilé avec la fonctionnalité `audio`).
+    pub muet: bool,
+    /// Entoure l'astéroïde qui risque de percuter le vaisseau le plus tôt.
+    pub indice_menace: bool,
+    /// Entoure l'astéroïde que toucherait un missile tiré maintenant.
+    pub aide_visee: bool,
+    /// Chemin de l'image utilisée pour dessiner les astéroïdes.
+    pub texture_asteroids: String,
+    /// Chemin de l'image de fond (`None` = champ d'étoiles généré par le jeu).
+    pub texture_fond: Option<String>,
+    /// Chemin du fichier du tableau des meilleurs scores.
+    pub fichier_scores: String,
+    /// Chemin du fichier des astuces déjà montrées au joueur.
+    pub fichier_tutoriel: String,
+    /// Chemin du fichier CSV auquel ajouter les statistiques de chaque partie terminée (`None` = pas d'export).
+    pub fichier_statistiques: Option<String>,
+}
+
+impl Default for Settings {
+    /// Paramètres par défaut : le premier niveau suit la même formule que les niveaux suivants.
+    fn default() -> Self {
+        Self {
+            asteroides_initiaux: nombre_asteroides_niveau(1),
+            durabilite_missiles: 1,
+            gravite_missiles: 0.0,
+            balayage_tir: 0.0,
+            fps_max: None,
+            acceleration_vaisseau: Spaceship::ACCELERATION_DEFAUT,
+            retention_vitesse_collision: 0.5,
+            force_impulsion_collision: 1.2,
+            missiles_trainees: false,
+            visee_souris: false,
+            poussee_directe: false,
+            rotation_inertielle: false,
+            freinage: false,
+            inverser_rotation: false,
+            vies_initiales: 1,
+            rejouer_vague_apres_vie: false,
+            graine_fixe: false,
+            intervalle_vie_bonus: 5,
+            vies_max: 5,
+            coque_vaisseau: 0,
+            regeneration_bouclier: 0.0,
+            friction_vaisseau: constantes::FRICTION_VAISSEAU,
+            rayon_assistance: 0.0,
+            facteur_assistance: 0.5,
+            vitesse_simulation: 1.0,
+            bonus: ConfigBonus::default(),
+            trou_noir: false,
+            intervalle_nebuleuse: 0,
+            intervalle_boss: 0,
+            intervalle_cluster: 0,
+            pause_auto: true,
+            mode_facile: false,
+            mode_horde: false,
+            nuages_debris: false,
+            heritage_fragments: 0.5,
+            fragments_vers_vaisseau: 0.0,
+            charges_ralentissement: 3,
+            charges_barriere: 2,
+            boutique: false,
+            decroissance_score: 0.0,
+            hitstop_frames: 3,
+            compte_a_rebours: true,
+            delai_redemarrage: 1.0,
+            echelle_interface: 1.0,
+            rapport_aspect: None,
+            coin_vies: CoinEcran::HautGauche,
+            style_vies: StyleVies::Nombre,
+            muet: false,
+            indice_menace: false,
+            aide_visee: false,
+            texture_asteroids: TEXTURE_ASTEROIDS_DEFAUT.to_string(),
+            texture_fond: Some(TEXTURE_FOND_DEFAUT.to_string()),
+            fichier_scores: FICHIER_SCORES_DEFAUT.to_string(),
+            fichier_tutoriel: FICHIER_TUTORIEL_DEFAUT.to_string(),
+            fichier_statistiques: None,
+        }
+    }
+}
+
+impl Settings {
+    /// Crée les paramètres par défaut, modifiés par les options de la ligne de commande.
+    pub fn depuis_arguments(arguments: &Arguments) -> Self {
+        let mut settings = Self::default();
+        if let Some(chemin) = &arguments.texture_asteroids {
+            settings.texture_asteroids = chemin.clone();
+        }
+        if arguments.champ_etoiles {
+            settings.texture_fond = None;
+        }
+        if arguments.sans_pause_auto {
+            settings.pause_auto = false;
+        }
+        if arguments.boutique {
+            settings.boutique = true;
+        }
+        if arguments.rapport_aspect.is_some() {
+            settings.rapport_aspect = arguments.rapport_aspect;
+        }
+        if arguments.statistiques.is_some() {
+            settings.fichier_statistiques = arguments.statistiques.clone();
+        }
+        if let Some(intervalle) = arguments.intervalle_boss {
+            settings.intervalle_boss = intervalle;
+        }
+        if let Some(intervalle) = arguments.intervalle_cluster {
+            settings.intervalle_cluster = intervalle;
+        }
+        if let Some(coin) = arguments.coin_vies {
+            settings.coin_vies = coin;
+        }
+        if let Some(style) = arguments.style_vies {
+            settings.style_vies = style;
+        }
+        if arguments.mode_facile {
+            settings.mode_facile = true;
+        }
+        if arguments.mode_horde {
+            settings.mode_horde = true;
+        }
+        if arguments.inverser_rotation {
+            settings.inverser_rotation = true;
+        }
+        if arguments.poussee_directe {
+            settings.poussee_directe = true;
+        }
+        if arguments.graine_fixe {
+            settings.graine_fixe = true;
+        }
+        if arguments.trou_noir {
+            settings.trou_noir = true;
+        }
+        if let Some(nombre) = arguments.max_bonus_simultanes {
+            settings.bonus.max_simultanes = nombre;
+        }
+        if arguments.nuages_debris {
+            settings.nuages_debris = true;
+        }
+        if let Some(points) = arguments.decroissance_score {
+            settings.decroissance_score = points;
+        }
+        if arguments.rotation_inertielle {
+            settings.rotation_inertielle = true;
+        }
+        if let Some(part) = arguments.fragments_vers_vaisseau {
+            settings.fragments_vers_vaisseau = part;
+        }
+        if arguments.freinage {
+            settings.freinage = true;
+        }
+        settings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sans option, la texture fournie avec le jeu est utilisée ; sinon, celle donnée sur la ligne de commande.
+    #[test]
+    fn test_texture_asteroids() {
+        let args = |ligne: &str| {
+            ligne
+                .split_whitespace()
+                .map(String::from)
+                .collect::<Vec<_>>()
+        };
+
+        let defaut = Settings::depuis_arguments(&Arguments::analyser(args("")).unwrap());
+        assert_eq!(defaut.texture_asteroids, TEXTURE_ASTEROIDS_DEFAUT);
+
+        let arguments = Arguments::analyser(args("--asteroid-texture mods/rochers.png")).unwrap();
+        let perso = Settings::depuis_arguments(&arguments);
+        assert_eq!(perso.texture_asteroids, "mods/rochers.png");
+    }
+
+    /// Chaque option sans valeur de la ligne de commande active le réglage correspondant, et seulement celui-là.
+    #[test]
+    fn test_options_booleennes() {
+        type Reglage = (&'static str, fn(&Settings) -> bool);
+        let reglages: [Reglage; 10] = [
+            ("--shop", |s| s.boutique),
+            ("--easy", |s| s.mode_facile),
+            ("--horde", |s| s.mode_horde),
+            ("--invert-rotation", |s| s.inverser_rotation),
+            ("--direct-thrust", |s| s.poussee_directe),
+            ("--fixed-seed", |s| s.graine_fixe),
+            ("--black-hole", |s| s.trou_noir),
+            ("--debris-clouds", |s| s.nuages_debris),
+            ("--inertial-rotation", |s| s.rotation_inertielle),
+            ("--brake", |s| s.freinage),
+        ];
+        let defaut = Settings::default();
+        for (option, reglage) in reglages {
+            assert!(!reglage(&defaut), "{option}");
+            let arguments = Arguments::analyser([option.to_string()]).unwrap();
+            let settings = Settings::depuis_arguments(&arguments);
+            for (autre, reglage_autre) in reglages {
+                assert_eq!(
+                    reglage_autre(&settings),
+                    autre == option,
+                    "{option} / {autre}"
+                );
+            }
+        }
+    }
+
+    /// Les options avec une valeur la reportent dans le réglage correspondant.
+    #[test]
+    fn test_options_avec_valeur() {
+        let arguments = Arguments::analyser(
+            "--max-bonuses 3 --score-decay 2.5 --aimed-fragments 0.5 --boss-interval 4"
+                .split_whitespace()
+                .map(String::from),
+        )
+        .unwrap();
+        let settings = Settings::depuis_arguments(&arguments);
+        assert_eq!(settings.bonus.max_simultanes, 3);
+        assert_eq!(settings.decroissance_score, 2.5);
+        assert_eq!(settings.fragments_vers_vaisseau, 0.5);
+        assert_eq!(settings.intervalle_boss, 4);
+    }
+
+    /// L'option `--starfield` remplace l'image de fond par le champ d'étoiles.
+    #[test]
+    fn test_texture_fond() {
+        assert_eq!(
+            Settings::default().texture_fond.as_deref(),
+            Some(TEXTURE_FOND_DEFAUT)
+        );
+        let arguments = Arguments {
+            champ_etoiles: true,
+            ..Default::default()
+        };
+        assert_eq!(Settings::depuis_arguments(&arguments).texture_fond, None);
+    }
+}
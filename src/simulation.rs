@@ -0,0 +1,529 @@
+//! Module pour la simulation des astéroïdes et des missiles.
+//! Ce module contient les fonctions appelées par `World::update` à chaque frame : le déplacement des astéroïdes
+//! (assistance, champs de ralentissement, barrières, trou noir, aimant) et les collisions entre les missiles
+//! et les astéroïdes. Rien n'y est dessiné.
+
+use crate::asteroid::{ajouter_asteroides, positions_asteroides_apres_collision, Asteroid};
+use crate::barriere::Barriere;
+use crate::bonus::{repulsion_aimant, GestionnaireBonus, VITESSE_ATTRACTION_AIMANT};
+use crate::missile::Missile;
+use crate::settings::Settings;
+use crate::spaceship::Spaceship;
+use crate::trou_noir::TrouNoir;
+use crate::zone::{facteur_ralentissement, ZoneRalentissement};
+use macroquad::prelude::*;
+
+/// Outils déployés par le joueur qui agissent sur le déplacement des astéroïdes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Deploiements<'a> {
+    pub zones_ralentissement: &'a [ZoneRalentissement], // Champs de ralentissement, qui freinent les astéroïdes
+    pub barrieres: &'a [Barriere], // Barrières, sur lesquelles les astéroïdes rebondissent
+}
+
+/// Met à jour le modèle des astéroïdes en déplaçant chaque astéroïde.
+/// Si l'assistance est activée, les astéroïdes proches du vaisseau avancent moins vite pendant cette frame.
+/// S'il y a un trou noir, il attire les astéroïdes proches et engloutit ceux qui touchent son horizon.
+/// Pendant l'effet d'un bonus aimant, les astéroïdes proches du vaisseau sont repoussés et tous les bonus attirés.
+/// # Arguments
+/// -`asteroids` - Référence mutable à un vecteur d'astéroïdes à mettre à jour.
+/// -`position_vaisseau` - Position actuelle du vaisseau.
+/// -`settings` - Paramètres du jeu (rayon et facteur d'assistance, vitesse de la simulation).
+/// -`trou_noir` - Trou noir présent à l'écran, s'il y en a un.
+/// -`deploiements` - Champs de ralentissement et barrières déployés par le joueur.
+/// -`aimant` - Bonus à attirer vers le vaisseau si l'aimant est actif (`None` = pas d'aimant).
+/// -`ecran` - Dimensions de l'écran (largeur, hauteur).
+pub fn update_model(
+    asteroids: &mut Vec<Asteroid>,
+    position_vaisseau: Vec2,
+    settings: &Settings,
+    trou_noir: Option<&TrouNoir>,
+    deploiements: Deploiements,
+    aimant: Option<&mut GestionnaireBonus>,
+    ecran: Vec2,
+) {
+    if let Some(bonus) = aimant {
+        bonus.attirer(
+            position_vaisseau,
+            VITESSE_ATTRACTION_AIMANT * settings.vitesse_simulation,
+        );
+        for asteroid in asteroids.iter_mut() {
+            let repulsion = repulsion_aimant(position_vaisseau, asteroid.get_position());
+            asteroid
+                .nouvelle_vitesse(asteroid.get_vitesse() + repulsion * settings.vitesse_simulation);
+        }
+    }
+
+    if let Some(trou_noir) = trou_noir {
+        for asteroid in asteroids.iter_mut() {
+            trou_noir.attirer(asteroid);
+        }
+        asteroids.retain(|asteroid| {
+            !trou_noir.touche_horizon(asteroid.get_position(), asteroid.rayon_asteroid())
+        });
+    }
+
+    // Les astéroïdes qui touchent une barrière rebondissent dessus.
+    for asteroid in asteroids.iter_mut() {
+        for barriere in deploiements.barrieres {
+            if let Some(vitesse) = barriere.reflechir(
+                asteroid.get_position(),
+                asteroid.rayon_asteroid(),
+                asteroid.get_vitesse(),
+            ) {
+                asteroid.nouvelle_vitesse(vitesse);
+            }
+        }
+    }
+
+    // Pour chaque astéroïde à l'écran, on utilise la fonction move_object() du module asteroid pour changer sa position en fonction de la vitesse.
+    for asteroid in asteroids {
+        let facteur =
+            if asteroid.get_position().distance(position_vaisseau) < settings.rayon_assistance {
+                settings.facteur_assistance
+            } else {
+                1.0
+            };
+        // Les champs de ralentissement déployés par le joueur freinent aussi les astéroïdes qui les traversent.
+        let facteur = facteur
+            * facteur_ralentissement(deploiements.zones_ralentissement, asteroid.get_position());
+        asteroid.move_object(facteur * settings.vitesse_simulation, ecran);
+    }
+}
+
+/// Distance maximale à laquelle l'explosion d'un astéroïde pousse le vaisseau.
+const RAYON_ONDE_CHOC: f32 = 200.0;
+
+/// Force de l'onde de choc pour un astéroïde de taille 1 situé à une distance de 1.
+const FORCE_ONDE_CHOC: f32 = 30.0;
+
+/// Calcule l'impulsion donnée au vaisseau par l'explosion d'un astéroïde.
+/// Le vaisseau est repoussé à l'opposé de l'explosion, d'autant plus fort que l'astéroïde est gros et proche.
+/// # Arguments
+/// - `position_vaisseau` - Position actuelle du vaisseau.
+/// - `position_explosion` - Position de l'astéroïde détruit.
+/// - `taille` - Taille de l'astéroïde détruit (1, 2 ou 3).
+fn impulsion_explosion(position_vaisseau: Vec2, position_explosion: Vec2, taille: u8) -> Vec2 {
+    let direction = position_vaisseau - position_explosion;
+    let distance = direction.length();
+    if distance >= RAYON_ONDE_CHOC {
+        return Vec2::ZERO; // Trop loin, l'onde de choc n'atteint pas le vaisseau.
+    }
+    // On évite une impulsion infinie si l'explosion a lieu sur le vaisseau.
+    direction.normalize_or_zero() * FORCE_ONDE_CHOC * taille as f32 / distance.max(1.0)
+}
+
+/// Gère les collisions entre les missiles et les astéroïdes.
+/// Un astéroïde touché perd un point de résistance. Lorsqu'il est détruit, il se divise en deux astéroïdes plus petits (sauf les petits)
+/// et son explosion repousse le vaisseau s'il est proche.
+/// Un missile perd un point de durabilité à chaque astéroïde touché et disparaît quand il n'en a plus.
+/// Un missile qui touche un astéroïde blindé ailleurs que sur son point faible ricoche sans faire de dégâts.
+/// # Arguments
+/// - `missiles` - Référence mutable au vecteur des missiles présents à l'écran.
+/// - `asteroids` - Référence mutable au vecteur des astéroïdes présents à l'écran.
+/// - `vaisseau` - Vaisseau du joueur, poussé par l'onde de choc des explosions.
+/// - `settings` - Paramètres du jeu (vitesse héritée par les fragments).
+/// - `rng` - Générateur aléatoire utilisé pour la vitesse des fragments.
+/// # Retourne la position et la taille de chaque astéroïde détruit pendant cette frame.
+pub fn gerer_collisions_missiles(
+    missiles: &mut Vec<Missile>,
+    asteroids: &mut Vec<Asteroid>,
+    vaisseau: &mut Spaceship,
+    settings: &Settings,
+    rng: &mut impl ::rand::Rng,
+) -> Vec<(Vec2, u8)> {
+    let mut asteroids_to_remove = Vec::new(); // Pour stocker les astéroïdes qui vont être enlevés.
+    let mut missiles_to_remove = Vec::new(); // Pour stocker les missiles qui vont devoir être enlevés.
+    let mut new_asteroids = Vec::new(); // Pour stocker les astéroïdes créés lors de la fragmentation
+    let mut destructions = Vec::new(); // Position et taille des astéroïdes détruits, pour leurs explosions
+
+    for (missile_index, missile) in missiles.iter_mut().enumerate() {
+        let mut en_contact = false; // Le missile se trouve-t-il dans un astéroïde à cette frame ?
+        for (asteroid_index, asteroid) in asteroids.iter_mut().enumerate() {
+            let distance = missile.get_position().distance(asteroid.get_position()); // Calcul de la distance entre le missile et le centre de l'astéroïde
+            let collision_distance = Missile::distance_collision(asteroid); // Calcul de la distance entre le centre de l'astéroïde et le rebord
+                                                                            // Si le missile se trouve entre le centre de l'astéroïde et le rebord = Collision
+            if distance < collision_distance {
+                en_contact = true;
+                // Si le missile était déjà dans cet astéroïde à la frame précédente, l'impact a déjà été compté.
+                if missile.est_en_contact() {
+                    break;
+                }
+                // Un astéroïde blindé renvoie le missile sans dégâts, sauf si celui-ci touche son point faible.
+                if asteroid.est_blinde() && !asteroid.touche_point_faible(missile.get_position()) {
+                    missile.reflechir(missile.get_position() - asteroid.get_position());
+                    break;
+                }
+                // Collision détectée entre un missile et un astéroïde
+                if settings.mode_facile {
+                    asteroid.detruire(); // En mode facile, un seul impact suffit.
+                } else {
+                    asteroid.diminuer_résistance(); // Donc on enlève un point de résistance
+                }
+                // Si l'astéroïde n'a plus de résistance, il est alors détruit.
+                asteroid.get_resistance();
+                if asteroid.est_détruit() {
+                    println!("Astéroïde détruit !");
+                    // L'explosion repousse le vaisseau.
+                    vaisseau.appliquer_impulsion(impulsion_explosion(
+                        vaisseau.get_position(),
+                        asteroid.get_position(),
+                        asteroid.get_taille(),
+                    ));
+                    // S'il peut se diviser, on va créer deux nouveaux astéroïdes de la taille inférieure.
+                    // En mode facile, les astéroïdes ne se divisent jamais.
+                    if asteroid.peut_se_diviser() && !settings.mode_facile {
+                        let taille_fragments = asteroid.get_taille() - 1;
+                        let (position1, position2) = positions_asteroides_apres_collision(
+                            missile.get_position(),
+                            asteroid.get_position(),
+                        );
+                        new_asteroids.push(Asteroid::fragment(
+                            rng,
+                            taille_fragments,
+                            position1,
+                            asteroid,
+                            settings.heritage_fragments,
+                            vaisseau.get_position(),
+                            settings.fragments_vers_vaisseau,
+                        ));
+                        new_asteroids.push(Asteroid::fragment(
+                            rng,
+                            taille_fragments,
+                            position2,
+                            asteroid,
+                            settings.heritage_fragments,
+                            vaisseau.get_position(),
+                            settings.fragments_vers_vaisseau,
+                        ));
+                    }
+                    // Et on va rajouter les anciens astéroïdes à la liste des astéroïdes qu'on doit enlever.
+                    asteroids_to_remove.push(asteroid_index);
+                    destructions.push((asteroid.get_position(), asteroid.get_taille()));
+                }
+                // Le missile perd un point de durabilité, on l'enlève s'il n'en a plus.
+                missile.encaisser_impact();
+                if missile.est_detruit() {
+                    missiles_to_remove.push(missile_index);
+                }
+                break; // Le missile ne peut toucher qu'un astéroïde
+            }
+        }
+        missile.set_en_contact(en_contact);
+    }
+
+    // Supprimer les astéroïdes détruits
+    asteroids_to_remove.sort_unstable();
+    for index in asteroids_to_remove.iter().rev() {
+        if *index < asteroids.len() {
+            asteroids.remove(*index);
+        }
+    }
+
+    // Ajouter les nouveaux astéroïdes créés lors de la fragmentation (dans la limite autorisée)
+    ajouter_asteroides(asteroids, new_asteroids);
+
+    // Supprimer les missiles qui ont touché un astéroïde
+    missiles_to_remove.sort_unstable();
+    for index in missiles_to_remove.iter().rev() {
+        if *index < missiles.len() {
+            missiles.remove(*index);
+        }
+    }
+
+    destructions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stellarobject::StellarObject;
+    use ::rand::thread_rng;
+
+    /// Vaisseau placé loin des astéroïdes des tests, hors de portée des ondes de choc.
+    fn vaisseau_loin() -> Spaceship {
+        Spaceship::avec_position(vec2(5000.0, 5000.0))
+    }
+
+    /// Un missile de durabilité 2 survit au premier astéroïde touché et disparaît au second.
+    #[test]
+    fn test_missile_durable() {
+        let mut missile = Missile::nouveau_missile(vec2(0.0, 0.0), 0.0);
+        missile.set_durabilite(2);
+        let mut missiles = vec![missile];
+        let mut asteroids = vec![
+            Asteroid::nouvel_asteroid(&mut thread_rng(), 3, vec2(0.0, 0.0)),
+            Asteroid::nouvel_asteroid(&mut thread_rng(), 3, vec2(300.0, 0.0)),
+        ];
+
+        // Premier impact : le missile continue sa route.
+        gerer_collisions_missiles(
+            &mut missiles,
+            &mut asteroids,
+            &mut vaisseau_loin(),
+            &Settings::default(),
+            &mut thread_rng(),
+        );
+        assert_eq!(missiles.len(), 1);
+        assert_eq!(asteroids[0].get_resistance(), 4);
+
+        // Le missile traverse le premier astéroïde (sans nouvel impact) jusqu'au second.
+        while !missiles.is_empty() && missiles[0].get_position().x < 300.0 {
+            missiles[0].maj_pos_missile(1.0);
+            gerer_collisions_missiles(
+                &mut missiles,
+                &mut asteroids,
+                &mut vaisseau_loin(),
+                &Settings::default(),
+                &mut thread_rng(),
+            );
+        }
+        assert!(missiles.is_empty());
+        assert_eq!(asteroids[0].get_resistance(), 4);
+        assert_eq!(asteroids[1].get_resistance(), 4);
+    }
+
+    /// Un missile classique disparaît dès le premier impact.
+    #[test]
+    fn test_missile_simple_detruit_au_premier_impact() {
+        let mut missiles = vec![Missile::nouveau_missile(vec2(0.0, 0.0), 0.0)];
+        let mut asteroids = vec![Asteroid::nouvel_asteroid(
+            &mut thread_rng(),
+            3,
+            vec2(0.0, 0.0),
+        )];
+        gerer_collisions_missiles(
+            &mut missiles,
+            &mut asteroids,
+            &mut vaisseau_loin(),
+            &Settings::default(),
+            &mut thread_rng(),
+        );
+        assert!(missiles.is_empty());
+    }
+
+    /// Avec l'assistance, un astéroïde proche du vaisseau avance moins qu'un astéroïde éloigné.
+    #[test]
+    fn test_assistance_ralentissement() {
+        let ecran = vec2(800.0, 600.0);
+        let settings = Settings {
+            rayon_assistance: 150.0,
+            facteur_assistance: 0.5,
+            ..Default::default()
+        };
+        let vitesse = vec2(2.0, 1.0);
+        let mut proche = Asteroid::nouvel_asteroid(&mut thread_rng(), 3, vec2(450.0, 300.0));
+        let mut loin = Asteroid::nouvel_asteroid(&mut thread_rng(), 3, vec2(100.0, 100.0));
+        proche.nouvelle_vitesse(vitesse);
+        loin.nouvelle_vitesse(vitesse);
+        let mut asteroids = vec![proche, loin];
+
+        update_model(
+            &mut asteroids,
+            vec2(400.0, 300.0),
+            &settings,
+            None,
+            Deploiements::default(),
+            None,
+            ecran,
+        );
+
+        let deplacement_proche = asteroids[0].get_position().distance(vec2(450.0, 300.0));
+        let deplacement_loin = asteroids[1].get_position().distance(vec2(100.0, 100.0));
+        assert!(deplacement_proche < deplacement_loin);
+        // La vitesse stockée n'est pas modifiée
+        assert_eq!(asteroids[0].get_vitesse(), vitesse);
+    }
+
+    /// Doubler la vitesse de la simulation double le déplacement d'un astéroïde à chaque frame.
+    #[test]
+    fn test_vitesse_simulation() {
+        let ecran = vec2(800.0, 600.0);
+        let deplacement = |vitesse_simulation: f32| {
+            let settings = Settings {
+                vitesse_simulation,
+                ..Default::default()
+            };
+            let depart = vec2(100.0, 100.0);
+            let mut asteroids = vec![Asteroid::scripté(2, depart, vec2(2.0, 1.0))];
+            update_model(
+                &mut asteroids,
+                vec2(5000.0, 5000.0),
+                &settings,
+                None,
+                Deploiements::default(),
+                None,
+                ecran,
+            );
+            asteroids[0].get_position() - depart
+        };
+        assert_eq!(deplacement(2.0), deplacement(1.0) * 2.0);
+    }
+
+    /// Un astéroïde dans un champ de ralentissement avance deux fois moins vite, sans que sa vitesse change.
+    #[test]
+    fn test_champ_ralentissement() {
+        let ecran = vec2(800.0, 600.0);
+        let settings = Settings::default();
+        let vitesse = vec2(2.0, 1.0);
+        let mut asteroids = vec![
+            Asteroid::scripté(2, vec2(400.0, 300.0), vitesse),
+            Asteroid::scripté(2, vec2(100.0, 100.0), vitesse),
+        ];
+        let champs = [ZoneRalentissement::deployer(vec2(420.0, 300.0))];
+        update_model(
+            &mut asteroids,
+            vec2(5000.0, 5000.0),
+            &settings,
+            None,
+            Deploiements {
+                zones_ralentissement: &champs,
+                ..Default::default()
+            },
+            None,
+            ecran,
+        );
+        assert_eq!(
+            asteroids[0].get_position() - vec2(400.0, 300.0),
+            vitesse * 0.5
+        );
+        assert_eq!(asteroids[1].get_position() - vec2(100.0, 100.0), vitesse);
+        assert_eq!(asteroids[0].get_vitesse(), vitesse);
+    }
+
+    /// Pendant l'effet de l'aimant, les astéroïdes proches du vaisseau s'en éloignent ; sans aimant, ils ne dévient pas.
+    #[test]
+    fn test_aimant_repousse_asteroides() {
+        let ecran = vec2(800.0, 600.0);
+        let vaisseau = vec2(400.0, 300.0);
+        let settings = Settings::default();
+        let mut bonus = GestionnaireBonus::new(&mut thread_rng(), ecran, vaisseau);
+        let depart = [vec2(450.0, 300.0), vec2(400.0, 200.0)];
+        let mut asteroids: Vec<Asteroid> = depart
+            .iter()
+            .map(|position| Asteroid::scripté(2, *position, Vec2::ZERO))
+            .collect();
+        let mut temoins = asteroids.clone();
+        for _ in 0..10 {
+            update_model(
+                &mut asteroids,
+                vaisseau,
+                &settings,
+                None,
+                Deploiements::default(),
+                Some(&mut bonus),
+                ecran,
+            );
+            update_model(
+                &mut temoins,
+                vaisseau,
+                &settings,
+                None,
+                Deploiements::default(),
+                None,
+                ecran,
+            );
+        }
+        for (asteroid, depart) in asteroids.iter().zip(depart) {
+            assert!(asteroid.get_position().distance(vaisseau) > depart.distance(vaisseau));
+        }
+        for (temoin, depart) in temoins.iter().zip(depart) {
+            assert_eq!(temoin.get_position(), depart);
+        }
+    }
+
+    /// En mode facile, un seul missile détruit un grand astéroïde, sans créer de fragments.
+    #[test]
+    fn test_mode_facile() {
+        let settings = Settings {
+            mode_facile: true,
+            ..Default::default()
+        };
+        let mut asteroids = vec![Asteroid::nouvel_asteroid(
+            &mut thread_rng(),
+            3,
+            vec2(300.0, 300.0),
+        )];
+        let mut missiles = vec![Missile::nouveau_missile(vec2(300.0, 300.0), 0.0)];
+
+        let detruits = gerer_collisions_missiles(
+            &mut missiles,
+            &mut asteroids,
+            &mut vaisseau_loin(),
+            &settings,
+            &mut thread_rng(),
+        );
+
+        assert_eq!(detruits, vec![(vec2(300.0, 300.0), 3)]);
+        assert!(asteroids.is_empty());
+        assert!(missiles.is_empty());
+    }
+
+    /// Un missile qui touche l'avant d'un astéroïde blindé ricoche et ne l'abîme pas.
+    #[test]
+    fn test_ricochet_asteroid_blinde() {
+        let mut asteroid = Asteroid::nouvel_asteroid(&mut thread_rng(), 3, vec2(300.0, 300.0));
+        asteroid.nouvelle_vitesse(vec2(1.0, 0.0)); // Il avance vers la droite, son point faible est à gauche.
+        asteroid.set_blinde(true);
+        let resistance = asteroid.get_resistance();
+        let bord_avant = 300.0 + asteroid.rayon_asteroid() - 5.0;
+        let mut asteroids = vec![asteroid];
+        let mut missiles = vec![Missile::nouveau_missile(
+            vec2(bord_avant, 300.0),
+            std::f32::consts::PI,
+        )]; // Il vient de la droite et part vers la gauche.
+
+        gerer_collisions_missiles(
+            &mut missiles,
+            &mut asteroids,
+            &mut vaisseau_loin(),
+            &Settings::default(),
+            &mut thread_rng(),
+        );
+
+        assert_eq!(missiles.len(), 1); // Le missile n'est pas détruit.
+        assert!((missiles[0].get_vitesse() - vec2(Missile::VITESSE_DEFAUT, 0.0)).length() < 1e-4); // Il repart vers la droite.
+        assert_eq!(asteroids[0].get_resistance(), resistance);
+    }
+
+    /// Un missile qui touche le point faible d'un astéroïde blindé l'abîme normalement.
+    #[test]
+    fn test_point_faible_asteroid_blinde() {
+        let mut asteroid = Asteroid::nouvel_asteroid(&mut thread_rng(), 3, vec2(300.0, 300.0));
+        asteroid.nouvelle_vitesse(vec2(1.0, 0.0));
+        asteroid.set_blinde(true);
+        let resistance = asteroid.get_resistance();
+        let bord_arriere = 300.0 - asteroid.rayon_asteroid() + 5.0;
+        let mut asteroids = vec![asteroid];
+        let mut missiles = vec![Missile::nouveau_missile(vec2(bord_arriere, 300.0), 0.0)];
+
+        gerer_collisions_missiles(
+            &mut missiles,
+            &mut asteroids,
+            &mut vaisseau_loin(),
+            &Settings::default(),
+            &mut thread_rng(),
+        );
+
+        assert!(missiles.is_empty());
+        assert_eq!(asteroids[0].get_resistance(), resistance - 1);
+    }
+
+    /// L'onde de choc repousse le vaisseau à l'opposé de l'explosion, plus fort pour un gros astéroïde proche.
+    #[test]
+    fn test_impulsion_explosion() {
+        let vaisseau = vec2(400.0, 300.0);
+        let impulsion = impulsion_explosion(vaisseau, vec2(350.0, 300.0), 3);
+        assert!(impulsion.x > 0.0 && impulsion.y.abs() < 1e-6); // Poussé vers la droite, loin de l'explosion.
+
+        let plus_loin = impulsion_explosion(vaisseau, vec2(250.0, 300.0), 3);
+        let plus_petit = impulsion_explosion(vaisseau, vec2(350.0, 300.0), 1);
+        assert!(plus_loin.length() < impulsion.length());
+        assert!(plus_petit.length() < impulsion.length());
+
+        // Hors de portée, aucune impulsion.
+        assert_eq!(
+            impulsion_explosion(vaisseau, vec2(400.0 - RAYON_ONDE_CHOC, 300.0), 3),
+            Vec2::ZERO
+        );
+    }
+}
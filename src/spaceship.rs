@@ -5,33 +5,104 @@
 //! Il possède également un bouclier qui peut être restauré avec des bonus.
 
 use crate::asteroid::Asteroid;
+use crate::constantes;
+use crate::input::InputState;
+use crate::interface::taille_relative;
+use crate::settings::Settings;
+use crate::stellarobject::StellarObject;
+use crate::transform::{direction_ou_defaut, Transform};
 use macroquad::prelude::*;
 
 /// Structure qui représente le vaisseau spatial.
 /// Un vaisseau a une position (x,y) une vitesse de déplacement, l'angle dans lequel il est orienté (vers où il se dirige) et il a un bouclier.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Spaceship {
-    position: Vec2, // Vecteur 2 dimensions qui représente la position du vaisseau: (x,y)
-    vitesse: Vec2, // Vecteur 2 dimensions qui représente sa vitesse: (1.0, 0.0) = il va vers la doite par ex.
-    rotation: f32, // Angle de rotation
-    bouclier: u8,  // Pourcentage bouclier
-    cooldown: f64, // Cooldown pour empêcher les collisions multiples
+    transform: Transform, // Position (x,y) et vitesse du vaisseau: (1.0, 0.0) = il va vers la doite par ex.
+    rotation: f32,        // Angle de rotation
+    vitesse_rotation: f32, // Vitesse de rotation (en radians par frame), avec la rotation inertielle
+    bouclier: u8,          // Pourcentage bouclier
+    bouclier_max: u8, // Bouclier vers lequel remontent la régénération et les bonus (100%, plus avec la boutique)
+    coque: u8,        // Intégrité de la coque, entamée seulement quand le bouclier est vide
+    coque_max: u8,    // Intégrité de la coque quand elle est intacte (0 = pas de coque)
+    regeneration_bouclier: f32, // Points de régénération accumulés mais pas encore ajoutés au bouclier
+    cooldown: f64,              // Cooldown pour empêcher les collisions multiples
+    decroissance_surcharge: f32, // Points de surcharge perdus mais pas encore retirés du bouclier
+    bouclier_brise: bool, // Vrai si le bouclier vient de tomber à 0 et que l'effet n'a pas encore été joué
+    phase_tir: f32,       // Phase du balayage de l'arme "spray", avancée à chaque tir
 }
 
 impl Spaceship {
-    /// Crée un nouveau vaisseau immobile initialisé au centre de l'écran, orienté vers le haut et avec son bouclier à 100%.
-    pub fn new() -> Self {
+    /// Au-dessus de ce pourcentage, le bouclier renvoie les petits astéroïdes sans subir de dégâts.
+    pub const SEUIL_BOUCLIER_REFLECHISSANT: u8 = 50;
+
+    /// Vitesse maximale que le vaisseau peut atteindre sous l'effet d'une impulsion extérieure.
+    /// Avec la friction, la poussée par défaut plafonne déjà vers 6.5.
+    pub const VITESSE_MAX: f32 = 8.0;
+
+    /// Accélération par défaut de la poussée (en pixels par frame, à chaque frame).
+    pub const ACCELERATION_DEFAUT: f32 = constantes::ACCELERATION_VAISSEAU;
+
+    /// Valeur maximale du bouclier (100%).
+    pub const BOUCLIER_MAX: u8 = 100;
+
+    /// Valeur du bouclier après un bonus de surcharge (150%), qui redescend ensuite vers 100%.
+    pub const BOUCLIER_SURCHARGE: u8 = 150;
+
+    /// Vitesse (en points par seconde) à laquelle la surcharge du bouclier redescend vers 100%.
+    pub const DECROISSANCE_SURCHARGE: f32 = 10.0;
+
+    /// Avancée de la phase du balayage à chaque tir (en radians) : un aller-retour complet toutes les 8 salves.
+    pub const PAS_PHASE_TIR: f32 = std::f32::consts::TAU / 8.0;
+
+    /// Angle (en radians) dont le vaisseau tourne à chaque frame quand une flèche est enfoncée.
+    pub const VITESSE_ROTATION: f32 = 0.05;
+
+    /// Avec la rotation inertielle, accélération de la rotation (en radians par frame, à chaque frame).
+    pub const ACCELERATION_ROTATION: f32 = 0.01;
+
+    /// Avec la rotation inertielle, part de la vitesse de rotation conservée à chaque frame (frottement).
+    /// Touche enfoncée, la rotation plafonne vers la même vitesse que sans inertie.
+    pub const FRICTION_ROTATION: f32 = 0.85;
+
+    /// Avec le freinage, vitesse retirée à chaque frame quand la flèche bas est enfoncée,
+    /// en multiple de l'accélération : le vaisseau s'arrête plus vite qu'il n'accélère.
+    pub const FACTEUR_FREINAGE: f32 = 2.0;
+
+    /// En dessous de cette vitesse de rotation, le vaisseau s'arrête de tourner.
+    pub const VITESSE_ROTATION_MIN: f32 = 1e-4;
+
+    /// Temps minimal (en secondes) entre deux impacts qui abîment le vaisseau.
+    pub const DELAI_IMPACTS: f64 = 0.5;
+
+    /// Rayon du vaisseau (pour les collisions, il est considéré comme un cercle).
+    pub const RAYON: f32 = constantes::RAYON_VAISSEAU;
+
+    /// Crée un nouveau vaisseau immobile à la position donnée (en général le centre de l'écran), orienté vers le haut et avec son bouclier à 100%.
+    pub fn avec_position(position: Vec2) -> Self {
         Self {
-            position: vec2(screen_width() / 2.0, screen_height() / 2.0), // Au départ il est centré sur l'écran
-            rotation: 0.0,           // Au départ il est orienté vers le haut
-            vitesse: vec2(0.0, 0.0), // Au départ le vaisseau est immobile
-            bouclier: 100,           // Bouclier au maximum (100%)
+            transform: Transform::new(position, vec2(0.0, 0.0)), // Au départ le vaisseau est immobile
+            rotation: 0.0, // Au départ il est orienté vers le haut
+            vitesse_rotation: 0.0,
+            bouclier: Self::BOUCLIER_MAX, // Bouclier au maximum (100%)
+            bouclier_max: Self::BOUCLIER_MAX,
+            coque: 0, // Pas de coque : le bouclier est la seule protection
+            coque_max: 0,
+            regeneration_bouclier: 0.0,
             cooldown: 0.0,
+            bouclier_brise: false,
+            phase_tir: 0.0,
+            decroissance_surcharge: 0.0,
         }
     }
 
     /// Méthode pour obtenir la position actuelle du vaisseau.
     pub fn get_position(&self) -> Vec2 {
-        self.position
+        self.transform.position
+    }
+
+    /// Retourne la vitesse actuelle du vaisseau.
+    pub fn get_vitesse(&self) -> Vec2 {
+        self.transform.vitesse
     }
 
     /// Méthode pour obtenir le pourcentage de bouclier du vaisseau.
@@ -39,48 +110,135 @@ impl Spaceship {
         self.bouclier
     }
 
+    /// Donne au vaisseau une coque intacte de l'intégrité donnée, qui encaisse les dégâts une fois le bouclier vide.
+    pub fn set_coque(&mut self, coque: u8) {
+        self.coque = coque;
+        self.coque_max = coque;
+    }
+
+    /// Renforce le bouclier (boutique) : le bouclier maximal devient `BOUCLIER_MAX + bonus`.
+    /// Le bouclier actuel n'est pas rempli pour autant.
+    pub fn set_bonus_bouclier(&mut self, bonus: u8) {
+        self.bouclier_max = Self::BOUCLIER_MAX.saturating_add(bonus);
+    }
+
+    /// Remet la coque à son intégrité maximale (bonus).
+    pub fn reparer_coque(&mut self) {
+        self.coque = self.coque_max;
+    }
+
+    /// Indique si le vaisseau est détruit : son bouclier et sa coque sont tous les deux vides.
+    pub fn est_detruit(&self) -> bool {
+        self.bouclier == 0 && self.coque == 0
+    }
+
     /// Retourne l'angle de rotation actuel du vaisseau.
     pub fn get_rotation(&self) -> f32 {
         self.rotation
     }
 
-    /// Recentre le vaisseau au milieu de l'écran et réinitialise sa vitesse.
-    pub fn recentrer(&mut self) {
-        self.position = vec2(screen_width() / 2.0, screen_height() / 2.0);
-        self.vitesse = vec2(0.0, 0.0); // Réinitialise aussi la vitesse du vaisseau
+    /// Replace le vaisseau sur le point donné (le centre de l'écran) et réinitialise sa vitesse.
+    pub fn recentrer_sur(&mut self, centre: Vec2) {
+        self.transform.position = centre;
+        self.transform.vitesse = vec2(0.0, 0.0); // Réinitialise aussi la vitesse du vaisseau
+        self.vitesse_rotation = 0.0;
     }
 
-    /// Fonction pour réstaurer le bouclier à 100% (Bonus).
+    /// Fonction pour réstaurer le bouclier à son maximum (Bonus).
+    /// Un bouclier en surcharge n'est pas diminué.
     pub fn restaurer_bouclier(&mut self) {
-        self.bouclier = 100
+        self.bouclier = self.bouclier.max(self.bouclier_max)
+    }
+
+    /// Surcharge le bouclier à `BOUCLIER_SURCHARGE` (bonus rare), sans descendre sous le bouclier maximal.
+    pub fn surcharger_bouclier(&mut self) {
+        self.bouclier = Self::BOUCLIER_SURCHARGE.max(self.bouclier_max);
+        self.decroissance_surcharge = 0.0;
+    }
+
+    /// Fait redescendre un bouclier en surcharge vers son maximum, sans jamais passer en dessous.
+    /// À appeler à chaque frame.
+    /// # Arguments
+    /// - `delta_time` : Temps écoulé (en secondes) depuis la dernière frame.
+    pub fn decroitre_surcharge(&mut self, delta_time: f32) {
+        if self.bouclier <= self.bouclier_max {
+            self.decroissance_surcharge = 0.0;
+            return;
+        }
+        self.decroissance_surcharge += delta_time * Self::DECROISSANCE_SURCHARGE;
+        let points = self.decroissance_surcharge.floor();
+        self.decroissance_surcharge -= points;
+        let surcharge = self.bouclier - self.bouclier_max;
+        self.bouclier -= surcharge.min(points as u8);
+    }
+
+    /// Régénère peu à peu le bouclier jusqu'à son maximum. La coque, elle, ne se régénère pas.
+    /// Un vaisseau détruit ne se régénère pas. À appeler à chaque frame.
+    /// # Arguments
+    /// - `delta_time` : Temps écoulé (en secondes) depuis la dernière frame.
+    /// - `vitesse` : Points de bouclier régénérés par seconde (0.0 = pas de régénération).
+    pub fn regenerer_bouclier(&mut self, delta_time: f32, vitesse: f32) {
+        if self.bouclier >= self.bouclier_max || self.est_detruit() {
+            self.regeneration_bouclier = 0.0;
+            return;
+        }
+        self.regeneration_bouclier += delta_time * vitesse.max(0.0);
+        let points = self.regeneration_bouclier.floor();
+        self.regeneration_bouclier -= points;
+        let manque = self.bouclier_max - self.bouclier;
+        self.bouclier += manque.min(points as u8);
+    }
+
+    /// Retourne le bouclier restant sous forme de fraction, entre 0.0 (vide) et 1.0 (plein).
+    /// La fraction dépasse 1.0 quand le bouclier est en surcharge.
+    pub fn fraction_bouclier(&self) -> f32 {
+        self.bouclier as f32 / Self::BOUCLIER_MAX as f32
+    }
+
+    /// Retourne la position du nez du vaisseau (le sommet du triangle), d'où partent les missiles.
+    pub fn point_tir(&self) -> Vec2 {
+        self.transform.position + vec2(self.rotation.cos(), self.rotation.sin()) * Self::RAYON
+    }
+
+    /// Retourne l'angle du prochain missile et avance la phase du balayage.
+    /// L'angle oscille autour de l'orientation du vaisseau, sans jamais s'en écarter de plus de `balayage`.
+    /// # Arguments
+    /// - `balayage` - Demi-amplitude du balayage en radians (0.0 = le missile part dans l'axe du vaisseau).
+    pub fn angle_tir(&mut self, balayage: f32) -> f32 {
+        let decalage = balayage * self.phase_tir.sin();
+        self.phase_tir = (self.phase_tir + Self::PAS_PHASE_TIR) % std::f32::consts::TAU;
+        self.rotation + decalage
     }
 
     /// Méthode pour dessiner le vaisseau à l'écran avec un triangle, représentant le vaisseau, entouré par un cercle qui représente son bouclier.
     pub fn draw(&self) {
         // Dessine un cercle, son point central c'est les cordonnées x et y du vaisseau.
-        // Son rayon est de 15px, son épaisseur est de 3px et il est vert
-        draw_circle_lines(self.position.x, self.position.y, 15.0, 3.0, GREEN);
+        // Son rayon est celui du vaisseau, son épaisseur est de 3px et il est vert
+        draw_circle_lines(
+            self.transform.position.x,
+            self.transform.position.y,
+            Self::RAYON,
+            3.0,
+            GREEN,
+        );
 
         // Maintenant pour le triangle qui représente le vaisseau.
         // Dessine un triangle à l'intérieur du cercle pour représenter un vaisseau entouré de son bouclier.
 
         // Sommet du triangle
-        let point1 = vec2(
-            self.position.x + self.rotation.cos() * 15.0,
-            self.position.y + self.rotation.sin() * 15.0,
-        );
+        let point1 = self.point_tir();
 
         // Points pour dessiner la base du triangle
         // Cercle trigonométrique, on place les points sur 4PI/5 pour la base du triangle.
-        let decale_angle = 3.1415 * 4.0 / 5.0;
+        let decale_angle = std::f32::consts::PI * 4.0 / 5.0;
         let point2 = vec2(
-            self.position.x + (self.rotation + decale_angle).cos() * 15.0,
-            self.position.y + (self.rotation + decale_angle).sin() * 15.0,
+            self.transform.position.x + (self.rotation + decale_angle).cos() * Self::RAYON,
+            self.transform.position.y + (self.rotation + decale_angle).sin() * Self::RAYON,
         );
 
         let point3 = vec2(
-            self.position.x + (self.rotation - decale_angle).cos() * 15.0,
-            self.position.y + (self.rotation - decale_angle).sin() * 15.0,
+            self.transform.position.x + (self.rotation - decale_angle).cos() * Self::RAYON,
+            self.transform.position.y + (self.rotation - decale_angle).sin() * Self::RAYON,
         );
 
         // Dessiner le triangle en connectant les points qu'on vient de créer.
@@ -92,189 +250,340 @@ impl Spaceship {
     /// Met à jour la position du vaisseau en fonction des entrées utilisateur.
     /// Cette fonction gère également les collisions avec les astéroïdes.
     /// # Arguments:
-    /// - Référence mutable au vecteur qui contient les astéroïdes présents à l'écran.
-    /// - L'objet vaisseau mutable car on va changer son positionnement en fonction des touches, etc...
-    pub fn maj_pos(&mut self, asteroids: &mut Vec<Asteroid>) {
-        // Rotation avec les touches droite et gauche:
-        if is_key_down(KeyCode::Left) {
-            self.rotation -= 0.05; // Tourne à gauche
+    /// - `entree` : État des commandes pour cette frame (clavier ou entrée synthétique).
+    /// - `asteroids` : Référence mutable aux astéroïdes présents à l'écran.
+    /// - `settings` : Paramètres du jeu qui règlent le comportement du vaisseau.
+    /// - `temps` : Temps actuel en secondes (`get_time()` dans le jeu), utilisé pour le cooldown des collisions.
+    /// - `ecran` : Dimensions de l'écran (largeur, hauteur) pour le rebouclage.
+    /// # Retourne `true` si le vaisseau a subi un impact (dégâts au bouclier) pendant cette frame.
+    pub fn maj_pos(
+        &mut self,
+        entree: &InputState,
+        asteroids: &mut [Asteroid],
+        settings: &Settings,
+        temps: f64,
+        ecran: Vec2,
+    ) -> bool {
+        // Avec la visée à la souris, le vaisseau s'oriente directement vers le point visé.
+        if let Some(cible) = entree.visee {
+            self.rotation = angle_vers(self.transform.position, cible);
         }
 
-        if is_key_down(KeyCode::Right) {
-            self.rotation += 0.05; // Tourne à droite
-        }
+        if settings.poussee_directe {
+            // Poussée directe : les flèches accélèrent le vaisseau dans leur direction à l'écran,
+            // quelle que soit son orientation. L'orientation ne sert qu'à viser.
+            let direction = vec2(
+                entree.droite as i32 as f32 - entree.gauche as i32 as f32,
+                entree.bas as i32 as f32 - entree.haut as i32 as f32,
+            );
+            if direction != Vec2::ZERO {
+                self.transform.vitesse += direction.normalize() * settings.acceleration_vaisseau;
+                // Sans visée à la souris, le vaisseau se tourne dans la direction de la poussée.
+                if entree.visee.is_none() {
+                    self.rotation = direction.y.atan2(direction.x);
+                }
+            }
+        } else {
+            // Rotation avec les touches droite et gauche (sauf si le vaisseau vise la souris):
+            // Avec l'inversion des commandes, les deux touches échangent leur effet.
+            let (gauche, droite) = if settings.inverser_rotation {
+                (entree.droite, entree.gauche)
+            } else {
+                (entree.gauche, entree.droite)
+            };
+            if settings.rotation_inertielle {
+                // Les touches accélèrent la rotation, que le frottement ralentit peu à peu.
+                if entree.visee.is_none() {
+                    let sens = droite as i32 as f32 - gauche as i32 as f32;
+                    self.vitesse_rotation += sens * Self::ACCELERATION_ROTATION;
+                }
+                self.vitesse_rotation *= Self::FRICTION_ROTATION;
+                if self.vitesse_rotation.abs() < Self::VITESSE_ROTATION_MIN {
+                    self.vitesse_rotation = 0.0;
+                }
+                self.rotation += self.vitesse_rotation;
+            } else {
+                if gauche && entree.visee.is_none() {
+                    self.rotation -= Self::VITESSE_ROTATION; // Tourne à gauche
+                }
 
-        // Accélération avec la touche "Haut"
-        if is_key_down(KeyCode::Up) {
-            let accel = vec2(self.rotation.cos(), self.rotation.sin()) * 0.2;
-            self.vitesse += accel
-        }
+                if droite && entree.visee.is_none() {
+                    self.rotation += Self::VITESSE_ROTATION; // Tourne à droite
+                }
+            }
 
-        // Rétro-poussée avec la touche "Bas"
-        if is_key_down(KeyCode::Down) {
-            let accel = vec2(self.rotation.cos(), self.rotation.sin()) * 0.2;
-            self.vitesse -= accel
+            // Accélération avec la touche "Haut"
+            if entree.haut {
+                let accel =
+                    vec2(self.rotation.cos(), self.rotation.sin()) * settings.acceleration_vaisseau;
+                self.transform.vitesse += accel
+            }
+
+            // Rétro-poussée avec la touche "Bas", ou freinage : la vitesse diminue jusqu'à zéro,
+            // sans jamais repartir en arrière.
+            if entree.bas && settings.freinage {
+                let freinage = settings.acceleration_vaisseau * Self::FACTEUR_FREINAGE;
+                let vitesse = self.transform.vitesse;
+                self.transform.vitesse =
+                    vitesse.clamp_length_max((vitesse.length() - freinage).max(0.0));
+            } else if entree.bas {
+                let accel =
+                    vec2(self.rotation.cos(), self.rotation.sin()) * settings.acceleration_vaisseau;
+                self.transform.vitesse -= accel
+            }
         }
 
         // Pour eviter qu'on puisse prendre une vitesse infinie, on va rajouter un effet de friction pour que le vaisseau ralentisse.
-        self.vitesse *= 0.97;
+        self.transform.vitesse *= settings.friction_vaisseau;
 
         // Mise à jour de la position, on ajoute la vitesse actuelle à la position
-        self.position += self.vitesse;
+        self.transform.advance(settings.vitesse_simulation);
 
         // Rebouclage si on sort de l'écran
-        self.position = Self::bound_pos(self.position);
+        self.transform.wrap(ecran.x, ecran.y);
 
+        self.gerer_collisions(asteroids.iter_mut(), settings, temps)
+    }
+
+    /// Gère les collisions du vaisseau avec des astéroïdes : le vaisseau est repoussé hors des astéroïdes touchés,
+    /// qui rebondissent, et subit les dégâts du plus gros impact (au plus un impact toutes les 0.5 secondes).
+    /// Appelée par `maj_pos` pour les astéroïdes ordinaires, et séparément pour ceux qui ne sont pas dans la liste
+    /// des astéroïdes (cluster).
+    /// # Arguments:
+    /// - `asteroids` : Astéroïdes avec lesquels le vaisseau peut entrer en collision.
+    /// - `settings` : Paramètres du jeu qui règlent les collisions.
+    /// - `temps` : Temps actuel en secondes, utilisé pour le cooldown des collisions.
+    /// # Retourne `true` si le vaisseau a subi un impact (dégâts au bouclier) pendant cette frame.
+    pub fn gerer_collisions<'a>(
+        &mut self,
+        asteroids: impl IntoIterator<Item = &'a mut Asteroid>,
+        settings: &Settings,
+        temps: f64,
+    ) -> bool {
         // Detection des collisions avec les astéroïdes
-        for asteroid in asteroids.iter_mut() {
-            let distance = self.position.distance(asteroid.get_position());
+        // Quand le vaisseau touche plusieurs astéroïdes à la fois, on additionne les corrections
+        // pour ne le repousser qu'une seule fois, et il ne subit les dégâts que d'un seul impact.
+        let mut correction_totale = Vec2::ZERO; // Somme des corrections de position
+        let mut correction_max: f32 = 0.0; // Plus grande pénétration dans un astéroïde
+        let mut taille_impact = None; // Taille du plus gros astéroïde touché
+        let vitesse_impact = self.transform.vitesse.length(); // Vitesse du vaisseau au moment des impacts
+
+        for asteroid in asteroids {
+            let distance = self.transform.position.distance(asteroid.get_position());
             // Variable distance qui permet de calculer la distance entre un astéroïde et le vaisseau
-            // self.position = position x, y du vaisseau
+            // self.transform.position = position x, y du vaisseau
             // asteroid.get_position() = position x, y de l'astéroïde avec la méthode get_position qu'on a créé.
             // .distance() méthode de Vec2 qui calcule la distance entre deux points (doc)
 
-            let distance_collision = 15.0 + asteroid.rayon_asteroid();
+            let distance_collision = Self::RAYON + asteroid.rayon_asteroid();
             // Tout d'abord on calcule la distance entre le centre des deux objets avec la variable distance.
             // La vrai distance avant la collision n'est pas la distance jusqu'au centre de l'objet mais là où se trouve son périmètre
             // Le vaisseau a un rayon de 15, asteroid.rayon_asteroid prends le rayon de l'astéroïde.
 
             if distance < distance_collision {
                 // Calculer le vecteur directionnel de collision
-                let collision_direction = asteroid.get_position() - self.position;
+                let collision_direction = asteroid.get_position() - self.transform.position;
+                // Si les deux centres sont confondus, on prend une direction par défaut pour éviter les NaN.
+                let normale = direction_ou_defaut(collision_direction);
 
                 // Empêcher que le vaisseau rentre dans l'astéroïde
-                let correction_vector =
-                    collision_direction.normalize() * (distance_collision - distance);
-                self.position -= correction_vector;
+                let penetration = distance_collision - distance;
+                correction_totale += normale * penetration;
+                correction_max = correction_max.max(penetration);
 
                 // L'asteroid rebondi sur le vaisseau si vaisseau immobile.
-                asteroid.rebondir(collision_direction);
-
-                if get_time() - self.cooldown > 0.5 {
-                    self.cooldown = get_time();
-                    //println!("Collision détectée !");
-                    self.bouclier = match asteroid.get_taille() {
-                        1 => self.bouclier.saturating_sub(10),
-                        2 => self.bouclier.saturating_sub(15),
-                        3 => self.bouclier.saturating_sub(25),
-                        _ => self.bouclier, // On ne change pas la valeur du bouclier.
-                    };
-                };
+                asteroid.rebondir(normale);
+
+                // Un bouclier bien chargé renvoie les petits astéroïdes : ce n'est pas un impact.
+                let reflechi = asteroid.get_taille() == 1
+                    && self.bouclier > Self::SEUIL_BOUCLIER_REFLECHISSANT;
+                if !reflechi {
+                    taille_impact = taille_impact.max(Some(asteroid.get_taille()));
+                }
 
                 // Ajouter une impulsion à l'astéroïde si le vaisseau a une vitesse suffisante
-                if self.vitesse.length() > 0.1 {
-                    let impulse_strength = 1.2; // Facteur d'impulsion
-                    let impulse =
-                        collision_direction.normalize() * self.vitesse.length() * impulse_strength;
+                if vitesse_impact > 0.1 {
+                    let impulse = normale * vitesse_impact * settings.force_impulsion_collision;
                     asteroid.nouvelle_vitesse(impulse); // Appliquer l'impulsion temporaire
                 }
-
-                // Réduire la vitesse du vaisseau après la collision (optionnel)
-                self.vitesse *= 0.5;
             }
 
             // Assurez-vous que l'astéroïde ne descend pas en dessous de sa vitesse minimale
             asteroid.appliquer_resistance();
         }
+
+        // Une seule poussée, jamais plus forte que la plus grande pénétration.
+        self.transform.position -= correction_totale.clamp_length_max(correction_max);
+
+        let mut touche = false; // Le vaisseau a-t-il subi des dégâts à cette frame ?
+        if let Some(taille) = taille_impact {
+            touche = self.subir_impact(Self::degats_collision(taille), temps);
+
+            // Réduire la vitesse du vaisseau après la collision, selon la part conservée dans les paramètres.
+            self.transform.vitesse *= settings.retention_vitesse_collision.clamp(0.0, 0.9);
+        }
+        touche
     }
 
-    /// Limite la position du vaisseau pour qu'il reste sur l'écran. (Même principe que pour les astéroïdes)
-    fn bound_pos(mut pos: Vec2) -> Vec2 {
-        pos.x = Self::bound_to(pos.x, screen_width());
-        pos.y = Self::bound_to(pos.y, screen_height());
-        pos
+    /// Inflige les dégâts d'un impact (astéroïde, boss, missile ennemi...), sauf si le vaisseau a déjà été touché
+    /// moins de `DELAI_IMPACTS` secondes plus tôt.
+    /// # Arguments
+    /// - `degats` : Points de dégâts infligés.
+    /// - `temps` : Temps actuel en secondes, utilisé pour le cooldown des collisions.
+    /// # Retourne `true` si le vaisseau a subi les dégâts.
+    pub fn subir_impact(&mut self, degats: u8, temps: f64) -> bool {
+        if temps - self.cooldown <= Self::DELAI_IMPACTS {
+            return false;
+        }
+        self.cooldown = temps;
+        self.subir_degats(degats);
+        true
     }
 
-    /// Limite une coordonnée à une valeur maximale pour assurer que les cordonnées restent dans les limites de l'écran.
-    fn bound_to(coord: f32, max: f32) -> f32 {
-        if coord < 0.0 {
-            max - coord
-        } else if coord > max {
-            coord - max
-        } else {
-            coord
+    /// Inflige des dégâts au vaisseau : le bouclier encaisse en premier, la coque prend ce qu'il n'a pas pu absorber.
+    /// # Arguments
+    /// - `degats` : Points de dégâts infligés.
+    pub fn subir_degats(&mut self, degats: u8) {
+        let avant = self.bouclier;
+        self.coque = self
+            .coque
+            .saturating_sub(degats.saturating_sub(self.bouclier));
+        self.bouclier = self.bouclier.saturating_sub(degats);
+        // Le bouclier vient de se briser : l'effet visuel sera joué une seule fois.
+        self.bouclier_brise |= avant > 0 && self.bouclier == 0;
+    }
+
+    /// Indique si le bouclier vient de se briser, et consomme l'événement :
+    /// l'appel suivant retourne `false` tant que le bouclier ne se brise pas à nouveau.
+    pub fn prendre_bouclier_brise(&mut self) -> bool {
+        std::mem::take(&mut self.bouclier_brise)
+    }
+
+    /// Retourne les dégâts infligés au bouclier par un astéroïde de la taille donnée.
+    fn degats_collision(taille: u8) -> u8 {
+        match taille {
+            1 => constantes::DEGATS_PETIT_ASTEROIDE,
+            2 => constantes::DEGATS_MOYEN_ASTEROIDE,
+            3 => constantes::DEGATS_GRAND_ASTEROIDE,
+            _ => 0, // On ne change pas la valeur du bouclier.
         }
     }
 
+    /// Ajoute une impulsion extérieure (onde de choc, choc...) à la vitesse du vaisseau.
+    /// La vitesse obtenue est limitée à `VITESSE_MAX`.
+    pub fn appliquer_impulsion(&mut self, impulsion: Vec2) {
+        self.transform.vitesse =
+            (self.transform.vitesse + impulsion).clamp_length_max(Self::VITESSE_MAX);
+    }
+
     /// Affichage de l'interface du boulier en haut à droite.
-    pub fn dessiner_interface_bouclier(&self) {
-        let largeur_barre_bouclier = 199.0 * (self.bouclier as f32 / 100.0);
+    /// La surcharge éventuelle est dessinée en doré par-dessus la barre pleine.
+    /// # Arguments
+    /// - `echelle` : Échelle de l'interface (voir `taille_relative`), les tailles suivent celles de l'écran.
+    /// - `ecran` : Dimensions de l'écran (largeur, hauteur).
+    pub fn dessiner_interface_bouclier(&self, echelle: f32, ecran: Vec2) {
+        let t = |pixels| taille_relative(pixels, ecran, echelle);
+        let gauche = ecran.x - t(220.0); // Bord gauche de la barre
+        let largeur_barre_bouclier = t(199.0) * self.fraction_bouclier().min(1.0);
         // On dessine un premier rectangle blanc = Fond de la barre
-        draw_rectangle(screen_width() - 220.0, 20.0, 200.0, 10.0, WHITE);
+        draw_rectangle(gauche, t(20.0), t(200.0), t(10.0), WHITE);
 
         // On dessine maintenant un rectangle vert qui represente le pourcentage:
         draw_rectangle(
-            screen_width() - 219.0,
-            21.0,
+            gauche + t(1.0),
+            t(21.0),
             largeur_barre_bouclier,
-            8.0,
+            t(8.0),
             GREEN,
         );
 
+        // La surcharge (au-delà de 100%) recouvre le début de la barre.
+        let surcharge = (self.fraction_bouclier() - 1.0).max(0.0);
+        if surcharge > 0.0 {
+            draw_rectangle(gauche + t(1.0), t(21.0), t(199.0) * surcharge, t(8.0), GOLD);
+        }
+
+        // La coque, s'il y en a une, est dessinée juste en dessous, en gris.
+        if self.coque_max > 0 {
+            let fraction_coque = self.coque as f32 / self.coque_max as f32;
+            draw_rectangle(gauche, t(33.0), t(200.0), t(6.0), WHITE);
+            draw_rectangle(
+                gauche + t(1.0),
+                t(34.0),
+                t(199.0) * fraction_coque,
+                t(4.0),
+                GRAY,
+            );
+        }
+
         // Finalement on écrit un petit texte avec les informations
-        draw_text(
-            &format!("Bouclier: {}%", self.bouclier),
-            screen_width() - 220.0,
-            52.0,
-            20.0,
-            WHITE,
-        );
+        let texte = if self.coque_max > 0 {
+            format!("Bouclier: {}%  Coque: {}", self.bouclier, self.coque)
+        } else {
+            format!("Bouclier: {}%", self.bouclier)
+        };
+        draw_text(&texte, gauche, t(52.0), t(20.0), WHITE);
+    }
+}
+
+/// Calcule l'angle (en radians, même repère que `rotation`) du vecteur qui va de `depart` vers `cible`.
+pub fn angle_vers(depart: Vec2, cible: Vec2) -> f32 {
+    let direction = cible - depart;
+    direction.y.atan2(direction.x)
+}
+
+impl StellarObject for Spaceship {
+    fn get_position(&self) -> Vec2 {
+        self.transform.position
+    }
+
+    fn set_position(&mut self, new_position: Vec2) {
+        self.transform.position = new_position;
+    }
+
+    fn get_vitesse(&self) -> Vec2 {
+        self.transform.vitesse
+    }
+
+    fn set_vitesse(&mut self, new_vitesse: Vec2) {
+        self.transform.vitesse = new_vitesse;
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ::rand::thread_rng;
 
-    /// Les tests unitaires ont utilisant macroquad génèrent des erreurs.
-    /// On simule alors la création du vaisseau  avec une nouvelle structure qui créé un vaisseau avec des variables prenant des f32 au lieu de Vec2 (fonction de Macroquad).
-    #[derive(Debug, PartialEq)]
-    struct SpaceshipTest {
-        x: f32,
-        y: f32,
-        vitesse_x: f32,
-        vitesse_y: f32,
-        bouclier: u8,
-    }
-
-    impl SpaceshipTest {
-        /// Crée un nouveau vaisseau pour les tests.
-        /// On écrit exactement les mêmes fonctions (celles qui nous intéressent) et utilisant des f32 au lieu des Vec2.
-        fn new() -> Self {
-            Self {
-                x: 400.0, // Centre fictif de l'écran (largeur)
-                y: 300.0, // Centre fictif de l'écran (hauteur)
-                vitesse_x: 0.0,
-                vitesse_y: 0.0,
-                bouclier: 100,
-            }
-        }
+    /// Dimensions fictives de l'écran pour les tests (les fonctions de macroquad ne sont pas disponibles).
+    const ECRAN: Vec2 = vec2(800.0, 600.0);
 
-        /// Restaure le bouclier à 100%.
-        fn restaurer_bouclier(&mut self) {
-            self.bouclier = 100;
-        }
+    /// Met à jour le vaisseau avec les paramètres par défaut sur l'écran de test.
+    fn maj(
+        vaisseau: &mut Spaceship,
+        entree: &InputState,
+        asteroids: &mut [Asteroid],
+        temps: f64,
+    ) -> bool {
+        vaisseau.maj_pos(entree, asteroids, &Settings::default(), temps, ECRAN)
+    }
 
-        /// Recentre le vaisseau au milieu de l'écran et réinitialise sa vitesse.
-        fn recentrer(&mut self) {
-            self.x = 400.0; // Centre fictif de l'écran (largeur)
-            self.y = 300.0; // Centre fictif de l'écran (hauteur)
-            self.vitesse_x = 0.0; // Le vaisseau est à l'arrêt.
-            self.vitesse_y = 0.0;
-        }
+    /// Crée un astéroïde de la taille donnée, qui chevauche le vaisseau de test placé en (400, 300).
+    fn asteroid_sur_vaisseau(taille: u8) -> Asteroid {
+        Asteroid::nouvel_asteroid(&mut thread_rng(), taille, vec2(420.0, 300.0))
     }
 
     #[test]
     fn test_creation_vaisseau() {
-        let vaisseau = SpaceshipTest::new(); // Nouveau vaisseau.
+        let vaisseau = Spaceship::avec_position(vec2(400.0, 300.0)); // Nouveau vaisseau.
         assert_eq!(vaisseau.bouclier, 100); // On vérifie que le bouclier est bien à 100%.
-        assert_eq!(vaisseau.x, 400.0); // Et qu'il se trouve bien au centre de l'écran en x.
-        assert_eq!(vaisseau.y, 300.0); // Et qu'il se trouve bien au centre de l'écran en y.
+        assert_eq!(vaisseau.transform.position, vec2(400.0, 300.0)); // Et qu'il se trouve bien à la position demandée.
+        assert_eq!(vaisseau.transform.vitesse, Vec2::ZERO); // Et qu'il est immobile.
     }
 
     #[test]
     fn test_restaurer_bouclier() {
-        let mut vaisseau = SpaceshipTest::new(); // Nouveau Vaisseau.
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
         vaisseau.bouclier = 50; // Son bouclier est à 50%.
         vaisseau.restaurer_bouclier(); // On utilise notre fonction pour réstaurer le bouclier.
         assert_eq!(vaisseau.bouclier, 100); // On vérifie que le bouclier est bien à 100%.
@@ -282,15 +591,554 @@ mod tests {
 
     #[test]
     fn test_recentrer_vaisseau() {
-        let mut vaisseau = SpaceshipTest::new();
-        vaisseau.x = 100.0; // On met le vaisseau à un autre endroit que le centre en x.
-        vaisseau.y = 100.0; // On met le vaisseau à un autre endroit que le centre en y.
-        vaisseau.vitesse_x = 1.0; // On lui donne une vitesse pour montrer qu'il n'est pas à l'arrêt.
-        vaisseau.vitesse_y = 1.0; // On lui donne une vitesse pour montrer qu'il n'est pas à l'arrêt.
-        vaisseau.recentrer(); // On utilise notre méthode pour recenter le vaisseau.
-        assert_eq!(vaisseau.x, 400.0); // On vérifie que les cordonnées x sont bien au centre que l'on a défini.
-        assert_eq!(vaisseau.y, 300.0); // On vérifie que les cordonnées y sont bien au centre que l'on a défini.
-        assert_eq!(vaisseau.vitesse_x, 0.0); // On vérifie également que la vitesse est nulle en x.
-        assert_eq!(vaisseau.vitesse_y, 0.0); // On vérifie également que la vitesse est nulle en y.
+        let mut vaisseau = Spaceship::avec_position(vec2(100.0, 100.0));
+        vaisseau.transform.vitesse = vec2(1.0, 1.0); // On lui donne une vitesse pour montrer qu'il n'est pas à l'arrêt.
+        vaisseau.recentrer_sur(vec2(400.0, 300.0));
+        assert_eq!(vaisseau.transform.position, vec2(400.0, 300.0));
+        assert_eq!(vaisseau.transform.vitesse, Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_poussee_dans_la_direction_du_vaisseau() {
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0)); // Orienté vers la droite (rotation 0).
+        let entree = InputState {
+            haut: true,
+            ..Default::default()
+        };
+        maj(&mut vaisseau, &entree, &mut [], 0.0);
+        assert!(vaisseau.transform.vitesse.x > 0.0); // La vitesse augmente dans la direction du vaisseau.
+        assert!(vaisseau.transform.vitesse.y.abs() < 1e-6);
+    }
+
+    /// `get_vitesse` donne la vitesse du vaisseau après une poussée : accélération, puis friction.
+    /// Elle augmente tant que la poussée est maintenue, puis diminue une fois la touche relâchée.
+    #[test]
+    fn test_get_vitesse_apres_poussee() {
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        assert_eq!(vaisseau.get_vitesse(), Vec2::ZERO);
+        let entree = InputState {
+            haut: true,
+            ..Default::default()
+        };
+        maj(&mut vaisseau, &entree, &mut [], 0.0);
+        let attendu = vec2(
+            Spaceship::ACCELERATION_DEFAUT * constantes::FRICTION_VAISSEAU,
+            0.0,
+        );
+        assert!(vaisseau.get_vitesse().distance(attendu) < 1e-6);
+
+        let mut precedente = vaisseau.get_vitesse().length();
+        for _ in 0..10 {
+            maj(&mut vaisseau, &entree, &mut [], 0.0);
+            assert!(vaisseau.get_vitesse().length() > precedente);
+            precedente = vaisseau.get_vitesse().length();
+        }
+        maj(&mut vaisseau, &InputState::default(), &mut [], 0.0);
+        assert!(vaisseau.get_vitesse().length() < precedente);
+    }
+
+    #[test]
+    fn test_retro_poussee() {
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        let entree = InputState {
+            bas: true,
+            ..Default::default()
+        };
+        maj(&mut vaisseau, &entree, &mut [], 0.0);
+        assert!(vaisseau.transform.vitesse.x < 0.0); // La vitesse diminue dans la direction du vaisseau.
+    }
+
+    #[test]
+    fn test_rotation_gauche_droite() {
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        let gauche = InputState {
+            gauche: true,
+            ..Default::default()
+        };
+        maj(&mut vaisseau, &gauche, &mut [], 0.0);
+        assert!(vaisseau.rotation < 0.0);
+
+        let droite = InputState {
+            droite: true,
+            ..Default::default()
+        };
+        maj(&mut vaisseau, &droite, &mut [], 0.0);
+        maj(&mut vaisseau, &droite, &mut [], 0.0);
+        assert!(vaisseau.rotation > 0.0);
+    }
+
+    /// Avec l'inversion des commandes, "gauche" fait tourner dans le sens de "droite", et inversement,
+    /// avec ou sans rotation inertielle.
+    #[test]
+    fn test_inverser_rotation() {
+        let gauche = InputState {
+            gauche: true,
+            ..Default::default()
+        };
+        let droite = InputState {
+            droite: true,
+            ..Default::default()
+        };
+        for rotation_inertielle in [false, true] {
+            let settings = Settings {
+                inverser_rotation: true,
+                rotation_inertielle,
+                ..Default::default()
+            };
+            let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+            vaisseau.maj_pos(&gauche, &mut [], &settings, 0.0, ECRAN);
+            assert!(vaisseau.rotation > 0.0);
+
+            let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+            vaisseau.maj_pos(&droite, &mut [], &settings, 0.0, ECRAN);
+            assert!(vaisseau.rotation < 0.0);
+        }
+    }
+
+    /// Avec la rotation inertielle, le vaisseau continue de tourner après avoir relâché la touche,
+    /// puis s'arrête à cause du frottement.
+    #[test]
+    fn test_rotation_inertielle() {
+        let settings = Settings {
+            rotation_inertielle: true,
+            ..Default::default()
+        };
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        let droite = InputState {
+            droite: true,
+            ..Default::default()
+        };
+        for _ in 0..30 {
+            vaisseau.maj_pos(&droite, &mut [], &settings, 0.0, ECRAN);
+        }
+        // Touche enfoncée, la rotation plafonne vers la vitesse de la rotation sans inertie.
+        assert!((vaisseau.vitesse_rotation - Spaceship::VITESSE_ROTATION).abs() < 0.01);
+
+        // Touche relâchée : la rotation continue...
+        let relachee = vaisseau.rotation;
+        vaisseau.maj_pos(&InputState::default(), &mut [], &settings, 0.0, ECRAN);
+        assert!(vaisseau.rotation > relachee);
+
+        // ... puis s'arrête.
+        for _ in 0..100 {
+            vaisseau.maj_pos(&InputState::default(), &mut [], &settings, 0.0, ECRAN);
+        }
+        assert_eq!(vaisseau.vitesse_rotation, 0.0);
+        let arretee = vaisseau.rotation;
+        vaisseau.maj_pos(&InputState::default(), &mut [], &settings, 0.0, ECRAN);
+        assert_eq!(vaisseau.rotation, arretee);
+        assert!(arretee - relachee < 1.0); // Le vaisseau n'a tourné que d'un petit angle après le relâchement.
+    }
+
+    #[test]
+    fn test_friction_sans_entree() {
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        vaisseau.transform.vitesse = vec2(2.0, 0.0);
+        maj(&mut vaisseau, &InputState::default(), &mut [], 0.0);
+        assert!(vaisseau.transform.vitesse.x > 0.0 && vaisseau.transform.vitesse.x < 2.0);
+        // Le vaisseau ralentit sans s'arrêter net.
+    }
+
+    #[test]
+    fn test_degats_collision_selon_taille() {
+        for (taille, bouclier_attendu) in [(1, 40), (2, 35), (3, 25)] {
+            let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+            vaisseau.bouclier = Spaceship::SEUIL_BOUCLIER_REFLECHISSANT; // Les petits astéroïdes ne sont plus renvoyés.
+            let mut asteroids = vec![asteroid_sur_vaisseau(taille)];
+            maj(&mut vaisseau, &InputState::default(), &mut asteroids, 1.0);
+            assert_eq!(vaisseau.bouclier, bouclier_attendu);
+        }
+    }
+
+    #[test]
+    fn test_cooldown_collision() {
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        let mut asteroids = vec![asteroid_sur_vaisseau(2)];
+        assert!(maj(
+            &mut vaisseau,
+            &InputState::default(),
+            &mut asteroids,
+            1.0
+        ));
+        assert_eq!(vaisseau.bouclier, 85);
+
+        // Nouvelle collision moins de 0.5s après : pas de dégâts.
+        asteroids[0] = asteroid_sur_vaisseau(2);
+        vaisseau.transform.position = vec2(400.0, 300.0);
+        assert!(!maj(
+            &mut vaisseau,
+            &InputState::default(),
+            &mut asteroids,
+            1.3
+        )); // Pas d'impact signalé
+        assert_eq!(vaisseau.bouclier, 85);
+
+        // Après le cooldown, la collision fait à nouveau des dégâts.
+        asteroids[0] = asteroid_sur_vaisseau(2);
+        vaisseau.transform.position = vec2(400.0, 300.0);
+        assert!(maj(
+            &mut vaisseau,
+            &InputState::default(),
+            &mut asteroids,
+            1.6
+        ));
+        assert_eq!(vaisseau.bouclier, 70);
+    }
+
+    #[test]
+    fn test_collision_multiple() {
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        // Le vaisseau est coincé entre deux astéroïdes, de part et d'autre.
+        let mut asteroids = vec![
+            Asteroid::nouvel_asteroid(&mut thread_rng(), 1, vec2(420.0, 300.0)),
+            Asteroid::nouvel_asteroid(&mut thread_rng(), 2, vec2(400.0, 240.0)),
+        ];
+        maj(&mut vaisseau, &InputState::default(), &mut asteroids, 1.0);
+
+        // Une seule poussée, bornée par la plus grande pénétration (25px pour le premier astéroïde).
+        let deplacement = vaisseau.transform.position.distance(vec2(400.0, 300.0));
+        assert!(deplacement > 0.0 && deplacement <= 25.0 + 1e-4);
+        // Un seul impact : celui du plus gros astéroïde (taille 2 = 15 points).
+        assert_eq!(vaisseau.bouclier, 85);
+    }
+
+    #[test]
+    fn test_retention_vitesse_collision() {
+        for retention in [0.0, 0.5, 0.9] {
+            let settings = Settings {
+                retention_vitesse_collision: retention,
+                ..Default::default()
+            };
+            let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+            vaisseau.transform.vitesse = vec2(2.0, 0.0);
+            let mut asteroids = vec![asteroid_sur_vaisseau(2)];
+            vaisseau.maj_pos(
+                &InputState::default(),
+                &mut asteroids,
+                &settings,
+                1.0,
+                ECRAN,
+            );
+            // Vitesse après friction (x0.97), puis facteur de rétention.
+            assert!(
+                (vaisseau.transform.vitesse.x - 2.0 * constantes::FRICTION_VAISSEAU * retention)
+                    .abs()
+                    < 1e-5
+            );
+        }
+    }
+
+    /// Un astéroïde exactement au centre du vaisseau ne doit produire aucune position ni vitesse NaN.
+    #[test]
+    fn test_collision_positions_confondues() {
+        for vitesse in [Vec2::ZERO, vec2(2.0, 0.0)] {
+            let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+            vaisseau.transform.vitesse = vitesse;
+            // L'astéroïde est placé là où le vaisseau sera après son déplacement.
+            let position = vec2(400.0, 300.0) + vitesse * constantes::FRICTION_VAISSEAU;
+            let mut asteroids = vec![Asteroid::scripté(2, position, vec2(1.0, 0.0))];
+            maj(&mut vaisseau, &InputState::default(), &mut asteroids, 1.0);
+            assert!(vaisseau.transform.position.is_finite());
+            assert!(vaisseau.transform.vitesse.is_finite());
+            assert!(asteroids[0].get_position().is_finite());
+            assert!(asteroids[0].get_vitesse().is_finite());
+        }
+    }
+
+    /// Pour une même vitesse du vaisseau, une impulsion plus forte projette l'astéroïde plus vite.
+    #[test]
+    fn test_force_impulsion_collision() {
+        let vitesse_apres_impact = |force: f32| {
+            let settings = Settings {
+                force_impulsion_collision: force,
+                ..Default::default()
+            };
+            let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+            vaisseau.transform.vitesse = vec2(2.0, 0.0);
+            let mut asteroids = vec![asteroid_sur_vaisseau(2)];
+            vaisseau.maj_pos(
+                &InputState::default(),
+                &mut asteroids,
+                &settings,
+                1.0,
+                ECRAN,
+            );
+            asteroids[0].get_vitesse().length()
+        };
+        assert!(vitesse_apres_impact(3.0) > vitesse_apres_impact(1.2));
+        assert!(vitesse_apres_impact(1.2) > vitesse_apres_impact(0.5));
+    }
+
+    #[test]
+    fn test_bouclier_reflechit_petits_asteroides() {
+        // Bouclier au-dessus du seuil : le petit astéroïde est renvoyé sans dégâts.
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        let mut asteroids = vec![asteroid_sur_vaisseau(1)];
+        asteroids[0].nouvelle_vitesse(vec2(-1.0, 0.0)); // Il fonce vers le vaisseau.
+        maj(&mut vaisseau, &InputState::default(), &mut asteroids, 1.0);
+        assert_eq!(vaisseau.bouclier, 100);
+        assert!(asteroids[0].get_vitesse().x > 0.0); // Il repart dans l'autre sens.
+
+        // Bouclier sous le seuil : l'impact fait des dégâts normalement.
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        vaisseau.bouclier = 40;
+        let mut asteroids = vec![asteroid_sur_vaisseau(1)];
+        maj(&mut vaisseau, &InputState::default(), &mut asteroids, 1.0);
+        assert_eq!(vaisseau.bouclier, 30);
+    }
+
+    #[test]
+    fn test_angle_vers_quatre_quadrants() {
+        use std::f32::consts::{FRAC_PI_4, PI};
+        let centre = vec2(400.0, 300.0);
+        // Rappel : l'axe y de l'écran est orienté vers le bas.
+        assert!((angle_vers(centre, vec2(500.0, 400.0)) - FRAC_PI_4).abs() < 1e-5); // En bas à droite
+        assert!((angle_vers(centre, vec2(300.0, 400.0)) - 3.0 * FRAC_PI_4).abs() < 1e-5); // En bas à gauche
+        assert!((angle_vers(centre, vec2(300.0, 200.0)) + 3.0 * FRAC_PI_4).abs() < 1e-5); // En haut à gauche
+        assert!((angle_vers(centre, vec2(500.0, 200.0)) + FRAC_PI_4).abs() < 1e-5); // En haut à droite
+        assert!((angle_vers(centre, vec2(300.0, 300.0)).abs() - PI).abs() < 1e-5);
+        // Plein gauche
+    }
+
+    #[test]
+    fn test_visee_oriente_le_vaisseau() {
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        let entree = InputState {
+            visee: Some(vec2(400.0, 500.0)), // Droit en dessous
+            ..Default::default()
+        };
+        maj(&mut vaisseau, &entree, &mut [], 0.0);
+        assert!((vaisseau.rotation - std::f32::consts::FRAC_PI_2).abs() < 1e-5);
+    }
+
+    /// Une impulsion s'ajoute à la vitesse du vaisseau.
+    #[test]
+    fn test_appliquer_impulsion() {
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        vaisseau.transform.vitesse = vec2(1.0, 0.0);
+        vaisseau.appliquer_impulsion(vec2(0.5, -2.0));
+        assert_eq!(vaisseau.transform.vitesse, vec2(1.5, -2.0));
+    }
+
+    /// Une impulsion trop forte est limitée à la vitesse maximale, dans la direction obtenue.
+    #[test]
+    fn test_appliquer_impulsion_vitesse_max() {
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        vaisseau.appliquer_impulsion(vec2(100.0, 0.0));
+        assert!((vaisseau.transform.vitesse.length() - Spaceship::VITESSE_MAX).abs() < 1e-5);
+        assert!(vaisseau.transform.vitesse.x > 0.0 && vaisseau.transform.vitesse.y.abs() < 1e-6);
+    }
+
+    /// La fraction du bouclier vaut 0 quand il est vide, 0.5 à moitié et 1 quand il est plein.
+    #[test]
+    fn test_fraction_bouclier() {
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        for (bouclier, fraction) in [(0, 0.0), (50, 0.5), (100, 1.0)] {
+            vaisseau.bouclier = bouclier;
+            assert!((vaisseau.fraction_bouclier() - fraction).abs() < 1e-6);
+        }
+    }
+
+    /// En poussée directe, la flèche droite accélère toujours le vaisseau vers la droite de l'écran.
+    #[test]
+    fn test_poussee_directe() {
+        let settings = Settings {
+            poussee_directe: true,
+            ..Default::default()
+        };
+        let entree = InputState {
+            droite: true,
+            ..Default::default()
+        };
+        for rotation in [0.0, 1.0, std::f32::consts::PI, -2.0] {
+            let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+            vaisseau.rotation = rotation;
+            vaisseau.maj_pos(&entree, &mut [], &settings, 0.0, ECRAN);
+            assert!(vaisseau.transform.vitesse.x > 0.0);
+            assert!(vaisseau.transform.vitesse.y.abs() < 1e-6);
+        }
+    }
+
+    /// En mode freinage, la flèche bas ralentit le vaisseau dans sa direction jusqu'à l'arrêt, sans le faire reculer.
+    #[test]
+    fn test_freinage() {
+        let settings = Settings {
+            freinage: true,
+            ..Default::default()
+        };
+        let entree = InputState {
+            bas: true,
+            ..Default::default()
+        };
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        vaisseau.transform.vitesse = vec2(3.0, -4.0);
+        let mut precedente = vaisseau.transform.vitesse.length();
+        for _ in 0..200 {
+            vaisseau.maj_pos(&entree, &mut [], &settings, 0.0, ECRAN);
+            let vitesse = vaisseau.transform.vitesse;
+            assert!(vitesse.length() <= precedente);
+            // Toujours dans la même direction (ou à l'arrêt) : jamais de marche arrière.
+            assert!(vitesse.dot(vec2(3.0, -4.0)) >= 0.0);
+            assert!(vitesse.perp_dot(vec2(3.0, -4.0)).abs() < 1e-4);
+            precedente = vitesse.length();
+        }
+        assert_eq!(vaisseau.transform.vitesse, Vec2::ZERO);
+
+        // À l'arrêt, freiner ne fait pas bouger le vaisseau, contrairement à la rétro-poussée.
+        vaisseau.maj_pos(&entree, &mut [], &settings, 0.0, ECRAN);
+        assert_eq!(vaisseau.transform.vitesse, Vec2::ZERO);
+        let retro = Settings::default();
+        vaisseau.maj_pos(&entree, &mut [], &retro, 0.0, ECRAN);
+        assert!(vaisseau.transform.vitesse.length() > 0.0);
+    }
+
+    /// La surcharge fait passer le bouclier au-dessus de 100%, puis il redescend à 100% sans aller plus bas.
+    #[test]
+    fn test_surcharge_bouclier() {
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        vaisseau.bouclier = 40;
+        vaisseau.surcharger_bouclier();
+        assert_eq!(vaisseau.bouclier, Spaceship::BOUCLIER_SURCHARGE);
+        assert!(vaisseau.fraction_bouclier() > 1.0);
+
+        // Un bonus classique ne fait pas perdre la surcharge.
+        vaisseau.restaurer_bouclier();
+        assert_eq!(vaisseau.bouclier, Spaceship::BOUCLIER_SURCHARGE);
+
+        let mut precedent = vaisseau.bouclier;
+        for _ in 0..1000 {
+            vaisseau.decroitre_surcharge(1.0 / 60.0);
+            assert!(vaisseau.bouclier <= precedent);
+            assert!(vaisseau.bouclier >= Spaceship::BOUCLIER_MAX);
+            precedent = vaisseau.bouclier;
+        }
+        assert_eq!(vaisseau.bouclier, Spaceship::BOUCLIER_MAX);
+
+        // Un bouclier normal n'est pas touché par la décroissance.
+        vaisseau.bouclier = 60;
+        vaisseau.decroitre_surcharge(10.0);
+        assert_eq!(vaisseau.bouclier, 60);
+    }
+
+    /// Un bouclier renforcé (boutique) se régénère et se restaure au-delà de 100%, et n'en redescend pas.
+    #[test]
+    fn test_bouclier_renforce() {
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        vaisseau.set_bonus_bouclier(25);
+        vaisseau.regenerer_bouclier(10.0, 100.0);
+        assert_eq!(vaisseau.bouclier, 125);
+        vaisseau.decroitre_surcharge(10.0);
+        assert_eq!(vaisseau.bouclier, 125);
+
+        vaisseau.bouclier = 10;
+        vaisseau.restaurer_bouclier();
+        assert_eq!(vaisseau.bouclier, 125);
+    }
+
+    /// Le point de tir est au bout du nez du vaisseau, dans la direction de sa rotation.
+    #[test]
+    fn test_point_tir() {
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        assert!((vaisseau.point_tir() - vec2(415.0, 300.0)).length() < 1e-4);
+        vaisseau.rotation = std::f32::consts::PI / 2.0;
+        assert!((vaisseau.point_tir() - vec2(400.0, 315.0)).length() < 1e-4);
+    }
+
+    /// Une accélération plus forte fait gagner plus de vitesse à chaque frame de poussée.
+    #[test]
+    fn test_acceleration_reglable() {
+        let entree = InputState {
+            haut: true,
+            ..Default::default()
+        };
+        let vitesse_apres_poussee = |acceleration: f32| {
+            let settings = Settings {
+                acceleration_vaisseau: acceleration,
+                ..Default::default()
+            };
+            let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+            vaisseau.maj_pos(&entree, &mut [], &settings, 0.0, ECRAN);
+            vaisseau.transform.vitesse.length()
+        };
+        assert!(vitesse_apres_poussee(0.4) > vitesse_apres_poussee(Spaceship::ACCELERATION_DEFAUT));
+        assert!(vitesse_apres_poussee(Spaceship::ACCELERATION_DEFAUT) > vitesse_apres_poussee(0.1));
+    }
+
+    /// Le bouclier qui passe de positif à 0 déclenche l'effet d'éclatement une seule fois.
+    #[test]
+    fn test_bouclier_brise_une_seule_fois() {
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        vaisseau.bouclier = 20;
+        let mut asteroids = vec![asteroid_sur_vaisseau(2)];
+        maj(&mut vaisseau, &InputState::default(), &mut asteroids, 1.0);
+        assert_eq!(vaisseau.bouclier, 5);
+        assert!(!vaisseau.prendre_bouclier_brise());
+
+        asteroids[0] = asteroid_sur_vaisseau(2);
+        vaisseau.transform.position = vec2(400.0, 300.0);
+        maj(&mut vaisseau, &InputState::default(), &mut asteroids, 2.0);
+        assert_eq!(vaisseau.bouclier, 0);
+        assert!(vaisseau.prendre_bouclier_brise());
+        assert!(!vaisseau.prendre_bouclier_brise());
+
+        // Un nouvel impact avec le bouclier déjà à 0 ne rejoue pas l'effet.
+        asteroids[0] = asteroid_sur_vaisseau(2);
+        vaisseau.transform.position = vec2(400.0, 300.0);
+        maj(&mut vaisseau, &InputState::default(), &mut asteroids, 3.0);
+        assert!(!vaisseau.prendre_bouclier_brise());
+    }
+
+    /// Avec l'arme "spray", les tirs successifs oscillent de part et d'autre de l'orientation, dans le balayage.
+    #[test]
+    fn test_angle_tir_balayage() {
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        vaisseau.rotation = 1.0;
+        let balayage = 0.2;
+        let angles: Vec<f32> = (0..16).map(|_| vaisseau.angle_tir(balayage)).collect();
+
+        assert!(angles.iter().all(|a| (a - 1.0).abs() <= balayage + 1e-5));
+        assert!(angles.iter().any(|a| *a > 1.1));
+        assert!(angles.iter().any(|a| *a < 0.9));
+        // Deux tirs consécutifs ne partent pas dans la même direction.
+        assert!(angles.windows(2).all(|paire| paire[0] != paire[1]));
+
+        // Sans balayage, le missile part toujours dans l'axe du vaisseau.
+        assert!((0..5).all(|_| vaisseau.angle_tir(0.0) == 1.0));
+    }
+
+    /// Les dégâts vident le bouclier avant d'entamer la coque, et le vaisseau n'est détruit que quand les deux sont vides.
+    #[test]
+    fn test_degats_bouclier_puis_coque() {
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        vaisseau.set_coque(20);
+        vaisseau.bouclier = 10;
+        let mut asteroids = vec![asteroid_sur_vaisseau(2)];
+        maj(&mut vaisseau, &InputState::default(), &mut asteroids, 1.0);
+        assert_eq!(vaisseau.bouclier, 0);
+        assert_eq!(vaisseau.coque, 15); // 15 de dégâts : 10 pour le bouclier, 5 pour la coque.
+        assert!(!vaisseau.est_detruit());
+
+        asteroids[0] = asteroid_sur_vaisseau(2);
+        vaisseau.transform.position = vec2(400.0, 300.0);
+        maj(&mut vaisseau, &InputState::default(), &mut asteroids, 2.0);
+        assert_eq!(vaisseau.coque, 0);
+        assert!(vaisseau.est_detruit());
+    }
+
+    /// La régénération recharge le bouclier jusqu'à 100%, mais jamais la coque, que seul un bonus répare.
+    #[test]
+    fn test_regeneration_ne_repare_pas_la_coque() {
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        vaisseau.set_coque(50);
+        vaisseau.bouclier = 90;
+        vaisseau.coque = 30;
+        for _ in 0..600 {
+            vaisseau.regenerer_bouclier(1.0 / 60.0, 5.0);
+        }
+        assert_eq!(vaisseau.bouclier, Spaceship::BOUCLIER_MAX);
+        assert_eq!(vaisseau.coque, 30);
+
+        vaisseau.reparer_coque();
+        assert_eq!(vaisseau.coque, 50);
     }
 }
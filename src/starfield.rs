@@ -0,0 +1,110 @@
+//! Module pour le champ d'étoiles dessiné en fond d'écran.
+//! Ce module contient la structure `Starfield` : un ensemble d'étoiles placées une fois pour toutes
+//! (à partir d'une graine), dont la luminosité oscille au cours du temps pour les faire scintiller.
+//! Il remplace l'image de fond quand aucune texture de fond n'est configurée.
+
+use ::rand::rngs::SmallRng;
+use ::rand::{Rng, SeedableRng};
+use macroquad::prelude::*;
+
+/// Une étoile du champ d'étoiles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Etoile {
+    position: Vec2, // Position à l'écran (x,y)
+    rayon: f32,     // Rayon du point dessiné
+    phase: f32,     // Décalage du scintillement, pour que les étoiles ne scintillent pas ensemble
+    pulsation: f32, // Vitesse du scintillement (en radians par seconde)
+}
+
+/// Champ d'étoiles scintillantes, généré une seule fois.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Starfield {
+    etoiles: Vec<Etoile>,
+}
+
+impl Starfield {
+    /// Nombre d'étoiles du champ d'étoiles du jeu.
+    pub const NOMBRE_ETOILES: usize = 200;
+
+    /// Luminosité minimale d'une étoile (au creux du scintillement).
+    pub const LUMINOSITE_MIN: f32 = 0.3;
+
+    /// Luminosité maximale d'une étoile.
+    pub const LUMINOSITE_MAX: f32 = 1.0;
+
+    /// Génère un champ d'étoiles. À graine égale, les étoiles sont toujours au même endroit.
+    /// # Arguments
+    /// - `graine` - Graine du générateur aléatoire.
+    /// - `nombre` - Nombre d'étoiles.
+    /// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+    pub fn generer(graine: u64, nombre: usize, ecran: Vec2) -> Self {
+        let mut rng = SmallRng::seed_from_u64(graine);
+        let etoiles = (0..nombre)
+            .map(|_| Etoile {
+                position: vec2(rng.gen_range(0.0..ecran.x), rng.gen_range(0.0..ecran.y)),
+                rayon: rng.gen_range(0.5..1.8),
+                phase: rng.gen_range(0.0..std::f32::consts::TAU),
+                pulsation: rng.gen_range(0.5..3.0),
+            })
+            .collect();
+        Self { etoiles }
+    }
+
+    /// Retourne la luminosité d'une étoile au temps donné, entre `LUMINOSITE_MIN` et `LUMINOSITE_MAX`.
+    fn luminosite(etoile: &Etoile, temps: f64) -> f32 {
+        let oscillation = ((temps as f32) * etoile.pulsation + etoile.phase).sin() * 0.5 + 0.5; // Entre 0 et 1
+        Self::LUMINOSITE_MIN + (Self::LUMINOSITE_MAX - Self::LUMINOSITE_MIN) * oscillation
+    }
+
+    /// Dessine les étoiles avec leur luminosité au temps donné.
+    /// # Arguments
+    /// - `temps` - Temps actuel en secondes (`get_time()`).
+    pub fn draw(&self, temps: f64) {
+        for etoile in &self.etoiles {
+            let luminosite = Self::luminosite(etoile, temps);
+            draw_circle(
+                etoile.position.x,
+                etoile.position.y,
+                etoile.rayon,
+                Color::new(luminosite, luminosite, luminosite, 1.0),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ECRAN: Vec2 = vec2(800.0, 600.0);
+
+    #[test]
+    fn test_etoiles_dans_l_ecran() {
+        let champ = Starfield::generer(3, Starfield::NOMBRE_ETOILES, ECRAN);
+        assert_eq!(champ.etoiles.len(), Starfield::NOMBRE_ETOILES);
+        for etoile in &champ.etoiles {
+            assert!(etoile.position.x >= 0.0 && etoile.position.x < ECRAN.x);
+            assert!(etoile.position.y >= 0.0 && etoile.position.y < ECRAN.y);
+        }
+        // À graine égale, le champ d'étoiles est le même.
+        assert_eq!(
+            Starfield::generer(3, Starfield::NOMBRE_ETOILES, ECRAN),
+            champ
+        );
+    }
+
+    /// La luminosité de chaque étoile reste dans l'intervalle prévu, et elle varie au cours du temps.
+    #[test]
+    fn test_luminosite_valide() {
+        let champ = Starfield::generer(5, 50, ECRAN);
+        for etoile in &champ.etoiles {
+            let luminosites: Vec<f32> = (0..500)
+                .map(|i| Starfield::luminosite(etoile, i as f64 * 0.1))
+                .collect();
+            assert!(luminosites
+                .iter()
+                .all(|l| (Starfield::LUMINOSITE_MIN..=Starfield::LUMINOSITE_MAX).contains(l)));
+            assert!(luminosites.iter().any(|l| *l != luminosites[0]));
+        }
+    }
+}
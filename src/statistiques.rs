@@ -0,0 +1,114 @@
+//! Module pour les statistiques d'une partie.
+//! Ce module contient la structure `Statistiques`, remplie pendant la partie (tirs, impacts, astéroïdes détruits,
+//! temps de survie), et son export : à chaque game over, une ligne est ajoutée à un fichier CSV pour que le joueur
+//! puisse suivre ses progrès d'une partie à l'autre (option `--stats <chemin>`).
+//!
+//! Format du fichier : une ligne d'en-tête (`EN_TETE`), puis une ligne par partie, par exemple
+//! `1200,3,95.4,0.625,12` (score, niveau atteint, temps de survie en secondes, précision entre 0 et 1,
+//! astéroïdes détruits).
+
+use std::io::Write;
+
+/// Ligne d'en-tête du fichier des statistiques, écrite quand le fichier est créé.
+pub const EN_TETE: &str = "score,niveau,temps_survie,precision,asteroides_detruits";
+
+/// Statistiques d'une partie.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Statistiques {
+    pub score: u32,               // Score final
+    pub niveau: i32,              // Niveau atteint
+    pub temps_survie: f32,        // Temps de jeu (en secondes), sans les pauses
+    pub tirs: u32,                // Nombre de missiles tirés
+    pub touches: u32,             // Nombre d'impacts de missiles (astéroïdes, boss, cluster)
+    pub asteroides_detruits: u32, // Nombre d'astéroïdes détruits, fragments compris
+}
+
+impl Statistiques {
+    /// Retourne la précision du joueur : la part des missiles tirés qui ont touché quelque chose (entre 0 et 1).
+    /// La précision est nulle si le joueur n'a pas tiré.
+    pub fn precision(&self) -> f32 {
+        if self.tirs == 0 {
+            return 0.0;
+        }
+        (self.touches as f32 / self.tirs as f32).min(1.0)
+    }
+
+    /// Retourne la ligne CSV de la partie (sans retour à la ligne), dans l'ordre des colonnes de `EN_TETE`.
+    pub fn ligne_csv(&self) -> String {
+        format!(
+            "{},{},{:.1},{:.3},{}",
+            self.score,
+            self.niveau,
+            self.temps_survie,
+            self.precision(),
+            self.asteroides_detruits
+        )
+    }
+
+    /// Ajoute la ligne de la partie à la fin du fichier des statistiques.
+    /// Si le fichier n'existe pas encore, il est créé avec la ligne d'en-tête.
+    /// # Arguments
+    /// - `chemin` - Chemin du fichier CSV des statistiques.
+    pub fn exporter(&self, chemin: &str) -> std::io::Result<()> {
+        let nouveau = !std::path::Path::new(chemin).exists();
+        let mut fichier = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(chemin)?;
+        if nouveau {
+            writeln!(fichier, "{EN_TETE}")?;
+        }
+        writeln!(fichier, "{}", self.ligne_csv())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ligne_csv() {
+        let statistiques = Statistiques {
+            score: 1200,
+            niveau: 3,
+            temps_survie: 95.44,
+            tirs: 16,
+            touches: 10,
+            asteroides_detruits: 12,
+        };
+        assert_eq!(statistiques.ligne_csv(), "1200,3,95.4,0.625,12");
+        // Sans tir, la précision est nulle (et pas NaN).
+        assert_eq!(Statistiques::default().ligne_csv(), "0,0,0.0,0.000,0");
+    }
+
+    /// Le fichier est créé avec l'en-tête à la première partie ; les parties suivantes ajoutent une ligne.
+    #[test]
+    fn test_exporter() {
+        let chemin =
+            std::env::temp_dir().join(format!("statistiques_test_{}.csv", std::process::id()));
+        let chemin = chemin.to_str().unwrap();
+        let premiere = Statistiques {
+            score: 500,
+            niveau: 2,
+            ..Default::default()
+        };
+        let seconde = Statistiques {
+            score: 900,
+            niveau: 4,
+            ..Default::default()
+        };
+        premiere.exporter(chemin).unwrap();
+        seconde.exporter(chemin).unwrap();
+        let contenu = std::fs::read_to_string(chemin).unwrap();
+        std::fs::remove_file(chemin).unwrap();
+
+        assert_eq!(
+            contenu,
+            format!(
+                "{EN_TETE}\n{}\n{}\n",
+                premiere.ligne_csv(),
+                seconde.ligne_csv()
+            )
+        );
+    }
+}
@@ -12,17 +12,19 @@
 //! - `set_position`: Définit une nouvelle position pour l'objet.
 //! - `get_vitesse`: Renvoie la vitesse actuelle de l'objet.
 //! - `set_vitesse`: Définit une nouvelle vitesse pour l'objet.
-//! - `maj_position`: Met à jour la position de l'objet en fonction de sa vitesse actuelle.
-//! - `gestion_bords`: Gère la situation où l'objet dépasse les bords de l'écran pour le repositionner.
+//!
+//! Le déplacement et le rebouclage sur les bords de l'écran sont écrits une seule fois dans `transform::Transform`,
+//! que chaque objet fait avancer avec sa propre logique (rotation, gravité, friction...).
+//! La fonction `avancer_tous` permet de déplacer d'un coup, en ligne droite, une liste d'objets de types différents.
 
+use crate::transform::Transform;
 use macroquad::prelude::*;
 
 /// Trait `StellarObject`
 ///
 /// Ce trait définit les caractéristiques communes à tous les objets du jeu qui se déplacent,
 /// comme les astéroïdes, le vaisseau et les missiles.
-/// Les objets stellaires doivent avoir une position, une vitesse, et pouvoir se déplacer
-
+/// Les objets stellaires doivent avoir une position et une vitesse.
 pub trait StellarObject {
     /// Retourne la position actuelle de l'objet stellaire
     fn get_position(&self) -> Vec2;
@@ -35,30 +37,63 @@ pub trait StellarObject {
 
     /// Définit une nouvelle vitesse pour l'objet stellaire.
     fn set_vitesse(&mut self, new_vitesse: Vec2);
+}
 
-    /// Met à jour la position de l'objet stellaire en fonction de sa vitesse actuelle.
-    /// Cette méthode permet de déplacer l'objet en fonction de sa vitesse.
-    /// Elle additionne la position actuelle avec la vitesse pour calculer la nouvelle position.
-    fn maj_position(&mut self) {
-        let position_actuelle = self.get_position();
-        let vitesse = self.get_vitesse();
-        self.set_position(position_actuelle + vitesse);
+/// Fait avancer chaque objet stellaire de la liste en ligne droite, selon sa vitesse (sans sa logique propre :
+/// ni friction, ni gravité, ni rebouclage sur les bords).
+/// Les objets peuvent être de types différents (astéroïdes, missiles, vaisseau).
+/// # Arguments
+/// - `objets` : Références mutables vers les objets à déplacer.
+/// - `dt` : Nombre de frames écoulées (1.0 = une frame normale).
+pub fn avancer_tous(objets: &mut [&mut dyn StellarObject], dt: f32) {
+    for objet in objets.iter_mut() {
+        let mut transform = Transform::new(objet.get_position(), objet.get_vitesse());
+        transform.advance(dt);
+        objet.set_position(transform.position);
     }
+}
 
-    /// Gère les bords de l'écran pour assurer que l'objet reste visible en le repositionnant.
-    /// Si l'objet dépasse les limites de l'écran, cette méthode le replace de l'autre côté de l'écran.
-    fn gestion_bords(&mut self) {
-        let mut position = self.get_position();
-        if position.x > screen_width() {
-            position.x = 0.0;
-        } else if position.x < 0.0 {
-            position.x = screen_width();
-        }
-        if position.y > screen_height() {
-            position.y = 0.0;
-        } else if position.y < 0.0 {
-            position.y = screen_height();
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asteroid::Asteroid;
+    use crate::missile::Missile;
+    use crate::spaceship::Spaceship;
+    use ::rand::thread_rng;
+
+    /// Les objets de types différents se manipulent de la même façon à travers le trait.
+    #[test]
+    fn test_objets_differents() {
+        let mut asteroid = Asteroid::nouvel_asteroid(&mut thread_rng(), 2, vec2(100.0, 100.0));
+        let mut missile = Missile::nouveau_missile(vec2(0.0, 0.0), 0.0);
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        let objets: [&mut dyn StellarObject; 3] = [&mut asteroid, &mut missile, &mut vaisseau];
+        for objet in objets {
+            objet.set_position(vec2(10.0, 20.0));
+            objet.set_vitesse(vec2(-1.0, 0.5));
+            assert_eq!(objet.get_position(), vec2(10.0, 20.0));
+            assert_eq!(objet.get_vitesse(), vec2(-1.0, 0.5));
         }
-        self.set_position(position);
+    }
+
+    /// Une liste d'objets de types différents avance d'un coup, chacun selon sa propre vitesse.
+    #[test]
+    fn test_avancer_objets_differents() {
+        let mut asteroid = Asteroid::nouvel_asteroid(&mut thread_rng(), 2, vec2(100.0, 100.0));
+        asteroid.set_vitesse(vec2(1.0, 2.0));
+        let mut missile = Missile::nouveau_missile(vec2(0.0, 0.0), 0.0);
+        let mut vaisseau = Spaceship::avec_position(vec2(400.0, 300.0));
+        vaisseau.set_vitesse(vec2(-1.0, 0.5));
+
+        avancer_tous(&mut [&mut asteroid, &mut missile, &mut vaisseau], 2.0);
+
+        assert_eq!(StellarObject::get_position(&asteroid), vec2(102.0, 104.0));
+        assert_eq!(
+            StellarObject::get_position(&missile),
+            vec2(2.0 * Missile::VITESSE_DEFAUT, 0.0)
+        );
+        assert_eq!(StellarObject::get_position(&vaisseau), vec2(398.0, 301.0));
+        // Les vitesses ne changent pas.
+        assert_eq!(StellarObject::get_vitesse(&vaisseau), vec2(-1.0, 0.5));
     }
 }
@@ -0,0 +1,158 @@
+//! Module pour la position et la vitesse des objets du jeu.
+//! Ce module contient le composant `Transform`, partagé par les astéroïdes, les missiles et le vaisseau :
+//! le déplacement et le rebouclage sur les bords de l'écran n'y sont écrits qu'une seule fois,
+//! chaque type d'objet n'ajoutant que sa propre logique (rotation, gravité, friction...).
+
+use macroquad::prelude::*;
+
+/// Position et vitesse d'un objet qui se déplace à l'écran.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    pub position: Vec2, // Position actuelle (x,y)
+    pub vitesse: Vec2,  // Déplacement par frame
+}
+
+impl Transform {
+    /// Crée un composant à la position et avec la vitesse données.
+    pub fn new(position: Vec2, vitesse: Vec2) -> Self {
+        Self { position, vitesse }
+    }
+
+    /// Fait avancer l'objet selon sa vitesse.
+    /// # Arguments
+    /// - `dt` - Nombre de frames écoulées (1.0 = une frame normale, moins pour un déplacement ralenti).
+    pub fn advance(&mut self, dt: f32) {
+        self.position += self.vitesse * dt;
+        debug_assert!(
+            self.position.is_finite(),
+            "position non finie : {:?}",
+            self.position
+        );
+    }
+
+    /// Replace l'objet de l'autre côté de l'écran s'il en est sorti.
+    /// # Arguments
+    /// - `largeur` - Largeur de l'écran.
+    /// - `hauteur` - Hauteur de l'écran.
+    pub fn wrap(&mut self, largeur: f32, hauteur: f32) {
+        self.position.x = Self::bound_to(self.position.x, largeur);
+        self.position.y = Self::bound_to(self.position.y, hauteur);
+    }
+
+    /// Assure que la coordonnée reste dans les limites de l'écran.
+    fn bound_to(coord: f32, max: f32) -> f32 {
+        if coord < 0.0 {
+            max + coord
+        } else if coord > max {
+            coord - max
+        } else {
+            coord
+        }
+    }
+}
+
+/// Direction utilisée quand deux objets sont exactement au même endroit (vers la droite).
+pub const DIRECTION_PAR_DEFAUT: Vec2 = Vec2::X;
+
+/// Normalise un vecteur sans risque de NaN : un vecteur de longueur nulle (objets confondus)
+/// donne `DIRECTION_PAR_DEFAUT` au lieu d'une direction invalide.
+/// # Arguments
+/// - `vecteur` - Vecteur à normaliser.
+pub fn direction_ou_defaut(vecteur: Vec2) -> Vec2 {
+    vecteur.try_normalize().unwrap_or(DIRECTION_PAR_DEFAUT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asteroid::Asteroid;
+    use crate::input::InputState;
+    use crate::missile::Missile;
+    use crate::settings::Settings;
+    use crate::spaceship::Spaceship;
+    use crate::stellarobject::StellarObject;
+
+    const ECRAN: Vec2 = vec2(800.0, 600.0);
+
+    /// Un objet de chaque type, tous à la même position et à la même vitesse.
+    fn objets_de_chaque_type(position: Vec2, vitesse: Vec2) -> (Asteroid, Missile, Spaceship) {
+        let asteroid = Asteroid::scripté(2, position, vitesse);
+        let mut missile = Missile::nouveau_missile(position, 0.0);
+        missile.set_vitesse(vitesse);
+        let mut vaisseau = Spaceship::avec_position(position);
+        vaisseau.set_vitesse(vitesse);
+        (asteroid, missile, vaisseau)
+    }
+
+    /// Fait avancer le vaisseau par `maj_pos`, sans commande ni frottement, à la vitesse de simulation donnée.
+    fn avancer_vaisseau(vaisseau: &mut Spaceship, facteur: f32) {
+        let settings = Settings {
+            friction_vaisseau: 1.0,
+            vitesse_simulation: facteur,
+            ..Default::default()
+        };
+        vaisseau.maj_pos(&InputState::default(), &mut [], &settings, 0.0, ECRAN);
+    }
+
+    /// Chaque type d'objet, déplacé par sa propre méthode, avance de la même façon selon sa vitesse.
+    #[test]
+    fn test_advance_identique_pour_tous_les_types() {
+        let (mut asteroid, mut missile, mut vaisseau) =
+            objets_de_chaque_type(vec2(100.0, 100.0), vec2(2.0, -1.0));
+        for (facteur, attendue) in [(1.0, vec2(102.0, 99.0)), (0.5, vec2(103.0, 98.5))] {
+            asteroid.move_object(facteur, ECRAN);
+            missile.maj_pos_missile(facteur);
+            avancer_vaisseau(&mut vaisseau, facteur);
+            assert_eq!(asteroid.get_position(), attendue);
+            assert_eq!(missile.get_position(), attendue);
+            assert_eq!(vaisseau.get_position(), attendue);
+        }
+    }
+
+    /// Les astéroïdes et le vaisseau reviennent de l'autre côté de l'écran de la même façon
+    /// (les missiles, eux, disparaissent en sortant de l'écran).
+    #[test]
+    fn test_wrap_identique_pour_tous_les_types() {
+        let (mut asteroid, _, mut vaisseau) =
+            objets_de_chaque_type(vec2(798.0, 300.0), vec2(5.0, 0.0));
+        asteroid.move_object(1.0, ECRAN);
+        avancer_vaisseau(&mut vaisseau, 1.0);
+        assert_eq!(asteroid.get_position(), vec2(3.0, 300.0)); // Sorti à droite, il revient à gauche.
+        assert_eq!(vaisseau.get_position(), vec2(3.0, 300.0));
+
+        let (mut asteroid, _, mut vaisseau) =
+            objets_de_chaque_type(vec2(5.0, 598.0), vec2(0.0, 5.0));
+        asteroid.move_object(1.0, ECRAN);
+        avancer_vaisseau(&mut vaisseau, 1.0);
+        assert_eq!(asteroid.get_position(), vec2(5.0, 3.0)); // Sorti en bas, il revient en haut.
+        assert_eq!(vaisseau.get_position(), vec2(5.0, 3.0));
+
+        let (mut asteroid, _, mut vaisseau) =
+            objets_de_chaque_type(vec2(2.0, 300.0), vec2(-5.0, 0.0));
+        asteroid.move_object(1.0, ECRAN);
+        avancer_vaisseau(&mut vaisseau, 1.0);
+        assert_eq!(asteroid.get_position(), vec2(797.0, 300.0)); // Sorti à gauche, il revient à droite.
+        assert_eq!(vaisseau.get_position(), vec2(797.0, 300.0));
+
+        let (mut asteroid, _, mut vaisseau) =
+            objets_de_chaque_type(vec2(400.0, 2.0), vec2(0.0, -5.0));
+        asteroid.move_object(1.0, ECRAN);
+        avancer_vaisseau(&mut vaisseau, 1.0);
+        assert_eq!(asteroid.get_position(), vec2(400.0, 597.0)); // Sorti en haut, il revient en bas.
+        assert_eq!(vaisseau.get_position(), vec2(400.0, 597.0));
+    }
+
+    #[test]
+    fn test_direction_ou_defaut() {
+        assert_eq!(direction_ou_defaut(vec2(0.0, -3.0)), vec2(0.0, -1.0));
+        assert_eq!(direction_ou_defaut(Vec2::ZERO), DIRECTION_PAR_DEFAUT);
+    }
+
+    /// Un objet déjà à l'écran n'est pas déplacé par le rebouclage.
+    #[test]
+    fn test_wrap_dans_l_ecran() {
+        let mut transform = Transform::new(vec2(400.0, 300.0), Vec2::ZERO);
+        transform.wrap(800.0, 600.0);
+        assert_eq!(transform.position, vec2(400.0, 300.0));
+    }
+}
@@ -0,0 +1,134 @@
+//! Module pour le trou noir, un danger optionnel et spectaculaire.
+//! Ce module contient la structure `TrouNoir` : il attire les astéroïdes proches et le vaisseau,
+//! et engloutit les astéroïdes qui touchent son horizon des événements.
+
+use crate::stellarobject::StellarObject;
+use macroquad::prelude::*;
+
+/// Trou noir immobile qui attire les objets dans son rayon d'attraction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrouNoir {
+    position: Vec2,     // Centre du trou noir
+    force: f32,         // Accélération (en pixels par frame, à chaque frame) au bord de l'horizon
+    rayon_horizon: f32, // Rayon de l'horizon des événements : ce qui le touche est détruit
+}
+
+impl TrouNoir {
+    /// Au-delà de cette distance du centre, le trou noir n'attire plus rien.
+    pub const RAYON_ATTRACTION: f32 = 250.0;
+
+    /// Force d'attraction par défaut au bord de l'horizon.
+    pub const FORCE_DEFAUT: f32 = 0.3;
+
+    /// Rayon par défaut de l'horizon des événements.
+    pub const RAYON_HORIZON_DEFAUT: f32 = 25.0;
+
+    /// Crée un trou noir.
+    /// # Arguments
+    /// - `position` - Centre du trou noir.
+    /// - `force` - Accélération subie au bord de l'horizon (elle diminue avec le carré de la distance).
+    /// - `rayon_horizon` - Rayon de l'horizon des événements.
+    pub fn new(position: Vec2, force: f32, rayon_horizon: f32) -> Self {
+        Self {
+            position,
+            force,
+            rayon_horizon,
+        }
+    }
+
+    /// Calcule l'accélération subie par un objet à la position donnée, dirigée vers le centre du trou noir.
+    /// Elle diminue avec le carré de la distance, et s'annule au-delà de `RAYON_ATTRACTION`.
+    pub fn acceleration(&self, position: Vec2) -> Vec2 {
+        let vers_centre = self.position - position;
+        let distance = vers_centre.length();
+        if distance >= Self::RAYON_ATTRACTION {
+            return Vec2::ZERO;
+        }
+        // Dans l'horizon, l'attraction ne grandit plus (on évite une accélération infinie au centre).
+        let rapport = self.rayon_horizon / distance.max(self.rayon_horizon);
+        vers_centre.normalize_or_zero() * self.force * rapport * rapport
+    }
+
+    /// Accélère un objet vers le trou noir pendant une frame.
+    /// La nouvelle vitesse est donnée à `set_vitesse`, qui applique les limites propres à chaque type d'objet.
+    /// # Arguments
+    /// - `objet` - Objet attiré (astéroïde, missile, vaisseau...).
+    pub fn attirer(&self, objet: &mut dyn StellarObject) {
+        let attraction = self.acceleration(objet.get_position());
+        objet.set_vitesse(objet.get_vitesse() + attraction);
+    }
+
+    /// Indique si un objet circulaire touche l'horizon des événements.
+    /// # Arguments
+    /// - `position` - Centre de l'objet.
+    /// - `rayon` - Rayon de l'objet.
+    pub fn touche_horizon(&self, position: Vec2, rayon: f32) -> bool {
+        self.position.distance(position) < self.rayon_horizon + rayon
+    }
+
+    /// Dessine le trou noir : un disque noir entouré d'un halo violet qui marque sa zone d'attraction.
+    pub fn draw(&self) {
+        draw_circle(
+            self.position.x,
+            self.position.y,
+            Self::RAYON_ATTRACTION,
+            Color::new(0.4, 0.1, 0.6, 0.08),
+        );
+        draw_circle(self.position.x, self.position.y, self.rayon_horizon, BLACK);
+        draw_circle_lines(
+            self.position.x,
+            self.position.y,
+            self.rayon_horizon,
+            2.0,
+            VIOLET,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asteroid::Asteroid;
+
+    /// L'attraction pointe vers le centre, diminue avec la distance et disparaît hors du rayon d'attraction.
+    #[test]
+    fn test_attraction_diminue_avec_la_distance() {
+        let trou = TrouNoir::new(vec2(400.0, 300.0), 0.3, 25.0);
+        let proche = trou.acceleration(vec2(450.0, 300.0));
+        let loin = trou.acceleration(vec2(600.0, 300.0));
+        assert!(proche.x < 0.0 && proche.y.abs() < 1e-6); // Vers le centre
+        assert!(proche.length() > loin.length());
+        // Deux fois plus loin : quatre fois moins d'attraction.
+        assert!(
+            (proche.length() - 4.0 * trou.acceleration(vec2(500.0, 300.0)).length()).abs() < 1e-5
+        );
+        assert_eq!(
+            trou.acceleration(vec2(400.0, 300.0 + TrouNoir::RAYON_ATTRACTION)),
+            Vec2::ZERO
+        );
+        // Au bord de l'horizon, l'accélération vaut la force du trou noir.
+        assert!((trou.acceleration(vec2(425.0, 300.0)).length() - 0.3).abs() < 1e-5);
+    }
+
+    /// L'objet attiré gagne la vitesse de l'accélération subie à sa position.
+    #[test]
+    fn test_attirer() {
+        let trou = TrouNoir::new(vec2(400.0, 300.0), 0.3, 25.0);
+        let mut asteroid = Asteroid::scripté(2, vec2(450.0, 300.0), vec2(0.0, 1.0));
+        trou.attirer(&mut asteroid);
+        let attendue = vec2(0.0, 1.0) + trou.acceleration(vec2(450.0, 300.0));
+        assert!(asteroid.get_vitesse().distance(attendue) < 1e-6);
+
+        let mut loin = Asteroid::scripté(2, vec2(50.0, 50.0), vec2(0.0, 1.0));
+        trou.attirer(&mut loin);
+        assert_eq!(loin.get_vitesse(), vec2(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_horizon_des_evenements() {
+        let trou = TrouNoir::new(vec2(400.0, 300.0), 0.3, 25.0);
+        assert!(trou.touche_horizon(vec2(400.0, 300.0), 10.0));
+        assert!(trou.touche_horizon(vec2(430.0, 300.0), 10.0)); // 30 < 25 + 10
+        assert!(!trou.touche_horizon(vec2(440.0, 300.0), 10.0));
+    }
+}
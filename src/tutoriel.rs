@@ -0,0 +1,206 @@
+//! Module pour les astuces affichées aux nouveaux joueurs.
+//! Ce module contient la structure `Tutoriel`, qui affiche une astuce à la fois ("Flèches pour diriger",
+//! "Espace pour tirer"...) au moment où elle est utile, et retient les astuces déjà montrées pour ne plus
+//! les afficher lors des parties suivantes. La liste est enregistrée dans un fichier texte.
+//!
+//! Format du fichier : une ligne par astuce déjà montrée, par exemple `diriger`.
+
+use macroquad::prelude::*;
+
+/// Astuce affichée au joueur.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Astuce {
+    Diriger, // Au début de la première partie
+    Tirer,   // Juste après l'astuce pour diriger
+    Bonus,   // Quand un bonus de bouclier apparaît pour la première fois
+}
+
+impl Astuce {
+    /// Retourne le nom de l'astuce dans le fichier du tutoriel.
+    pub fn nom(&self) -> &'static str {
+        match self {
+            Self::Diriger => "diriger",
+            Self::Tirer => "tirer",
+            Self::Bonus => "bonus",
+        }
+    }
+
+    /// Retourne l'astuce correspondant à son nom dans le fichier du tutoriel, ou `None` si le nom est inconnu.
+    pub fn depuis_nom(nom: &str) -> Option<Self> {
+        match nom {
+            "diriger" => Some(Self::Diriger),
+            "tirer" => Some(Self::Tirer),
+            "bonus" => Some(Self::Bonus),
+            _ => None,
+        }
+    }
+
+    /// Retourne le texte affiché au joueur.
+    pub fn texte(&self) -> &'static str {
+        match self {
+            Self::Diriger => "Utilisez les flèches pour diriger le vaisseau",
+            Self::Tirer => "Appuyez sur Espace pour tirer",
+            Self::Bonus => "Attrapez le bouclier pour le recharger !",
+        }
+    }
+}
+
+/// Astuces déjà montrées et astuce affichée en ce moment.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Tutoriel {
+    vues: Vec<Astuce>, // Astuces déjà montrées (pendant cette partie ou une précédente)
+    courante: Option<Astuce>, // Astuce affichée en ce moment, jusqu'à ce que le joueur la ferme
+}
+
+impl Tutoriel {
+    /// Charge la liste des astuces déjà montrées depuis un fichier.
+    /// Si le fichier n'existe pas encore (premier lancement) ou est invalide, aucune astuce n'a été montrée.
+    /// # Arguments
+    /// - `chemin` : Chemin du fichier du tutoriel.
+    pub fn charger(chemin: &str) -> Self {
+        std::fs::read_to_string(chemin)
+            .map_err(|erreur| erreur.to_string())
+            .and_then(|texte| Self::analyser(&texte))
+            .unwrap_or_else(|erreur| {
+                if std::path::Path::new(chemin).exists() {
+                    eprintln!("Impossible de charger le tutoriel '{chemin}' : {erreur}");
+                }
+                Self::default()
+            })
+    }
+
+    /// Enregistre la liste des astuces déjà montrées dans un fichier.
+    /// # Arguments
+    /// - `chemin` : Chemin du fichier du tutoriel.
+    pub fn sauvegarder(&self, chemin: &str) -> std::io::Result<()> {
+        std::fs::write(chemin, self.en_texte())
+    }
+
+    /// Lit la liste des astuces déjà montrées au format du fichier du tutoriel.
+    /// # Retourne une erreur décrivant la première ligne invalide.
+    pub fn analyser(texte: &str) -> Result<Self, String> {
+        let mut tutoriel = Self::default();
+        for (numero, ligne) in texte.lines().enumerate() {
+            let ligne = ligne.trim();
+            if ligne.is_empty() {
+                continue;
+            }
+            let astuce = Astuce::depuis_nom(ligne)
+                .ok_or_else(|| format!("Ligne {} invalide : '{ligne}'.", numero + 1))?;
+            if !tutoriel.est_vue(astuce) {
+                tutoriel.vues.push(astuce);
+            }
+        }
+        Ok(tutoriel)
+    }
+
+    /// Retourne la liste des astuces déjà montrées au format du fichier du tutoriel.
+    pub fn en_texte(&self) -> String {
+        self.vues
+            .iter()
+            .map(|astuce| format!("{}\n", astuce.nom()))
+            .collect()
+    }
+
+    /// Indique si l'astuce a déjà été montrée.
+    pub fn est_vue(&self, astuce: Astuce) -> bool {
+        self.vues.contains(&astuce)
+    }
+
+    /// Retourne l'astuce affichée en ce moment.
+    pub fn courante(&self) -> Option<Astuce> {
+        self.courante
+    }
+
+    /// Affiche l'astuce si elle n'a encore jamais été montrée et qu'aucune autre n'est affichée.
+    /// L'astuce est aussitôt considérée comme vue : elle ne sera plus jamais proposée.
+    /// # Retourne `true` si l'astuce est affichée (la liste des astuces vues a alors changé).
+    pub fn proposer(&mut self, astuce: Astuce) -> bool {
+        if self.courante.is_some() || self.est_vue(astuce) {
+            return false;
+        }
+        self.vues.push(astuce);
+        self.courante = Some(astuce);
+        true
+    }
+
+    /// Ferme l'astuce affichée en ce moment.
+    pub fn fermer(&mut self) {
+        self.courante = None;
+    }
+
+    /// Dessine l'astuce affichée en ce moment (s'il y en a une) en bas de l'écran.
+    /// # Arguments
+    /// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+    pub fn draw(&self, ecran: Vec2) {
+        let Some(astuce) = self.courante() else {
+            return;
+        };
+        let texte = format!("{}  (Entrée pour fermer)", astuce.texte());
+        let largeur = measure_text(&texte, None, 24, 1.0).width;
+        let gauche = (ecran.x - largeur) / 2.0;
+        let haut = ecran.y - 60.0;
+        draw_rectangle(
+            gauche - 12.0,
+            haut - 24.0,
+            largeur + 24.0,
+            36.0,
+            Color::new(0.0, 0.0, 0.0, 0.7),
+        );
+        draw_text(&texte, gauche, haut, 24.0, YELLOW);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Une astuce montrée est marquée comme vue et n'est plus jamais proposée, même après avoir été fermée.
+    #[test]
+    fn test_astuce_montree_une_seule_fois() {
+        let mut tutoriel = Tutoriel::default();
+        assert!(tutoriel.proposer(Astuce::Diriger));
+        assert!(tutoriel.est_vue(Astuce::Diriger));
+        assert_eq!(tutoriel.courante(), Some(Astuce::Diriger));
+
+        // Une seule astuce à la fois.
+        assert!(!tutoriel.proposer(Astuce::Tirer));
+        assert!(!tutoriel.est_vue(Astuce::Tirer));
+
+        tutoriel.fermer();
+        assert_eq!(tutoriel.courante(), None);
+        assert!(!tutoriel.proposer(Astuce::Diriger));
+        assert_eq!(tutoriel.courante(), None);
+        assert!(tutoriel.proposer(Astuce::Tirer));
+    }
+
+    /// Les astuces vues sont retrouvées après un enregistrement puis un chargement.
+    #[test]
+    fn test_sauvegarde_et_chargement() {
+        let mut tutoriel = Tutoriel::default();
+        tutoriel.proposer(Astuce::Diriger);
+        tutoriel.fermer();
+        tutoriel.proposer(Astuce::Bonus);
+
+        let chemin = std::env::temp_dir().join(format!("tutoriel_test_{}.txt", std::process::id()));
+        let chemin = chemin.to_str().unwrap();
+        tutoriel.sauvegarder(chemin).unwrap();
+        let charge = Tutoriel::charger(chemin);
+        std::fs::remove_file(chemin).unwrap();
+
+        assert!(charge.est_vue(Astuce::Diriger));
+        assert!(charge.est_vue(Astuce::Bonus));
+        assert!(!charge.est_vue(Astuce::Tirer));
+        assert_eq!(charge.courante(), None); // Rien n'est affiché au chargement.
+        assert_eq!(charge.en_texte(), "diriger\nbonus\n");
+    }
+
+    #[test]
+    fn test_fichier_invalide() {
+        assert!(Tutoriel::analyser("diriger\nsauter\n").is_err());
+        assert_eq!(
+            Tutoriel::charger("fichier/inexistant.txt"),
+            Tutoriel::default()
+        );
+    }
+}
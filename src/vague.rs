@@ -0,0 +1,190 @@
+//! Module pour les vagues d'astéroïdes définies à l'avance.
+//! Ce module contient la structure `Vague`, qui décrit la composition d'un niveau (taille de chaque astéroïde,
+//! et s'il est blindé, éventuellement la formation dans laquelle ils apparaissent, et les ennemis du niveau :
+//! boss et ovnis), ainsi que la lecture d'un fichier de vagues (option `--waves`).
+//! Les niveaux qui ne sont pas décrits dans le fichier gardent la vague générée au hasard.
+
+use crate::asteroid::{Asteroid, MAX_ASTEROIDS};
+use crate::formation::Formation;
+use ::rand::Rng;
+use macroquad::prelude::*;
+
+/// Astéroïde d'une vague : sa taille et s'il est blindé.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AsteroideVague {
+    pub taille: u8,   // 1 = petit, 2 = moyen, 3 = grand
+    pub blinde: bool, // Un astéroïde blindé renvoie les missiles, sauf sur son point faible
+}
+
+/// Composition de la vague d'un niveau.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vague {
+    pub niveau: i32,                     // Niveau concerné
+    pub asteroides: Vec<AsteroideVague>, // Astéroïdes qui apparaissent au début du niveau
+    pub formation: Option<Formation>, // Formation des astéroïdes (`None` = placés au hasard près des bords)
+    pub boss: bool,                   // Un boss apparaît-il au début du niveau ?
+    pub ovnis: u8,                    // Nombre d'ovnis qui traversent l'écran pendant le niveau
+}
+
+impl Vague {
+    /// Génère les astéroïdes de la vague, avec une position (près d'un bord) et une vitesse aléatoires,
+    /// ou dans la formation de la vague s'il y en a une.
+    /// Au plus `MAX_ASTEROIDS` astéroïdes sont créés.
+    /// # Arguments
+    /// - `rng` : Générateur aléatoire.
+    /// - `ecran` : Dimensions de l'écran (largeur, hauteur).
+    pub fn generer(&self, rng: &mut impl Rng, ecran: Vec2) -> Vec<Asteroid> {
+        if let Some(formation) = self.formation {
+            let descriptions = &self.asteroides[..self.asteroides.len().min(MAX_ASTEROIDS)];
+            let tailles: Vec<u8> = descriptions.iter().map(|d| d.taille).collect();
+            let mut asteroids = formation.generer(&tailles, rng, ecran);
+            for (asteroid, description) in asteroids.iter_mut().zip(descriptions) {
+                asteroid.set_blinde(description.blinde);
+            }
+            return asteroids;
+        }
+        self.asteroides
+            .iter()
+            .take(MAX_ASTEROIDS)
+            .map(|description| {
+                let mut asteroid = Asteroid::de_taille_dans(rng, description.taille, ecran);
+                asteroid.set_blinde(description.blinde);
+                asteroid
+            })
+            .collect()
+    }
+}
+
+/// Retourne la vague définie pour le niveau donné, s'il y en a une.
+pub fn vague_niveau(vagues: &[Vague], niveau: i32) -> Option<&Vague> {
+    vagues.iter().find(|vague| vague.niveau == niveau)
+}
+
+/// Lit des vagues écrites sous forme de texte.
+/// Chaque ligne décrit un niveau : `niveau: tailles...`, par exemple `3: 3 3 2B 1` (B = blindé).
+/// Les mots `boss` et `ovni` ajoutent un boss ou un ovni au niveau : `5: 3 3 ovni ovni boss`.
+/// Une formation peut suivre, après une barre : `4: 2 2 2 2 2 | cercle` (`ligne`, `v` ou `cercle`).
+/// Les lignes vides et celles commençant par `#` sont ignorées.
+/// # Retourne une erreur indiquant la ligne fautive si une ligne est mal formée.
+pub fn analyser_vagues(texte: &str) -> Result<Vec<Vague>, String> {
+    texte
+        .lines()
+        .enumerate()
+        .map(|(numero, ligne)| (numero + 1, ligne.trim()))
+        .filter(|(_, ligne)| !ligne.is_empty() && !ligne.starts_with('#'))
+        .map(|(numero, ligne)| {
+            let erreur =
+                || format!("Ligne {numero} invalide : '{ligne}' (attendu : niveau: tailles...).");
+            let (niveau, reste) = ligne.split_once(':').ok_or_else(erreur)?;
+            let niveau: i32 = niveau.trim().parse().map_err(|_| erreur())?;
+            let (asteroides, formation) = match reste.split_once('|') {
+                Some((asteroides, nom)) => (
+                    asteroides,
+                    Some(Formation::depuis_nom(nom.trim()).ok_or_else(erreur)?),
+                ),
+                None => (reste, None),
+            };
+            let mut vague = Vague {
+                niveau,
+                asteroides: Vec::new(),
+                formation,
+                boss: false,
+                ovnis: 0,
+            };
+            for champ in asteroides.split_whitespace() {
+                match champ {
+                    "boss" => vague.boss = true,
+                    "ovni" => vague.ovnis = vague.ovnis.saturating_add(1),
+                    _ => {
+                        let (taille, blinde) = match champ.strip_suffix('B') {
+                            Some(taille) => (taille, true),
+                            None => (champ, false),
+                        };
+                        match taille.parse::<u8>() {
+                            Ok(taille @ 1..=3) => {
+                                vague.asteroides.push(AsteroideVague { taille, blinde })
+                            }
+                            _ => return Err(erreur()),
+                        }
+                    }
+                }
+            }
+            Ok(vague)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::rand::rngs::SmallRng;
+    use ::rand::SeedableRng;
+
+    const ECRAN: Vec2 = vec2(800.0, 600.0);
+
+    /// Le fichier de vagues fourni avec le jeu est valide.
+    #[test]
+    fn test_fichier_vagues() {
+        let vagues = analyser_vagues(include_str!("../ressources/vagues.txt")).unwrap();
+        assert_eq!(vagues.len(), 3);
+        assert!(vague_niveau(&vagues, 3).is_some());
+        assert!(vague_niveau(&vagues, 4).is_none());
+    }
+
+    /// Une vague lue dans un texte génère exactement la composition demandée pour son niveau.
+    #[test]
+    fn test_composition_vague() {
+        let vagues = analyser_vagues("# commentaire\n\n2: 3 2B 1 1\n").unwrap();
+        let vague = vague_niveau(&vagues, 2).unwrap();
+        let asteroids = vague.generer(&mut SmallRng::seed_from_u64(0), ECRAN);
+
+        let composition: Vec<(u8, bool)> = asteroids
+            .iter()
+            .map(|a| (a.get_taille(), a.est_blinde()))
+            .collect();
+        assert_eq!(
+            composition,
+            vec![(3, false), (2, true), (1, false), (1, false)]
+        );
+    }
+
+    #[test]
+    fn test_vagues_invalides() {
+        assert!(analyser_vagues("3 3 2").is_err()); // Pas de niveau
+        assert!(analyser_vagues("x: 3 3").is_err());
+        assert!(analyser_vagues("1: 3 4").is_err()); // Taille hors limites
+        assert!(analyser_vagues("1: 3 2X").is_err());
+        assert!(analyser_vagues("1: 3 2 | carre").is_err()); // Formation inconnue
+        assert!(analyser_vagues("1: 3 ufo").is_err()); // Ennemi inconnu
+    }
+
+    /// Les mots `boss` et `ovni` ajoutent des ennemis au niveau, sans changer ses astéroïdes.
+    #[test]
+    fn test_ennemis_vague() {
+        let vagues = analyser_vagues("5: 3 ovni 2B ovni boss | ligne\n6: 3").unwrap();
+        assert!(vagues[0].boss);
+        assert_eq!(vagues[0].ovnis, 2);
+        assert_eq!(vagues[0].asteroides.len(), 2);
+        assert_eq!(vagues[0].formation, Some(Formation::Ligne));
+        assert!(!vagues[1].boss);
+        assert_eq!(vagues[1].ovnis, 0);
+    }
+
+    /// Une vague avec une formation place ses astéroïdes dans cette formation, avec la même vitesse.
+    #[test]
+    fn test_vague_en_formation() {
+        let vagues = analyser_vagues("4: 2 2B 2 2 2 | cercle").unwrap();
+        assert_eq!(vagues[0].formation, Some(Formation::Cercle));
+        let asteroids = vagues[0].generer(&mut SmallRng::seed_from_u64(0), ECRAN);
+        assert_eq!(asteroids.len(), 5);
+        assert!(asteroids[1].est_blinde() && !asteroids[0].est_blinde());
+        let distances: Vec<f32> = asteroids
+            .iter()
+            .map(|a| a.get_position().distance(ECRAN / 2.0))
+            .collect();
+        assert!(distances.iter().all(|d| (d - distances[0]).abs() < 1e-3));
+        assert!(asteroids
+            .iter()
+            .all(|a| a.get_vitesse() == asteroids[0].get_vitesse()));
+    }
+}
@@ -0,0 +1,1746 @@
+//! Module qui regroupe l'état d'une partie.
+//! Ce module contient la structure `World` : les astéroïdes, les missiles, le vaisseau, le bonus,
+//! le niveau et les vies, ainsi que le générateur aléatoire utilisé pour toutes les apparitions.
+//! À graine égale, deux mondes génèrent exactement les mêmes astéroïdes et bonus, ce qui permet
+//! de rejouer une partie à l'identique (option `--seed`).
+
+use crate::asteroid::{
+    ajouter_asteroides, generer_asteroides, nombre_asteroides_niveau, Asteroid, MAX_ASTEROIDS,
+};
+use crate::barriere::Barriere;
+use crate::bonus::{GestionnaireBonus, DUREE_AIMANT};
+use crate::boss::{boss_niveau, Boss};
+use crate::boutique::{Amelioration, Ameliorations};
+use crate::cluster::{cluster_niveau, ClusterAsteroide, Impact};
+use crate::debris::NuageDebris;
+use crate::horde::ModeHorde;
+use crate::input::InputState;
+use crate::missile::Missile;
+use crate::ovni::Ovni;
+use crate::scores::{TableScores, NOM_ANONYME};
+use crate::settings::Settings;
+use crate::simulation::{gerer_collisions_missiles, update_model, Deploiements};
+use crate::spaceship::Spaceship;
+use crate::statistiques::Statistiques;
+use crate::stellarobject::{avancer_tous, StellarObject};
+use crate::transform::direction_ou_defaut;
+use crate::trou_noir::TrouNoir;
+use crate::vague::{vague_niveau, Vague};
+use crate::zone::{zones_niveau, Zone, ZoneRalentissement};
+use ::rand::rngs::SmallRng;
+use ::rand::{Rng, SeedableRng};
+use macroquad::prelude::*;
+
+/// Structure représentant l'état d'une partie en cours.
+pub struct World {
+    pub asteroids: Vec<Asteroid>, // Astéroïdes présents à l'écran
+    pub missiles: Vec<Missile>,   // Missiles présents à l'écran
+    pub vaisseau: Spaceship,      // Vaisseau du joueur
+    pub bonus: GestionnaireBonus, // Bonus de bouclier présents à l'écran
+    pub zones: Vec<Zone>,         // Zones dangereuses (nébuleuses) du niveau actuel
+    pub zones_ralentissement: Vec<ZoneRalentissement>, // Champs de ralentissement déployés par le joueur
+    pub barrieres: Vec<Barriere>,                      // Barrières déployées par le joueur
+    pub trou_noir: Option<TrouNoir>, // Trou noir, s'il est activé dans les paramètres
+    pub nuages: Vec<NuageDebris>,    // Nuages de débris laissés par les grands astéroïdes détruits
+    pub boss: Option<Boss>,          // Boss du niveau actuel, s'il y en a un
+    pub ovnis: Vec<Ovni>,            // Ovnis qui traversent l'écran pendant le niveau actuel
+    pub missiles_ennemis: Vec<Missile>, // Missiles tirés par le boss et les ovnis
+    pub cluster: Option<ClusterAsteroide>, // Cluster protégé par des orbiteurs du niveau actuel, s'il y en a un
+    pub horde: Option<ModeHorde>,          // Minuteur des apparitions, en mode horde
+    pub niveau: i32,                       // Niveau actuel
+    pub vies: u8,                          // Nombre de vies restantes
+    pub rng: SmallRng,                     // Générateur aléatoire de la partie
+    pub rng_effets: SmallRng, // Générateur des effets visuels, séparé pour que les effets ne changent pas la partie
+    graine: u64,              // Graine de départ du générateur, pour rejouer la même partie
+    hitstop_frames: u32,      // Nombre de frames pendant lesquelles le monde reste figé
+    compte_a_rebours: f32, // Temps restant (en secondes) avant que les astéroïdes se mettent en mouvement
+    combo: u32,            // Multiplicateur de combo actuel
+    score: u32,            // Points gagnés depuis le début de la partie
+    inactivite: f32,       // Temps (en secondes) depuis la dernière destruction d'astéroïde
+    destructions: Vec<(Vec2, u8)>, // Position et taille des astéroïdes détruits depuis le dernier `prendre_destructions`
+    decroissance: f32, // Points perdus par la décroissance du score, pas encore retirés (moins d'un point)
+    vagues: Vec<Vague>, // Vagues définies à l'avance pour certains niveaux
+    ovnis_a_venir: u8, // Nombre d'ovnis du niveau actuel qui ne sont pas encore apparus
+    minuteur_ovni: f32, // Temps (en secondes) avant l'apparition du prochain ovni
+    fichier_scores: String, // Fichier du tableau des meilleurs scores, mis à jour quand le joueur quitte
+    charges_ralentissement: u8, // Nombre de champs de ralentissement que le joueur peut encore déployer
+    charges_barriere: u8,       // Nombre de barrières que le joueur peut encore déployer
+    aimant_restant: f32, // Temps restant (en secondes) de l'effet du bonus aimant (0 = inactif)
+    statistiques: Statistiques, // Tirs, impacts, destructions et temps de survie de la partie en cours
+    degats_niveau: bool, // Le vaisseau a-t-il subi des dégâts depuis le début du niveau ? (bonus de vague parfaite)
+    ameliorations: Ameliorations, // Améliorations achetées à la boutique pendant la partie
+    boutique_ouverte: bool, // La boutique est-elle ouverte (entre deux vagues) ?
+}
+
+impl World {
+    /// Multiplicateur de combo de départ, rétabli quand le vaisseau est touché.
+    pub const COMBO_BASE: u32 = 1;
+
+    /// Multiplicateur de combo maximal.
+    pub const COMBO_MAX: u32 = 8;
+
+    /// Points gagnés pour chaque astéroïde détruit, multipliés par le combo.
+    pub const POINTS_ASTEROIDE: u32 = 100;
+
+    /// Points gagnés en terminant un niveau sans que le vaisseau ait subi de dégâts (vague parfaite).
+    pub const POINTS_VAGUE_PARFAITE: u32 = 500;
+
+    /// Durée (en secondes) du compte à rebours : "3", "2", "1" pendant une seconde chacun, puis "GO!".
+    pub const DUREE_COMPTE_A_REBOURS: f32 = 3.5;
+
+    /// Temps (en secondes) sans destruction avant que le score commence à baisser (contre-la-montre).
+    pub const DELAI_DECROISSANCE: f32 = 2.0;
+
+    /// Rayon (en pixels) autour du vaisseau dans lequel une bombe détruit les astéroïdes.
+    pub const RAYON_BOMBE: f32 = 200.0;
+
+    /// Angle maximal (en radians) dont un missile téléguidé tourne vers sa cible à chaque frame.
+    pub const ANGLE_TELEGUIDAGE: f32 = 0.05;
+
+    /// Crée une nouvelle partie.
+    /// # Arguments
+    /// - `settings` : Paramètres du jeu.
+    /// - `graine` : Graine du générateur aléatoire. Si elle n'est pas donnée, une graine est tirée au sort.
+    /// - `ecran` : Dimensions de l'écran (largeur, hauteur).
+    pub fn new(settings: &Settings, graine: Option<u64>, ecran: Vec2) -> Self {
+        let graine = graine.unwrap_or_else(|| ::rand::thread_rng().gen());
+        let mut rng = SmallRng::seed_from_u64(graine);
+        let asteroids = generer_asteroides(&mut rng, settings.asteroides_initiaux, ecran);
+        let bonus = GestionnaireBonus::new(&mut rng, ecran, ecran / 2.0);
+
+        Self {
+            asteroids,
+            missiles: Vec::new(),
+            vaisseau: nouveau_vaisseau(settings, ecran), // Au départ il est centré sur l'écran
+            bonus,
+            zones: zones_niveau(1, settings.intervalle_nebuleuse, ecran),
+            zones_ralentissement: Vec::new(),
+            barrieres: Vec::new(),
+            // Le trou noir est placé à droite de l'écran, loin du vaisseau qui part du centre.
+            trou_noir: settings.trou_noir.then(|| {
+                TrouNoir::new(
+                    vec2(ecran.x * 0.8, ecran.y / 2.0),
+                    TrouNoir::FORCE_DEFAUT,
+                    TrouNoir::RAYON_HORIZON_DEFAUT,
+                )
+            }),
+            horde: settings.mode_horde.then(ModeHorde::default),
+            nuages: Vec::new(),
+            boss: boss_niveau(1, settings.intervalle_boss, ecran),
+            ovnis: Vec::new(),
+            missiles_ennemis: Vec::new(),
+            cluster: cluster_niveau(1, settings.intervalle_cluster, ecran),
+            niveau: 1,
+            vies: settings.vies_initiales,
+            rng,
+            rng_effets: generateur_effets(graine),
+            graine,
+            hitstop_frames: 0,
+            compte_a_rebours: duree_compte_a_rebours(settings),
+            combo: Self::COMBO_BASE,
+            score: 0,
+            inactivite: 0.0,
+            decroissance: 0.0,
+            destructions: Vec::new(),
+            vagues: Vec::new(),
+            ovnis_a_venir: 0,
+            minuteur_ovni: 0.0,
+            fichier_scores: settings.fichier_scores.clone(),
+            charges_ralentissement: settings.charges_ralentissement,
+            charges_barriere: settings.charges_barriere,
+            aimant_restant: 0.0,
+            statistiques: Statistiques::default(),
+            degats_niveau: false,
+            ameliorations: Ameliorations::default(),
+            boutique_ouverte: false,
+        }
+    }
+
+    /// Recommence une partie depuis le début : nouvelle vague de départ, nouveau vaisseau, niveau 1.
+    pub fn nouvelle_partie(&mut self, settings: &Settings, ecran: Vec2) {
+        // Avec une graine fixe, le générateur repart de zéro : les astéroïdes sont les mêmes qu'au début.
+        if settings.graine_fixe {
+            self.rng = SmallRng::seed_from_u64(self.graine);
+            self.rng_effets = generateur_effets(self.graine);
+        }
+        self.niveau = 1;
+        self.asteroids = self.generer_vague(settings, ecran);
+        self.vaisseau = nouveau_vaisseau(settings, ecran);
+        self.missiles.clear();
+        // Un bonus visible au moment de la mort ne doit pas rester dans la nouvelle partie.
+        self.bonus.reinitialiser(&mut self.rng, ecran, ecran / 2.0);
+        self.zones = zones_niveau(1, settings.intervalle_nebuleuse, ecran);
+        self.nuages.clear();
+        self.preparer_ennemis(settings, ecran);
+        self.cluster = cluster_niveau(1, settings.intervalle_cluster, ecran);
+        self.zones_ralentissement.clear();
+        self.charges_ralentissement = settings.charges_ralentissement;
+        self.barrieres.clear();
+        self.charges_barriere = settings.charges_barriere;
+        self.aimant_restant = 0.0;
+        self.statistiques = Statistiques::default();
+        self.degats_niveau = false;
+        self.ameliorations = Ameliorations::default();
+        self.boutique_ouverte = false;
+        self.vies = settings.vies_initiales;
+        self.horde = settings.mode_horde.then(ModeHorde::default);
+        self.combo = Self::COMBO_BASE;
+        self.score = 0;
+        self.inactivite = 0.0;
+        self.decroissance = 0.0;
+        self.compte_a_rebours = duree_compte_a_rebours(settings);
+    }
+
+    /// Passe au niveau suivant : une vague plus grande apparaît et le vaisseau est recentré.
+    /// Premier niveau = 5 astéroïdes, ensuite 1 astéroïde de plus à chaque niveau.
+    pub fn niveau_suivant(&mut self, settings: &Settings, ecran: Vec2) {
+        self.niveau += 1;
+        self.vies = vies_apres_niveau(self.vies, self.niveau, settings); // Une vie en bonus tous les quelques niveaux.
+        self.asteroids = self.generer_vague(settings, ecran);
+        self.zones = zones_niveau(self.niveau, settings.intervalle_nebuleuse, ecran);
+        self.nuages.clear();
+        self.preparer_ennemis(settings, ecran);
+        self.cluster = cluster_niveau(self.niveau, settings.intervalle_cluster, ecran);
+        // On recentre le vaisseau et on enlève tous les missiles qui avaient été tirés avant.
+        self.vaisseau.recentrer_sur(ecran / 2.0);
+        self.missiles.clear();
+        self.degats_niveau = false;
+        self.compte_a_rebours = duree_compte_a_rebours(settings);
+    }
+
+    /// Passe directement au niveau donné (console de développement), sans vie bonus.
+    /// La vague de ce niveau apparaît et le vaisseau est recentré, comme au passage d'un niveau.
+    pub fn aller_au_niveau(&mut self, niveau: i32, settings: &Settings, ecran: Vec2) {
+        self.niveau = niveau;
+        self.asteroids = self.generer_vague(settings, ecran);
+        self.zones = zones_niveau(self.niveau, settings.intervalle_nebuleuse, ecran);
+        self.nuages.clear();
+        self.preparer_ennemis(settings, ecran);
+        self.cluster = cluster_niveau(self.niveau, settings.intervalle_cluster, ecran);
+        self.vaisseau.recentrer_sur(ecran / 2.0);
+        self.missiles.clear();
+        self.degats_niveau = false;
+        self.compte_a_rebours = duree_compte_a_rebours(settings);
+    }
+
+    /// Ajoute des astéroïdes aléatoires près des bords de l'écran, dans la limite du nombre d'astéroïdes autorisé.
+    /// # Retourne le nombre d'astéroïdes réellement ajoutés.
+    pub fn ajouter_asteroides_aleatoires(&mut self, nombre: usize, ecran: Vec2) -> usize {
+        // On ne crée pas plus d'astéroïdes qu'il n'y a de place (la console accepte des nombres énormes).
+        let place = MAX_ASTEROIDS.saturating_sub(self.asteroids.len());
+        let nouveaux: Vec<Asteroid> = (0..nombre.min(place))
+            .map(|_| Asteroid::nouveau_dans(&mut self.rng, ecran))
+            .collect();
+        ajouter_asteroides(&mut self.asteroids, nouveaux)
+    }
+
+    /// Recommence la vague du niveau actuel avec de nouveaux astéroïdes, sans changer le niveau ni les vies.
+    /// Les missiles en vol disparaissent.
+    pub fn rejouer_vague(&mut self, settings: &Settings, ecran: Vec2) {
+        self.asteroids = self.generer_vague(settings, ecran);
+        self.missiles.clear();
+    }
+
+    /// Utilise les vagues données pour les niveaux qu'elles décrivent, et recommence la vague actuelle en conséquence.
+    pub fn charger_vagues(&mut self, vagues: Vec<Vague>, settings: &Settings, ecran: Vec2) {
+        self.vagues = vagues;
+        self.rejouer_vague(settings, ecran);
+        self.preparer_ennemis(settings, ecran);
+    }
+
+    /// Remplace les astéroïdes de la vague actuelle par ceux d'une disposition (option `--layout`),
+    /// dans la limite de `MAX_ASTEROIDS` (les astéroïdes en trop sont ignorés).
+    pub fn charger_disposition(&mut self, asteroids: Vec<Asteroid>) {
+        self.asteroids.clear();
+        ajouter_asteroides(&mut self.asteroids, asteroids);
+    }
+
+    /// Prépare les ennemis du niveau actuel : le boss, si la vague de ce niveau en demande un ou si c'est un niveau
+    /// à boss d'après les paramètres, et les ovnis de la vague, qui apparaîtront un par un pendant le niveau.
+    /// Les ennemis et les missiles ennemis du niveau précédent disparaissent.
+    fn preparer_ennemis(&mut self, settings: &Settings, ecran: Vec2) {
+        let vague = vague_niveau(&self.vagues, self.niveau);
+        self.boss = match vague {
+            Some(vague) if vague.boss => Some(Boss::apparition(ecran)),
+            _ => boss_niveau(self.niveau, settings.intervalle_boss, ecran),
+        };
+        self.ovnis.clear();
+        self.ovnis_a_venir = vague.map_or(0, |vague| vague.ovnis);
+        self.minuteur_ovni = 0.0; // Le premier ovni apparaît dès la fin du compte à rebours.
+        self.missiles_ennemis.clear();
+    }
+
+    /// Génère les astéroïdes du niveau actuel : la vague définie pour ce niveau s'il y en a une,
+    /// sinon une vague aléatoire (nombre défini dans les paramètres au niveau 1, puis 4 + niveau).
+    fn generer_vague(&mut self, settings: &Settings, ecran: Vec2) -> Vec<Asteroid> {
+        if let Some(vague) = vague_niveau(&self.vagues, self.niveau) {
+            return vague.generer(&mut self.rng, ecran);
+        }
+        let nombre = if self.niveau == 1 {
+            settings.asteroides_initiaux
+        } else {
+            nombre_asteroides_niveau(self.niveau)
+        };
+        generer_asteroides(&mut self.rng, nombre, ecran)
+    }
+
+    /// Passe directement au niveau suivant en détruisant tous les astéroïdes restants (debug).
+    pub fn passer_niveau(&mut self, settings: &Settings, ecran: Vec2) {
+        self.asteroids.clear();
+        self.niveau_suivant(settings, ecran);
+    }
+
+    /// Fige les astéroïdes et les missiles pendant quelques frames pour marquer un impact (0 = pas de hitstop).
+    /// Un nouveau hitstop ne raccourcit pas celui en cours.
+    pub fn declencher_hitstop(&mut self, frames: u32) {
+        self.hitstop_frames = self.hitstop_frames.max(frames);
+    }
+
+    /// À appeler une fois par frame : indique si le monde est figé pendant cette frame.
+    pub fn avancer_hitstop(&mut self) -> bool {
+        if self.hitstop_frames > 0 {
+            self.hitstop_frames -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// À appeler une fois par frame : fait avancer le compte à rebours du début de vague,
+    /// et indique si les astéroïdes doivent rester immobiles pendant cette frame.
+    /// # Arguments
+    /// - `delta_time` : Temps écoulé (en secondes) depuis la dernière frame.
+    pub fn avancer_compte_a_rebours(&mut self, delta_time: f32) -> bool {
+        let actif = self.compte_a_rebours > 0.0;
+        self.compte_a_rebours = (self.compte_a_rebours - delta_time).max(0.0);
+        actif
+    }
+
+    /// Retourne le texte du compte à rebours à afficher ("3", "2", "1" puis "GO!"), ou `None` s'il est terminé.
+    pub fn texte_compte_a_rebours(&self) -> Option<String> {
+        let restant = self.compte_a_rebours - (Self::DUREE_COMPTE_A_REBOURS - 3.0);
+        if self.compte_a_rebours <= 0.0 {
+            None
+        } else if restant > 0.0 {
+            Some((restant.ceil() as u32).to_string())
+        } else {
+            Some("GO!".to_string())
+        }
+    }
+
+    /// En mode horde, fait avancer le minuteur et fait arriver un astéroïde par un bord quand il le faut.
+    /// Ne fait rien en dehors du mode horde.
+    /// # Arguments
+    /// - `delta_time` : Temps écoulé (en secondes) depuis la dernière frame.
+    /// - `ecran` : Dimensions de l'écran (largeur, hauteur).
+    pub fn avancer_horde(&mut self, delta_time: f32, ecran: Vec2) {
+        if let Some(horde) = &mut self.horde {
+            if horde.avancer(delta_time, self.asteroids.len()) {
+                let nouveau = Asteroid::new_hors_ecran_vers_interieur(&mut self.rng, ecran);
+                ajouter_asteroides(&mut self.asteroids, [nouveau]);
+            }
+        }
+    }
+
+    /// Retourne le nombre de champs de ralentissement que le joueur peut encore déployer.
+    pub fn get_charges_ralentissement(&self) -> u8 {
+        self.charges_ralentissement
+    }
+
+    /// Déploie un champ de ralentissement à la position du vaisseau, s'il reste des charges.
+    /// # Retourne `true` si le champ a été déployé.
+    pub fn deployer_ralentissement(&mut self) -> bool {
+        if self.charges_ralentissement == 0 {
+            return false;
+        }
+        self.charges_ralentissement -= 1;
+        self.zones_ralentissement
+            .push(ZoneRalentissement::deployer(self.vaisseau.get_position()));
+        true
+    }
+
+    /// Retourne le nombre de barrières que le joueur peut encore déployer.
+    pub fn get_charges_barriere(&self) -> u8 {
+        self.charges_barriere
+    }
+
+    /// Déploie une barrière devant le vaisseau, s'il reste des charges.
+    /// # Retourne `true` si la barrière a été déployée.
+    pub fn deployer_barriere(&mut self) -> bool {
+        if self.charges_barriere == 0 {
+            return false;
+        }
+        self.charges_barriere -= 1;
+        self.barrieres.push(Barriere::deployer(
+            self.vaisseau.get_position(),
+            self.vaisseau.get_rotation(),
+        ));
+        true
+    }
+
+    /// Indique si la boutique est ouverte : la vague est terminée, et la suivante attend que le joueur ferme la boutique.
+    pub fn boutique_ouverte(&self) -> bool {
+        self.boutique_ouverte
+    }
+
+    /// Ferme la boutique : la vague suivante peut commencer.
+    pub fn fermer_boutique(&mut self) {
+        self.boutique_ouverte = false;
+    }
+
+    /// Retourne les améliorations achetées pendant la partie.
+    pub fn get_ameliorations(&self) -> &Ameliorations {
+        &self.ameliorations
+    }
+
+    /// Achète une amélioration avec le score de la partie (voir `Ameliorations::acheter`).
+    /// Un bouclier renforcé est appliqué au vaisseau et rempli aussitôt.
+    /// # Retourne `true` si l'achat a eu lieu.
+    pub fn acheter(&mut self, amelioration: Amelioration) -> bool {
+        if !self.ameliorations.acheter(&mut self.score, amelioration) {
+            return false;
+        }
+        if amelioration == Amelioration::BouclierRenforce {
+            self.vaisseau
+                .set_bonus_bouclier(self.ameliorations.bonus_bouclier_max);
+            self.vaisseau.restaurer_bouclier();
+        }
+        true
+    }
+
+    /// Fait exploser une bombe, s'il en reste : les astéroïdes à moins de `RAYON_BOMBE` du vaisseau sont détruits
+    /// sans se fragmenter, et comptent comme des astéroïdes ordinaires (points, combo et explosion).
+    /// # Retourne le nombre d'astéroïdes détruits.
+    pub fn utiliser_bombe(&mut self) -> usize {
+        if !self.ameliorations.utiliser_bombe() {
+            return 0;
+        }
+        let position = self.vaisseau.get_position();
+        let mut detruits = Vec::new();
+        self.asteroids.retain(|asteroid| {
+            let touche = asteroid.get_position().distance(position)
+                < Self::RAYON_BOMBE + asteroid.rayon_asteroid();
+            if touche {
+                detruits.push((asteroid.get_position(), asteroid.get_taille()));
+            }
+            !touche
+        });
+        let nombre = detruits.len();
+        self.enregistrer_destructions(nombre);
+        self.destructions.extend(detruits);
+        nombre
+    }
+
+    /// Retourne les statistiques de la partie en cours, avec le score et le niveau actuels.
+    pub fn statistiques(&self) -> Statistiques {
+        Statistiques {
+            score: self.score,
+            niveau: self.niveau,
+            ..self.statistiques.clone()
+        }
+    }
+
+    /// Indique si l'effet d'un bonus aimant est en cours.
+    pub fn aimant_actif(&self) -> bool {
+        self.aimant_restant > 0.0
+    }
+
+    /// Déclenche l'effet de l'aimant pendant `DUREE_AIMANT` secondes (un nouvel aimant relance la durée).
+    pub fn activer_aimant(&mut self) {
+        self.aimant_restant = DUREE_AIMANT;
+    }
+
+    /// Retourne le multiplicateur de combo actuel.
+    pub fn get_combo(&self) -> u32 {
+        self.combo
+    }
+
+    /// Retourne le score de la partie.
+    pub fn get_score(&self) -> u32 {
+        self.score
+    }
+
+    /// Ajoute les points des astéroïdes détruits (multipliés par le combo actuel),
+    /// puis augmente le combo d'un cran par astéroïde détruit, sans dépasser `COMBO_MAX`.
+    pub fn enregistrer_destructions(&mut self, detruits: usize) {
+        self.statistiques.asteroides_detruits += detruits as u32;
+        self.score += detruits as u32 * Self::POINTS_ASTEROIDE * self.combo;
+        self.combo = (self.combo + detruits as u32).min(Self::COMBO_MAX);
+        if detruits > 0 {
+            // Détruire un astéroïde remet à zéro le temps d'inactivité : le score cesse de baisser.
+            self.inactivite = 0.0;
+            self.decroissance = 0.0;
+        }
+    }
+
+    /// Contre-la-montre : après `DELAI_DECROISSANCE` secondes sans destruction, le score baisse de `taux` points
+    /// par seconde, sans descendre sous zéro.
+    /// # Arguments
+    /// - `delta_time` : Temps écoulé (en secondes) depuis la dernière frame.
+    /// - `taux` : Points perdus par seconde (0.0 = pas de décroissance).
+    pub fn avancer_decroissance_score(&mut self, delta_time: f32, taux: f32) {
+        self.inactivite += delta_time;
+        if taux <= 0.0 || self.inactivite <= Self::DELAI_DECROISSANCE {
+            return;
+        }
+        self.decroissance += taux * delta_time;
+        let retrait = self.decroissance.floor();
+        self.decroissance -= retrait;
+        self.score = self.score.saturating_sub(retrait as u32);
+    }
+
+    /// Le vaisseau a été touché : le combo retombe à sa valeur de départ.
+    pub fn briser_combo(&mut self) {
+        self.combo = Self::COMBO_BASE;
+    }
+
+    /// Le vaisseau a subi des dégâts : le combo est perdu, et le niveau ne rapportera pas le bonus de vague parfaite.
+    fn signaler_degats(&mut self) {
+        self.briser_combo();
+        self.degats_niveau = true;
+    }
+
+    /// Retire une vie au joueur et le fait repartir avec un nouveau vaisseau au centre de l'écran.
+    pub fn perdre_vie(&mut self, settings: &Settings, ecran: Vec2) {
+        self.vies = self.vies.saturating_sub(1);
+        self.vaisseau = nouveau_vaisseau(settings, ecran);
+        // Le bouclier renforcé acheté à la boutique reste acquis pour toute la partie.
+        self.vaisseau
+            .set_bonus_bouclier(self.ameliorations.bonus_bouclier_max);
+        self.vaisseau.restaurer_bouclier();
+        self.missiles.clear();
+        self.missiles_ennemis.clear(); // Le nouveau vaisseau ne doit pas apparaître au milieu d'une gerbe.
+    }
+
+    /// Indique si la partie est perdue : le vaisseau est détruit et il ne reste plus de vie.
+    pub fn est_terminee(&self) -> bool {
+        self.vaisseau.est_detruit() && self.vies <= 1
+    }
+
+    /// Retourne la position et la taille des astéroïdes détruits depuis le dernier appel (pour leurs explosions),
+    /// et vide la liste.
+    pub fn prendre_destructions(&mut self) -> Vec<(Vec2, u8)> {
+        std::mem::take(&mut self.destructions)
+    }
+
+    /// Fait avancer le boss et les missiles ennemis (du boss et des ovnis) : le boss tire ses gerbes, les missiles qui touchent le vaisseau
+    /// lui infligent des dégâts et disparaissent, comme ceux qui sortent de l'écran. Le vaisseau qui percute
+    /// le boss est repoussé hors de lui et abîmé. Comme pour les astéroïdes, le vaisseau n'est abîmé qu'une fois
+    /// par cooldown de collision (`Spaceship::DELAI_IMPACTS`). Ne fait rien sans boss ni missile ennemi.
+    /// # Arguments
+    /// - `settings` : Paramètres du jeu.
+    /// - `temps` : Temps actuel en secondes, utilisé pour le cooldown des collisions.
+    /// - `delta_time` : Temps écoulé (en secondes) depuis la dernière frame.
+    /// - `ecran` : Dimensions de l'écran (largeur, hauteur).
+    pub fn avancer_boss(&mut self, settings: &Settings, temps: f64, delta_time: f32, ecran: Vec2) {
+        if let Some(boss) = &mut self.boss {
+            let gerbe = boss.avancer(settings.vitesse_simulation, delta_time, ecran);
+            self.missiles_ennemis.extend(gerbe);
+
+            let ecart = self.vaisseau.get_position() - boss.get_position();
+            let distance_contact = Boss::RAYON + Spaceship::RAYON;
+            if ecart.length() < distance_contact {
+                let normale = direction_ou_defaut(ecart);
+                self.vaisseau
+                    .set_position(boss.get_position() + normale * distance_contact);
+                self.vaisseau
+                    .appliquer_impulsion(normale * Boss::REBOND_CONTACT);
+                if self.vaisseau.subir_impact(Boss::DEGATS_CONTACT, temps) {
+                    self.signaler_degats();
+                }
+            }
+        }
+        // Les missiles ennemis vont en ligne droite, sans gravité.
+        let mut missiles_ennemis: Vec<&mut dyn StellarObject> = self
+            .missiles_ennemis
+            .iter_mut()
+            .map(|missile| missile as &mut dyn StellarObject)
+            .collect();
+        avancer_tous(&mut missiles_ennemis, settings.vitesse_simulation);
+
+        let position = self.vaisseau.get_position();
+        let avant = self.missiles_ennemis.len();
+        self.missiles_ennemis.retain(|missile| {
+            missile.get_position().distance(position) >= Spaceship::RAYON + Missile::RAYON
+        });
+        let impacts = avant - self.missiles_ennemis.len();
+        let degats = u8::try_from(impacts)
+            .unwrap_or(u8::MAX)
+            .saturating_mul(Boss::DEGATS_MISSILE);
+        if impacts > 0 && self.vaisseau.subir_impact(degats, temps) {
+            self.signaler_degats();
+        }
+        self.missiles_ennemis
+            .retain(|missile| !missile.est_hors_ecran(ecran));
+    }
+
+    /// Fait avancer les ovnis : ceux du niveau qui ne sont pas encore apparus arrivent un par un, tous les
+    /// `Ovni::INTERVALLE_APPARITION` secondes, et ceux qui ont traversé l'écran disparaissent. Les ovnis tirent sur
+    /// le vaisseau (leurs missiles sont gérés avec ceux du boss) ; le vaisseau qui en percute un le détruit,
+    /// mais est abîmé par le choc (au plus une fois par cooldown de collision).
+    /// # Arguments
+    /// - `settings` : Paramètres du jeu.
+    /// - `temps` : Temps actuel en secondes, utilisé pour le cooldown des collisions.
+    /// - `delta_time` : Temps écoulé (en secondes) depuis la dernière frame.
+    /// - `ecran` : Dimensions de l'écran (largeur, hauteur).
+    pub fn avancer_ovnis(&mut self, settings: &Settings, temps: f64, delta_time: f32, ecran: Vec2) {
+        if self.ovnis_a_venir > 0 {
+            self.minuteur_ovni -= delta_time;
+            if self.minuteur_ovni <= 0.0 {
+                self.ovnis.push(Ovni::apparition(&mut self.rng, ecran));
+                self.ovnis_a_venir -= 1;
+                self.minuteur_ovni = Ovni::INTERVALLE_APPARITION;
+            }
+        }
+
+        let cible = self.vaisseau.get_position();
+        for ovni in self.ovnis.iter_mut() {
+            self.missiles_ennemis.extend(ovni.avancer(
+                settings.vitesse_simulation,
+                delta_time,
+                cible,
+            ));
+        }
+        self.ovnis.retain(|ovni| !ovni.est_sorti(ecran));
+
+        let (percutes, restants): (Vec<Ovni>, Vec<Ovni>) = std::mem::take(&mut self.ovnis)
+            .into_iter()
+            .partition(|ovni| ovni.get_position().distance(cible) < Ovni::RAYON + Spaceship::RAYON);
+        self.ovnis = restants;
+        if !percutes.is_empty() {
+            self.destructions
+                .extend(percutes.iter().map(|ovni| (ovni.get_position(), 1)));
+            let degats = u8::try_from(percutes.len())
+                .unwrap_or(u8::MAX)
+                .saturating_mul(Ovni::DEGATS_CONTACT);
+            if self.vaisseau.subir_impact(degats, temps) {
+                self.signaler_degats();
+            }
+        }
+    }
+
+    /// Vérifie si les missiles du joueur touchent les ovnis : un seul missile détruit un ovni, qui rapporte
+    /// `Ovni::POINTS` points (multipliés par le combo) et explose.
+    pub fn gerer_collisions_ovnis(&mut self) {
+        let mut detruits = Vec::new();
+        for ovni in std::mem::take(&mut self.ovnis) {
+            match self
+                .missiles
+                .iter()
+                .position(|missile| ovni.est_touche_par(missile))
+            {
+                Some(indice) => {
+                    self.missiles.remove(indice);
+                    detruits.push(ovni.get_position());
+                }
+                None => self.ovnis.push(ovni),
+            }
+        }
+        self.statistiques.touches += detruits.len() as u32;
+        self.score += Ovni::POINTS * self.combo * detruits.len() as u32;
+        self.destructions
+            .extend(detruits.into_iter().map(|position| (position, 1)));
+    }
+
+    /// Vérifie si les missiles du joueur touchent le boss : chaque missile qui le touche disparaît et lui retire
+    /// un point de vie. Le boss détruit rapporte `Boss::POINTS` points (multipliés par le combo) et explose.
+    pub fn gerer_collisions_boss(&mut self) {
+        let Some(boss) = &mut self.boss else {
+            return;
+        };
+        let avant = self.missiles.len();
+        self.missiles.retain(|missile| {
+            let touche = !boss.est_detruit() && boss.est_touche_par(missile);
+            if touche {
+                boss.encaisser_impact();
+            }
+            !touche
+        });
+        self.statistiques.touches += (avant - self.missiles.len()) as u32;
+        if boss.est_detruit() {
+            self.score += Boss::POINTS * self.combo;
+            self.destructions.push((boss.get_position(), 3));
+            self.boss = None;
+        }
+    }
+
+    /// Vérifie si les missiles du joueur touchent le cluster : les orbiteurs arrêtent les missiles
+    /// et protègent le cœur tant qu'il en reste. Chaque astéroïde du cluster détruit compte comme
+    /// un astéroïde ordinaire (points, combo et explosion) ; le cluster disparaît avec son cœur.
+    pub fn gerer_collisions_cluster(&mut self) {
+        let Some(cluster) = &mut self.cluster else {
+            return;
+        };
+        let mut detruits = Vec::new();
+        let avant = self.missiles.len();
+        self.missiles.retain(|missile| {
+            match cluster.recevoir_missile(missile.get_position(), Missile::RAYON) {
+                Impact::Manque => true,
+                Impact::Arrete => false,
+                Impact::Detruit(position, taille) => {
+                    detruits.push((position, taille));
+                    false
+                }
+            }
+        });
+        self.statistiques.touches += (avant - self.missiles.len()) as u32;
+        if cluster.est_detruit() {
+            self.cluster = None;
+        }
+        self.enregistrer_destructions(detruits.len());
+        self.destructions.extend(detruits);
+    }
+
+    /// À appeler juste avant de quitter le jeu : le score de la partie en cours est ajouté au tableau des meilleurs
+    /// scores (sous le nom `NOM_ANONYME`, les initiales n'ayant pas été tapées) s'il y a sa place, pour ne pas
+    /// perdre la progression. Le fichier des scores n'est pas modifié si le score ne rentre pas dans le tableau.
+    /// # Retourne une erreur si le fichier des scores n'a pas pu être écrit.
+    pub fn on_quit(&self) -> std::io::Result<()> {
+        let mut table = TableScores::charger(&self.fichier_scores);
+        if !table.qualifie(self.score) {
+            return Ok(());
+        }
+        table.inserer(NOM_ANONYME, self.score);
+        table.sauvegarder(&self.fichier_scores)
+    }
+
+    /// Retourne la graine de départ du générateur aléatoire.
+    pub fn get_graine(&self) -> u64 {
+        self.graine
+    }
+
+    /// Fait avancer la partie d'une frame : vaisseau, bonus, tirs, missiles, collisions et astéroïdes.
+    /// Rien n'est dessiné, et le résultat ne dépend que de l'état du monde et des arguments :
+    /// avec les mêmes entrées, deux mondes créés avec la même graine évoluent de la même façon.
+    /// # Arguments
+    /// - `entree` : Commandes du joueur pour cette frame.
+    /// - `settings` : Paramètres du jeu.
+    /// - `temps` : Temps actuel (en secondes), utilisé pour la cadence de tir et le cooldown des collisions.
+    ///   C'est une horloge absolue : elle n'est pas accélérée par `Settings::vitesse_simulation`, qui peut changer
+    ///   en cours de partie (console) et ferait alors sauter la cadence de tir.
+    /// - `delta_time` : Temps réel écoulé (en secondes) depuis la dernière frame. Il est multiplié par
+    ///   `Settings::vitesse_simulation`, comme les déplacements : tous les minuteurs de la partie (bonus, compte
+    ///   à rebours, apparitions, tirs ennemis, régénération du bouclier...) suivent la vitesse de la simulation.
+    /// - `ecran` : Dimensions de l'écran (largeur, hauteur).
+    pub fn update(
+        &mut self,
+        entree: &InputState,
+        settings: &Settings,
+        temps: f64,
+        delta_time: f32,
+        ecran: Vec2,
+    ) {
+        self.statistiques.temps_survie += delta_time;
+        let delta_time = delta_time * settings.vitesse_simulation;
+
+        // Si le vaisseau est détruit (plus de bouclier ni de coque) mais qu'il reste des vies, on en perd une et on repart avec un nouveau vaisseau.
+        // Selon les paramètres, la vague actuelle recommence alors avec de nouveaux astéroïdes.
+        if self.vaisseau.est_detruit() && self.vies > 1 {
+            self.perdre_vie(settings, ecran);
+            if settings.rejouer_vague_apres_vie {
+                self.rejouer_vague(settings, ecran);
+            }
+        }
+
+        // Mise à jour de la position du vaisseau et gestion de la collision avec les astéroïdes
+        let mut touche = self
+            .vaisseau
+            .maj_pos(entree, &mut self.asteroids, settings, temps, ecran);
+        // Les astéroïdes du cluster ne sont pas dans la liste des astéroïdes, mais heurtent aussi le vaisseau.
+        if let Some(cluster) = &mut self.cluster {
+            touche |= self
+                .vaisseau
+                .gerer_collisions(cluster.asteroides_mut(), settings, temps);
+        }
+        if touche {
+            self.signaler_degats(); // Prendre un coup fait perdre le combo en cours.
+        }
+
+        // Mettre à jour les bonus (apparition et disparition)
+        self.bonus.update(
+            &mut self.rng,
+            delta_time,
+            self.vaisseau.get_bouclier(),
+            self.vaisseau.get_position(),
+            ecran,
+            &settings.bonus,
+        );
+
+        // Vérifier si le vaisseau récupère un bonus
+        if let Some(bonus) = self
+            .bonus
+            .ramasser(self.vaisseau.get_position(), Spaceship::RAYON)
+        {
+            if bonus.est_surcharge() {
+                self.vaisseau.surcharger_bouclier(); // Bonus rare : bouclier au-delà de 100%
+            } else if bonus.est_aimant() {
+                self.activer_aimant(); // Bonus rare : attire les bonus et repousse les astéroïdes
+            } else {
+                self.vaisseau.restaurer_bouclier(); // Remettre le bouclier à 100%
+            }
+            self.vaisseau.reparer_coque(); // Seuls les bonus réparent la coque
+        }
+        self.vaisseau
+            .regenerer_bouclier(delta_time, settings.regeneration_bouclier);
+        self.vaisseau.decroitre_surcharge(delta_time); // La surcharge redescend peu à peu vers 100%
+
+        // Tirs du vaisseau
+        if entree.tir {
+            // Créer un nouveau missile qui part du nez du vaisseau, dans sa direction (décalée par le balayage de l'arme "spray")
+            let angle = self.vaisseau.angle_tir(settings.balayage_tir);
+            let mut nv_missile = Missile::nouveau_missile(self.vaisseau.point_tir(), angle);
+            // Le tir rapide acheté à la boutique fait partir les missiles plus vite.
+            nv_missile.set_vitesse(
+                nv_missile.get_vitesse() * self.ameliorations.facteur_vitesse_missiles(),
+            );
+            nv_missile.set_durabilite(settings.durabilite_missiles);
+            nv_missile.set_gravite(settings.gravite_missiles);
+            self.missiles.push(nv_missile);
+            self.statistiques.tirs += 1;
+        }
+
+        // Champ de ralentissement déployé à la position du vaisseau (touche X), dans la limite des charges.
+        if entree.ralentissement {
+            self.deployer_ralentissement();
+        }
+
+        // Barrière déployée devant le vaisseau (touche B), dans la limite des charges.
+        if entree.barriere {
+            self.deployer_barriere();
+        }
+
+        // Bombe (touche V), si le joueur en a acheté à la boutique.
+        if entree.bombe {
+            self.utiliser_bombe();
+        }
+
+        // Pendant un hitstop (juste après une destruction), les astéroïdes et les missiles restent figés.
+        let hitstop = self.avancer_hitstop();
+
+        // Au début de chaque vague, les astéroïdes attendent la fin du compte à rebours pour bouger.
+        let compte_a_rebours = self.avancer_compte_a_rebours(delta_time);
+
+        if !hitstop {
+            // Les missiles téléguidés (boutique) tournent peu à peu vers l'astéroïde le plus proche.
+            if self.ameliorations.missiles_teleguides {
+                for missile in self.missiles.iter_mut() {
+                    if let Some(cible) =
+                        position_plus_proche(missile.get_position(), &self.asteroids)
+                    {
+                        missile.orienter_vers(
+                            cible,
+                            Self::ANGLE_TELEGUIDAGE * settings.vitesse_simulation,
+                        );
+                    }
+                }
+            }
+            for missile in self.missiles.iter_mut() {
+                missile.maj_pos_missile(settings.vitesse_simulation);
+            }
+
+            // Les nébuleuses ralentissent ou détruisent les missiles qui les traversent.
+            let zones = &self.zones;
+            self.missiles
+                .retain_mut(|missile| !zones.iter().any(|zone| zone.affecter_missile(missile)));
+
+            // Gestion des collisions entre missiles et astéroïdes : chaque impact coûte un point de durabilité.
+            let durabilite_avant = durabilite_totale(&self.missiles);
+            let detruits = gerer_collisions_missiles(
+                &mut self.missiles,
+                &mut self.asteroids,
+                &mut self.vaisseau,
+                settings,
+                &mut self.rng,
+            );
+            self.statistiques.touches += durabilite_avant - durabilite_totale(&self.missiles);
+            if !detruits.is_empty() {
+                self.enregistrer_destructions(detruits.len());
+                self.declencher_hitstop(settings.hitstop_frames);
+                // Les grands astéroïdes laissent un nuage de débris là où ils ont explosé.
+                if settings.nuages_debris {
+                    self.nuages.extend(
+                        detruits
+                            .iter()
+                            .filter(|(_, taille)| *taille == 3)
+                            .map(|(position, _)| NuageDebris::apres_destruction(*position)),
+                    );
+                }
+                self.destructions.extend(detruits);
+            }
+            self.gerer_collisions_boss();
+            self.gerer_collisions_ovnis();
+            self.gerer_collisions_cluster();
+        }
+
+        // Le vaisseau qui reste dans un nuage de débris est abîmé ; les nuages se dissipent peu à peu.
+        let mut degats_nuages: u8 = 0;
+        for nuage in self.nuages.iter_mut() {
+            degats_nuages = degats_nuages.saturating_add(nuage.avancer(
+                delta_time,
+                self.vaisseau.get_position(),
+                Spaceship::RAYON,
+            ));
+        }
+        self.nuages.retain(|nuage| !nuage.est_dissipe());
+        if degats_nuages > 0 {
+            self.vaisseau.subir_degats(degats_nuages);
+            self.signaler_degats();
+        }
+
+        // Si tous les astéroïdes (ainsi que le boss, les ovnis et le cluster) sont détruits, passer au niveau suivant
+        // (il n'y a pas de niveaux en mode horde).
+        if self.asteroids.is_empty()
+            && self.boss.is_none()
+            && self.ovnis.is_empty()
+            && self.ovnis_a_venir == 0
+            && self.cluster.is_none()
+            && self.horde.is_none()
+        {
+            // Vague parfaite : le niveau a été terminé sans que le vaisseau subisse de dégâts.
+            if !self.degats_niveau {
+                self.score += Self::POINTS_VAGUE_PARFAITE;
+            }
+            self.niveau_suivant(settings, ecran);
+            // Entre deux vagues, le joueur peut dépenser son score à la boutique.
+            self.boutique_ouverte = settings.boutique;
+        }
+
+        if !hitstop && !compte_a_rebours {
+            update_model(
+                &mut self.asteroids,
+                self.vaisseau.get_position(),
+                settings,
+                self.trou_noir.as_ref(),
+                Deploiements {
+                    zones_ralentissement: &self.zones_ralentissement,
+                    barrieres: &self.barrieres,
+                },
+                (self.aimant_restant > 0.0).then_some(&mut self.bonus),
+                ecran,
+            );
+            self.aimant_restant = (self.aimant_restant - delta_time).max(0.0);
+            // Les champs de ralentissement disparaissent après leur durée de vie.
+            for zone in self.zones_ralentissement.iter_mut() {
+                zone.avancer(delta_time);
+            }
+            self.zones_ralentissement.retain(|zone| !zone.est_expiree());
+            // Les barrières aussi.
+            for barriere in self.barrieres.iter_mut() {
+                barriere.avancer(delta_time);
+            }
+            self.barrieres.retain(|barriere| !barriere.est_expiree());
+            self.avancer_horde(delta_time, ecran);
+            self.avancer_ovnis(settings, temps, delta_time, ecran);
+            self.avancer_boss(settings, temps, delta_time, ecran);
+            if let Some(cluster) = &mut self.cluster {
+                cluster.avancer(settings.vitesse_simulation, ecran);
+            }
+            self.avancer_decroissance_score(delta_time, settings.decroissance_score);
+            // Le trou noir entraîne aussi le vaisseau s'il s'en approche trop.
+            if let Some(trou_noir) = &self.trou_noir {
+                self.vaisseau
+                    .appliquer_impulsion(trou_noir.acceleration(self.vaisseau.get_position()));
+            }
+        }
+    }
+}
+
+/// Retourne la position de l'astéroïde le plus proche du point donné (`None` s'il n'y a aucun astéroïde).
+fn position_plus_proche(point: Vec2, asteroids: &[Asteroid]) -> Option<Vec2> {
+    asteroids
+        .iter()
+        .map(|asteroid| asteroid.get_position())
+        .min_by(|a, b| a.distance(point).total_cmp(&b.distance(point)))
+}
+
+/// Retourne le nombre total d'impacts que les missiles peuvent encore encaisser.
+fn durabilite_totale(missiles: &[Missile]) -> u32 {
+    missiles
+        .iter()
+        .map(|missile| missile.get_durabilite() as u32)
+        .sum()
+}
+
+/// Crée le générateur des effets visuels d'une partie. Il est tiré de la graine de la partie, pour qu'un replay
+/// reproduise les mêmes effets, mais distinct du générateur de la partie : les effets dessinés (ou non) ne changent
+/// rien aux astéroïdes ni aux bonus.
+fn generateur_effets(graine: u64) -> SmallRng {
+    SmallRng::seed_from_u64(graine.wrapping_add(1))
+}
+
+/// Durée du compte à rebours au début d'une vague, selon les paramètres (0 = pas de compte à rebours).
+fn duree_compte_a_rebours(settings: &Settings) -> f32 {
+    if settings.compte_a_rebours {
+        World::DUREE_COMPTE_A_REBOURS
+    } else {
+        0.0
+    }
+}
+
+/// Crée un vaisseau neuf au centre de l'écran, avec la coque définie dans les paramètres.
+fn nouveau_vaisseau(settings: &Settings, ecran: Vec2) -> Spaceship {
+    let mut vaisseau = Spaceship::avec_position(ecran / 2.0);
+    vaisseau.set_coque(settings.coque_vaisseau);
+    vaisseau
+}
+
+/// Calcule le nombre de vies en arrivant à un nouveau niveau.
+/// Une vie est offerte chaque fois que le niveau atteint un multiple de `intervalle_vie_bonus`, sans dépasser `vies_max`.
+/// # Arguments
+/// - `vies` - Nombre de vies actuel.
+/// - `niveau` - Niveau qui commence.
+/// - `settings` - Paramètres du jeu.
+fn vies_apres_niveau(vies: u8, niveau: i32, settings: &Settings) -> u8 {
+    let intervalle = settings.intervalle_vie_bonus.max(1) as i32;
+    if niveau % intervalle == 0 {
+        vies.saturating_add(1).min(settings.vies_max)
+    } else {
+        vies
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bonus::ConfigBonus;
+    use crate::replay::{temps_frame, PAS_TEMPS};
+
+    const ECRAN: Vec2 = vec2(800.0, 600.0);
+
+    /// Résume un champ d'astéroïdes (position, vitesse, taille) pour pouvoir le comparer.
+    fn resume(asteroids: &[Asteroid]) -> Vec<(Vec2, Vec2, u8)> {
+        asteroids
+            .iter()
+            .map(|a| (a.get_position(), a.get_vitesse(), a.get_taille()))
+            .collect()
+    }
+
+    /// La vague de départ respecte le nombre d'astéroïdes défini dans les paramètres.
+    #[test]
+    fn test_vague_initiale_selon_parametres() {
+        assert_eq!(
+            World::new(&Settings::default(), None, ECRAN)
+                .asteroids
+                .len(),
+            5
+        );
+
+        let settings = Settings {
+            asteroides_initiaux: 12,
+            ..Default::default()
+        };
+        assert_eq!(World::new(&settings, None, ECRAN).asteroids.len(), 12);
+    }
+
+    /// Deux mondes créés avec la même graine ont exactement le même champ d'astéroïdes.
+    #[test]
+    fn test_meme_graine_meme_champ() {
+        let monde1 = World::new(&Settings::default(), Some(42), ECRAN);
+        let monde2 = World::new(&Settings::default(), Some(42), ECRAN);
+        assert_eq!(resume(&monde1.asteroids), resume(&monde2.asteroids));
+
+        let monde3 = World::new(&Settings::default(), Some(43), ECRAN);
+        assert_ne!(resume(&monde1.asteroids), resume(&monde3.asteroids));
+    }
+
+    /// Le passage au niveau suivant génère la vague correspondant au nouveau niveau.
+    #[test]
+    fn test_niveau_suivant() {
+        let mut monde = World::new(&Settings::default(), Some(1), ECRAN);
+        monde.asteroids.clear();
+        monde.niveau_suivant(&Settings::default(), ECRAN);
+        assert_eq!(monde.niveau, 2);
+        assert_eq!(monde.asteroids.len(), nombre_asteroides_niveau(2));
+    }
+
+    /// Passer un niveau vide le champ et génère la vague du niveau suivant.
+    #[test]
+    fn test_passer_niveau() {
+        let mut monde = World::new(&Settings::default(), Some(1), ECRAN);
+        monde.passer_niveau(&Settings::default(), ECRAN);
+        assert_eq!(monde.niveau, 2);
+        assert_eq!(monde.asteroids.len(), nombre_asteroides_niveau(2));
+
+        monde.passer_niveau(&Settings::default(), ECRAN);
+        assert_eq!(monde.niveau, 3);
+        assert_eq!(monde.asteroids.len(), nombre_asteroides_niveau(3));
+    }
+
+    /// Rejouer une vague génère à nouveau la vague du niveau actuel, sans changer le niveau ni les vies.
+    #[test]
+    fn test_rejouer_vague() {
+        let settings = Settings::default();
+        let mut monde = World::new(&settings, Some(1), ECRAN);
+        monde.passer_niveau(&settings, ECRAN);
+        monde.passer_niveau(&settings, ECRAN);
+        monde.asteroids.truncate(2); // Une partie de la vague a été détruite.
+        monde.vies = 2;
+
+        monde.rejouer_vague(&settings, ECRAN);
+        assert_eq!(monde.niveau, 3);
+        assert_eq!(monde.vies, 2);
+        assert_eq!(monde.asteroids.len(), nombre_asteroides_niveau(3));
+        assert!(monde.missiles.is_empty());
+    }
+
+    /// Le hitstop fige le monde pendant le nombre de frames demandé, puis le jeu reprend.
+    #[test]
+    fn test_hitstop() {
+        let mut monde = World::new(&Settings::default(), Some(1), ECRAN);
+        assert!(!monde.avancer_hitstop());
+
+        monde.declencher_hitstop(3);
+        assert!(monde.avancer_hitstop());
+        assert!(monde.avancer_hitstop());
+        assert!(monde.avancer_hitstop());
+        assert!(!monde.avancer_hitstop());
+
+        monde.declencher_hitstop(0); // Hitstop désactivé
+        assert!(!monde.avancer_hitstop());
+    }
+
+    /// Atteindre un palier de niveaux donne une vie, sans dépasser le maximum.
+    #[test]
+    fn test_vie_bonus_aux_paliers() {
+        let settings = Settings::default(); // Une vie tous les 5 niveaux, 5 vies au maximum.
+        assert_eq!(vies_apres_niveau(1, 4, &settings), 1);
+        assert_eq!(vies_apres_niveau(1, 5, &settings), 2);
+        assert_eq!(vies_apres_niveau(2, 10, &settings), 3);
+        assert_eq!(
+            vies_apres_niveau(settings.vies_max, 15, &settings),
+            settings.vies_max
+        );
+    }
+
+    /// Les destructions font monter le combo jusqu'à son maximum, et un impact le ramène à sa valeur de départ.
+    #[test]
+    fn test_combo_brise_par_un_impact() {
+        let mut monde = World::new(&Settings::default(), Some(1), ECRAN);
+        assert_eq!(monde.get_combo(), World::COMBO_BASE);
+
+        monde.enregistrer_destructions(3);
+        assert_eq!(monde.get_combo(), World::COMBO_BASE + 3);
+        monde.enregistrer_destructions(100);
+        assert_eq!(monde.get_combo(), World::COMBO_MAX);
+
+        monde.briser_combo();
+        assert_eq!(monde.get_combo(), World::COMBO_BASE);
+    }
+
+    /// En mode horde, les astéroïdes apparaissent au fil du temps ; sans ce mode, rien n'apparaît.
+    #[test]
+    fn test_apparitions_horde() {
+        let settings = Settings {
+            mode_horde: true,
+            ..Default::default()
+        };
+        let mut monde = World::new(&settings, Some(1), ECRAN);
+        monde.asteroids.clear();
+        monde.avancer_horde(ModeHorde::INTERVALLE_INITIAL, ECRAN);
+        assert_eq!(monde.asteroids.len(), 1);
+        // Il arrive de l'extérieur de l'écran, et non au milieu.
+        let position = monde.asteroids[0].get_position();
+        assert!(!Rect::new(0.0, 0.0, ECRAN.x, ECRAN.y).contains(position));
+
+        let mut classique = World::new(&Settings::default(), Some(1), ECRAN);
+        classique.asteroids.clear();
+        classique.avancer_horde(ModeHorde::INTERVALLE_INITIAL, ECRAN);
+        assert!(classique.asteroids.is_empty());
+    }
+
+    /// Avec une graine fixe, chaque nouvelle partie rejoue la même disposition ; sinon elle change.
+    #[test]
+    fn test_graine_fixe_au_redemarrage() {
+        let fixe = Settings {
+            graine_fixe: true,
+            ..Default::default()
+        };
+        let mut monde = World::new(&fixe, Some(3), ECRAN);
+        let depart = resume(&monde.asteroids);
+        monde.nouvelle_partie(&fixe, ECRAN);
+        let premiere = resume(&monde.asteroids);
+        monde.nouvelle_partie(&fixe, ECRAN);
+        assert_eq!(resume(&monde.asteroids), premiere);
+        assert_eq!(premiere, depart);
+
+        let aleatoire = Settings::default();
+        let mut monde = World::new(&aleatoire, Some(3), ECRAN);
+        monde.nouvelle_partie(&aleatoire, ECRAN);
+        let premiere = resume(&monde.asteroids);
+        monde.nouvelle_partie(&aleatoire, ECRAN);
+        assert_ne!(resume(&monde.asteroids), premiere);
+    }
+
+    /// Chaque destruction rapporte des points multipliés par le combo ; une nouvelle partie remet le score à zéro.
+    #[test]
+    fn test_score() {
+        let settings = Settings::default();
+        let mut monde = World::new(&settings, Some(1), ECRAN);
+        monde.enregistrer_destructions(1);
+        assert_eq!(
+            monde.get_score(),
+            World::POINTS_ASTEROIDE * World::COMBO_BASE
+        );
+        monde.enregistrer_destructions(2);
+        assert_eq!(
+            monde.get_score(),
+            World::POINTS_ASTEROIDE * (World::COMBO_BASE + 2 * (World::COMBO_BASE + 1))
+        );
+
+        monde.nouvelle_partie(&settings, ECRAN);
+        assert_eq!(monde.get_score(), 0);
+    }
+
+    /// Contre-la-montre : sans destruction, le score baisse (sans passer sous zéro),
+    /// et une destruction remet le délai à zéro, ce qui suspend la baisse.
+    #[test]
+    fn test_decroissance_score() {
+        let mut monde = World::new(&Settings::default(), Some(1), ECRAN);
+        monde.enregistrer_destructions(1); // 100 points
+        let avancer = |monde: &mut World, secondes: u32| {
+            for _ in 0..secondes * 60 {
+                monde.avancer_decroissance_score(1.0 / 60.0, 10.0);
+            }
+        };
+
+        avancer(&mut monde, 2); // Pendant le délai, le score ne bouge pas.
+        assert_eq!(monde.get_score(), 100);
+        avancer(&mut monde, 3);
+        assert!((69..=71).contains(&monde.get_score())); // 3 secondes à 10 points par seconde
+
+        monde.enregistrer_destructions(1);
+        let apres_destruction = monde.get_score();
+        avancer(&mut monde, 1); // Le délai recommence : pas de baisse.
+        assert_eq!(monde.get_score(), apres_destruction);
+
+        avancer(&mut monde, 120);
+        assert_eq!(monde.get_score(), 0);
+
+        // Taux nul : pas de décroissance.
+        let mut monde = World::new(&Settings::default(), Some(1), ECRAN);
+        monde.enregistrer_destructions(1);
+        for _ in 0..600 {
+            monde.avancer_decroissance_score(1.0 / 60.0, 0.0);
+        }
+        assert_eq!(monde.get_score(), 100);
+    }
+
+    /// Les niveaux décrits par un fichier de vagues suivent cette description, les suivants restent aléatoires.
+    #[test]
+    fn test_vagues_definies() {
+        let settings = Settings::default();
+        let vagues = crate::vague::analyser_vagues(
+            "1: 3 3
+2: 1B",
+        )
+        .unwrap();
+        let mut monde = World::new(&settings, Some(1), ECRAN);
+        monde.charger_vagues(vagues, &settings, ECRAN);
+        assert_eq!(monde.asteroids.len(), 2);
+        assert!(monde.asteroids.iter().all(|a| a.get_taille() == 3));
+
+        monde.passer_niveau(&settings, ECRAN);
+        assert_eq!(monde.asteroids.len(), 1);
+        assert!(monde.asteroids[0].est_blinde());
+
+        monde.passer_niveau(&settings, ECRAN);
+        assert_eq!(monde.asteroids.len(), nombre_asteroides_niveau(3));
+    }
+
+    /// Une disposition trop grande est tronquée à `MAX_ASTEROIDS` astéroïdes.
+    #[test]
+    fn test_disposition_plafonnee() {
+        let settings = Settings::default();
+        let mut monde = World::new(&settings, Some(1), ECRAN);
+        let disposition = "1 100 100 1 0\n".repeat(MAX_ASTEROIDS + 5);
+        monde.charger_disposition(crate::asteroid::analyser_disposition(&disposition).unwrap());
+        assert_eq!(monde.asteroids.len(), MAX_ASTEROIDS);
+    }
+
+    /// Pendant le compte à rebours, les astéroïdes restent immobiles ; ils repartent une fois qu'il est écoulé.
+    #[test]
+    fn test_compte_a_rebours() {
+        let settings = Settings::default();
+        let mut monde = World::new(&settings, Some(1), ECRAN);
+        assert_eq!(monde.texte_compte_a_rebours().as_deref(), Some("3"));
+
+        let avancer = |monde: &mut World| {
+            if !monde.avancer_compte_a_rebours(0.25) {
+                for asteroid in monde.asteroids.iter_mut() {
+                    asteroid.move_object(1.0, ECRAN);
+                }
+            }
+        };
+        let depart = resume(&monde.asteroids);
+        for _ in 0..14 {
+            avancer(&mut monde); // 14 x 0.25s = 3.5s
+        }
+        assert_eq!(resume(&monde.asteroids), depart);
+        assert_eq!(monde.texte_compte_a_rebours(), None);
+
+        avancer(&mut monde);
+        assert_ne!(resume(&monde.asteroids), depart);
+
+        // Le niveau suivant recommence par un compte à rebours.
+        monde.niveau_suivant(&settings, ECRAN);
+        assert!(monde.avancer_compte_a_rebours(0.25));
+    }
+
+    /// Les minuteurs suivent la vitesse de la simulation : à 2.0, le compte à rebours se termine deux fois plus tôt.
+    #[test]
+    fn test_minuteurs_selon_vitesse_simulation() {
+        let frames_compte_a_rebours = |vitesse_simulation: f32| {
+            let settings = Settings {
+                vitesse_simulation,
+                ..Default::default()
+            };
+            let mut monde = World::new(&settings, Some(1), ECRAN);
+            let mut frames = 0;
+            while monde.texte_compte_a_rebours().is_some() {
+                monde.update(
+                    &InputState::default(),
+                    &settings,
+                    temps_frame(frames),
+                    PAS_TEMPS,
+                    ECRAN,
+                );
+                frames += 1;
+            }
+            frames
+        };
+        let normal = frames_compte_a_rebours(1.0);
+        let attendu = (World::DUREE_COMPTE_A_REBOURS / PAS_TEMPS).round() as usize;
+        assert!(normal.abs_diff(attendu) <= 1); // À un arrondi près.
+        assert!(frames_compte_a_rebours(2.0).abs_diff(normal / 2) <= 1);
+    }
+
+    /// Sans compte à rebours, les astéroïdes bougent dès la première frame.
+    #[test]
+    fn test_sans_compte_a_rebours() {
+        let settings = Settings {
+            compte_a_rebours: false,
+            ..Default::default()
+        };
+        let mut monde = World::new(&settings, Some(1), ECRAN);
+        assert!(!monde.avancer_compte_a_rebours(0.016));
+        assert_eq!(monde.texte_compte_a_rebours(), None);
+    }
+
+    /// Les missiles du boss abîment le vaisseau qu'ils touchent ; le boss détruit rapporte des points.
+    #[test]
+    fn test_boss() {
+        let settings = Settings {
+            intervalle_boss: 1,
+            ..Default::default()
+        };
+        let mut monde = World::new(&settings, Some(1), ECRAN);
+        assert!(monde.boss.is_some());
+
+        let centre = monde.vaisseau.get_position();
+        monde.missiles_ennemis = crate::boss::tirer_spread(centre - vec2(20.0, 0.0), 4, 3.0);
+        let bouclier = monde.vaisseau.get_bouclier();
+        monde.avancer_boss(&settings, 10.0, 1.0 / 60.0, ECRAN);
+        assert_eq!(monde.missiles_ennemis.len(), 3); // Celui qui part vers la droite touche le vaisseau.
+        assert_eq!(
+            monde.vaisseau.get_bouclier(),
+            bouclier - Boss::DEGATS_MISSILE
+        );
+
+        let position_boss = monde.boss.as_ref().unwrap().get_position();
+        for _ in 0..Boss::POINTS_VIE {
+            monde
+                .missiles
+                .push(Missile::nouveau_missile(position_boss, 0.0));
+            monde.gerer_collisions_boss();
+        }
+        assert!(monde.boss.is_none());
+        assert!(monde.missiles.is_empty());
+        assert_eq!(monde.get_score(), Boss::POINTS);
+        assert_eq!(monde.prendre_destructions(), vec![(position_boss, 3)]);
+    }
+
+    /// Une vague peut demander un boss et des ovnis : les ovnis arrivent un par un, et le niveau ne se termine
+    /// qu'une fois qu'ils sont tous détruits ou repartis.
+    #[test]
+    fn test_ennemis_vague() {
+        let settings = Settings::default();
+        let vagues = crate::vague::analyser_vagues("1: 3 boss ovni ovni").unwrap();
+        let mut monde = World::new(&settings, Some(1), ECRAN);
+        assert!(monde.boss.is_none());
+        monde.charger_vagues(vagues, &settings, ECRAN);
+        assert!(monde.boss.is_some());
+        assert!(monde.ovnis.is_empty());
+
+        monde.avancer_ovnis(&settings, 10.0, 1.0 / 60.0, ECRAN);
+        assert_eq!(monde.ovnis.len(), 1);
+        monde.avancer_ovnis(&settings, 10.0, 1.0 / 60.0, ECRAN);
+        assert_eq!(monde.ovnis.len(), 1); // Le second attend `INTERVALLE_APPARITION` secondes.
+        monde.avancer_ovnis(&settings, 10.0, Ovni::INTERVALLE_APPARITION, ECRAN);
+        assert_eq!(monde.ovnis.len(), 2);
+
+        // Un missile suffit à détruire un ovni.
+        let position = monde.ovnis[0].get_position();
+        monde.missiles.push(Missile::nouveau_missile(position, 0.0));
+        monde.gerer_collisions_ovnis();
+        assert_eq!(monde.ovnis.len(), 1);
+        assert!(monde.missiles.is_empty());
+        assert_eq!(monde.get_score(), Ovni::POINTS);
+        assert_eq!(monde.prendre_destructions(), vec![(position, 1)]);
+
+        // Le niveau suivant, sans vague définie, n'a ni boss ni ovni.
+        monde.niveau_suivant(&settings, ECRAN);
+        assert!(monde.boss.is_none());
+        assert!(monde.ovnis.is_empty());
+        assert_eq!(monde.ovnis_a_venir, 0);
+    }
+
+    /// Le vaisseau qui percute le boss est repoussé hors de lui et perd du bouclier.
+    #[test]
+    fn test_contact_boss() {
+        let settings = Settings {
+            intervalle_boss: 1,
+            ..Default::default()
+        };
+        let mut monde = World::new(&settings, Some(1), ECRAN);
+        let position_boss = monde.boss.as_ref().unwrap().get_position();
+        monde
+            .vaisseau
+            .recentrer_sur(position_boss + vec2(0.0, Boss::RAYON));
+        monde.avancer_boss(&settings, 10.0, 1.0 / 60.0, ECRAN);
+        let position_boss = monde.boss.as_ref().unwrap().get_position();
+        assert!(
+            monde.vaisseau.get_position().distance(position_boss)
+                >= Boss::RAYON + Spaceship::RAYON - 1e-3
+        );
+        assert!(monde.vaisseau.get_vitesse().y > 0.0); // Repoussé vers le bas.
+        assert_eq!(
+            monde.vaisseau.get_bouclier(),
+            Spaceship::BOUCLIER_MAX - Boss::DEGATS_CONTACT
+        );
+
+        // Percuter à nouveau le boss, ou recevoir une gerbe, pendant le cooldown des collisions n'abîme pas le vaisseau.
+        let percuter = |monde: &mut World, temps: f64| {
+            let position_boss = monde.boss.as_ref().unwrap().get_position();
+            monde
+                .vaisseau
+                .recentrer_sur(position_boss + vec2(0.0, Boss::RAYON));
+            monde.missiles_ennemis =
+                crate::boss::tirer_spread(monde.vaisseau.get_position(), 300, 3.0);
+            monde.avancer_boss(&settings, temps, 1.0 / 60.0, ECRAN);
+        };
+        percuter(&mut monde, 10.2);
+        assert_eq!(
+            monde.vaisseau.get_bouclier(),
+            Spaceship::BOUCLIER_MAX - Boss::DEGATS_CONTACT
+        );
+        assert!(monde.missiles_ennemis.is_empty()); // Les missiles qui touchent disparaissent quand même.
+        percuter(&mut monde, 10.6);
+        assert_eq!(
+            monde.vaisseau.get_bouclier(),
+            Spaceship::BOUCLIER_MAX - 2 * Boss::DEGATS_CONTACT
+        );
+    }
+
+    /// Un missile qui vise le cœur protégé est arrêté ; le cluster disparaît une fois tous ses astéroïdes détruits.
+    #[test]
+    fn test_cluster() {
+        let settings = Settings {
+            intervalle_cluster: 1,
+            ..Default::default()
+        };
+        let mut monde = World::new(&settings, Some(1), ECRAN);
+        let coeur = monde
+            .cluster
+            .as_ref()
+            .unwrap()
+            .asteroides()
+            .next()
+            .unwrap()
+            .get_position();
+        monde.missiles.push(Missile::nouveau_missile(coeur, 0.0));
+        monde.gerer_collisions_cluster();
+        assert!(monde.missiles.is_empty());
+        assert!(monde.prendre_destructions().is_empty());
+
+        // On vise toujours le dernier astéroïde du cluster : les orbiteurs, puis le cœur.
+        while let Some(cluster) = &monde.cluster {
+            let cible = cluster.asteroides().last().unwrap().get_position();
+            monde.missiles.push(Missile::nouveau_missile(cible, 0.0));
+            monde.gerer_collisions_cluster();
+        }
+        let destructions = monde.prendre_destructions();
+        assert_eq!(destructions.len(), ClusterAsteroide::NOMBRE_ORBITEURS + 1);
+        assert_eq!(destructions.last(), Some(&(coeur, 3)));
+    }
+
+    /// Demander beaucoup trop d'astéroïdes n'en ajoute que jusqu'à la limite, sans les créer tous.
+    #[test]
+    fn test_ajouter_asteroides_limite() {
+        let mut monde = World::new(&Settings::default(), Some(1), ECRAN);
+        let place = MAX_ASTEROIDS - monde.asteroids.len();
+        assert_eq!(
+            monde.ajouter_asteroides_aleatoires(usize::MAX, ECRAN),
+            place
+        );
+        assert_eq!(monde.asteroids.len(), MAX_ASTEROIDS);
+        assert_eq!(monde.ajouter_asteroides_aleatoires(10, ECRAN), 0);
+    }
+
+    /// Chaque champ de ralentissement déployé consomme une charge ; sans charge, plus rien n'est déployé.
+    #[test]
+    fn test_charges_ralentissement() {
+        let settings = Settings {
+            charges_ralentissement: 2,
+            ..Default::default()
+        };
+        let mut monde = World::new(&settings, Some(1), ECRAN);
+        assert!(monde.deployer_ralentissement());
+        assert!(monde.deployer_ralentissement());
+        assert!(!monde.deployer_ralentissement());
+        assert_eq!(monde.zones_ralentissement.len(), 2);
+        assert_eq!(monde.get_charges_ralentissement(), 0);
+
+        monde.nouvelle_partie(&settings, ECRAN);
+        assert!(monde.zones_ralentissement.is_empty());
+        assert_eq!(monde.get_charges_ralentissement(), 2);
+    }
+
+    /// Terminer un niveau sans dégâts rapporte le bonus de vague parfaite ; après un coup, le bonus est perdu
+    /// pour ce niveau, mais il est de nouveau possible au niveau suivant.
+    #[test]
+    fn test_vague_parfaite() {
+        let settings = Settings {
+            compte_a_rebours: false,
+            ..Default::default()
+        };
+        let mut monde = World::new(&settings, Some(1), ECRAN);
+        monde.asteroids.clear();
+        monde.update(&InputState::default(), &settings, 1.0, 1.0 / 60.0, ECRAN);
+        assert_eq!(monde.niveau, 2);
+        assert_eq!(monde.get_score(), World::POINTS_VAGUE_PARFAITE);
+
+        // Un astéroïde touche le vaisseau, puis le niveau est terminé : pas de bonus.
+        let centre = monde.vaisseau.get_position();
+        monde.asteroids = vec![Asteroid::scripté(3, centre, Vec2::ZERO)]; // Trop gros pour être renvoyé par le bouclier
+        monde.update(&InputState::default(), &settings, 2.0, 1.0 / 60.0, ECRAN);
+        assert!(monde.vaisseau.get_bouclier() < Spaceship::BOUCLIER_MAX);
+        monde.asteroids.clear();
+        monde.update(&InputState::default(), &settings, 3.0, 1.0 / 60.0, ECRAN);
+        assert_eq!(monde.niveau, 3);
+        assert_eq!(monde.get_score(), World::POINTS_VAGUE_PARFAITE);
+
+        monde.asteroids.clear();
+        monde.update(&InputState::default(), &settings, 4.0, 1.0 / 60.0, ECRAN);
+        assert_eq!(monde.niveau, 4);
+        assert_eq!(monde.get_score(), 2 * World::POINTS_VAGUE_PARFAITE);
+    }
+
+    /// Le vaisseau qui heurte un astéroïde du cluster est repoussé et abîmé, comme avec les autres astéroïdes.
+    #[test]
+    fn test_collision_cluster() {
+        let settings = Settings {
+            compte_a_rebours: false,
+            ..Default::default()
+        };
+        let mut monde = World::new(&settings, Some(1), ECRAN);
+        let centre = monde.vaisseau.get_position();
+        monde.asteroids.clear();
+        monde.cluster = Some(ClusterAsteroide::new(
+            centre + vec2(30.0, 0.0),
+            Vec2::ZERO,
+            ClusterAsteroide::NOMBRE_ORBITEURS,
+        ));
+        monde.update(&InputState::default(), &settings, 1.0, 1.0 / 60.0, ECRAN);
+        assert!(monde.vaisseau.get_bouclier() < Spaceship::BOUCLIER_MAX);
+        assert!(monde.vaisseau.get_position().x < centre.x); // Repoussé loin du cœur.
+        assert_eq!(monde.get_combo(), World::COMBO_BASE);
+    }
+
+    /// Ramasser un bonus aimant déclenche l'effet de l'aimant.
+    #[test]
+    fn test_ramasser_aimant() {
+        let settings = Settings {
+            compte_a_rebours: false,
+            bonus: ConfigBonus {
+                frequence_tirage: 1000.0,
+                chance_apparition: 1.0,
+                chance_surcharge: 0.0,
+                chance_aimant: 1.0,
+                duree_min: 100.0,
+                duree_max: 200.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut monde = World::new(&settings, Some(1), ECRAN);
+        monde.asteroids.clear();
+        monde.update(&InputState::default(), &settings, 1.0, 1.0 / 60.0, ECRAN);
+        assert_eq!(monde.bonus.nombre_visibles(), 1); // Loin du vaisseau : pas encore ramassé.
+        assert!(!monde.aimant_actif());
+
+        monde.bonus.attirer(monde.vaisseau.get_position(), ECRAN.x);
+        monde.update(&InputState::default(), &settings, 2.0, 1.0 / 60.0, ECRAN);
+        assert!(monde.aimant_actif());
+    }
+
+    /// La boutique s'ouvre à la fin d'une vague ; les achats coûtent du score et agissent sur la partie.
+    #[test]
+    fn test_boutique() {
+        let settings = Settings {
+            boutique: true,
+            compte_a_rebours: false,
+            ..Default::default()
+        };
+        let mut monde = World::new(&settings, Some(1), ECRAN);
+        assert!(!monde.boutique_ouverte());
+        monde.asteroids.clear();
+        monde.update(&InputState::default(), &settings, 1.0, 1.0 / 60.0, ECRAN);
+        assert!(monde.boutique_ouverte());
+        let score = monde.get_score(); // Le bonus de vague parfaite : de quoi acheter une bombe.
+        assert!(monde.acheter(Amelioration::BombeSupplementaire));
+        assert_eq!(
+            monde.get_score(),
+            score - Amelioration::BombeSupplementaire.prix()
+        );
+        assert!(!monde.acheter(Amelioration::MissilesTeleguides)); // Trop cher.
+        monde.fermer_boutique();
+        assert!(!monde.boutique_ouverte());
+
+        // La bombe détruit les astéroïdes proches du vaisseau, pas les autres.
+        let centre = monde.vaisseau.get_position();
+        monde.asteroids = vec![
+            Asteroid::scripté(3, centre + vec2(150.0, 0.0), Vec2::ZERO),
+            Asteroid::scripté(1, vec2(10.0, 10.0), Vec2::ZERO),
+        ];
+        let entree = InputState {
+            bombe: true,
+            ..Default::default()
+        };
+        monde.update(&entree, &settings, 2.0, 1.0 / 60.0, ECRAN);
+        assert_eq!(monde.asteroids.len(), 1);
+        assert_eq!(monde.prendre_destructions().len(), 1);
+        assert_eq!(monde.get_ameliorations().bombes, 0);
+        assert_eq!(monde.utiliser_bombe(), 0); // Plus de bombe.
+
+        // Le bouclier renforcé reste acquis après la perte d'une vie.
+        monde.enregistrer_destructions(10);
+        assert!(monde.acheter(Amelioration::BouclierRenforce));
+        assert_eq!(monde.vaisseau.get_bouclier(), Spaceship::BOUCLIER_MAX + 25);
+        monde.perdre_vie(&settings, ECRAN);
+        assert_eq!(monde.vaisseau.get_bouclier(), Spaceship::BOUCLIER_MAX + 25);
+
+        // Sans boutique, la vague suivante commence directement.
+        let settings = Settings {
+            boutique: false,
+            ..settings
+        };
+        monde.asteroids.clear();
+        monde.update(&InputState::default(), &settings, 3.0, 1.0 / 60.0, ECRAN);
+        assert!(!monde.boutique_ouverte());
+    }
+
+    /// Les missiles téléguidés tournent vers l'astéroïde le plus proche.
+    #[test]
+    fn test_missiles_teleguides() {
+        let settings = Settings {
+            compte_a_rebours: false,
+            ..Default::default()
+        };
+        let mut monde = World::new(&settings, Some(1), ECRAN);
+        monde.asteroids = vec![Asteroid::scripté(3, vec2(100.0, 500.0), Vec2::ZERO)];
+        monde.ameliorations.missiles_teleguides = true;
+        monde
+            .missiles
+            .push(Missile::nouveau_missile(vec2(100.0, 100.0), 0.0)); // Vers la droite, cible en bas
+        monde.update(&InputState::default(), &settings, 1.0, 1.0 / 60.0, ECRAN);
+        assert!(monde.missiles[0].get_vitesse().y > 0.0);
+    }
+
+    /// Une barrière déployée fait rebondir les astéroïdes, puis disparaît après sa durée de vie.
+    #[test]
+    fn test_barriere() {
+        let settings = Settings {
+            charges_barriere: 1,
+            compte_a_rebours: false,
+            ..Default::default()
+        };
+        let mut monde = World::new(&settings, Some(1), ECRAN);
+        monde.vaisseau = Spaceship::avec_position(vec2(400.0, 300.0)); // Tourné vers la droite
+        monde.asteroids = vec![Asteroid::scripté(1, vec2(480.0, 300.0), vec2(-2.0, 0.0))];
+        let entree = InputState {
+            barriere: true,
+            ..Default::default()
+        };
+        monde.update(&entree, &settings, 0.0, 1.0 / 60.0, ECRAN);
+        assert_eq!(monde.barrieres.len(), 1);
+        assert_eq!(monde.get_charges_barriere(), 0);
+        assert!(!monde.deployer_barriere());
+        assert!(monde.asteroids[0].get_vitesse().x > 0.0); // Il a rebondi sur la barrière.
+
+        let duree = (Barriere::DUREE * 60.0) as usize + 1;
+        for frame in 1..=duree {
+            monde.update(
+                &InputState::default(),
+                &settings,
+                frame as f64 / 60.0,
+                1.0 / 60.0,
+                ECRAN,
+            );
+        }
+        assert!(monde.barrieres.is_empty());
+    }
+
+    /// Les statistiques comptent les tirs, les impacts, les astéroïdes détruits et le temps de jeu.
+    #[test]
+    fn test_statistiques() {
+        let settings = Settings {
+            compte_a_rebours: false,
+            hitstop_frames: 0,
+            ..Default::default()
+        };
+        let mut monde = World::new(&settings, Some(1), ECRAN);
+        // Un petit astéroïde juste devant le nez du vaisseau, et un autre loin de lui (le niveau continue).
+        monde.asteroids = vec![
+            Asteroid::scripté(1, monde.vaisseau.point_tir(), Vec2::ZERO),
+            Asteroid::scripté(1, vec2(50.0, 50.0), Vec2::ZERO),
+        ];
+        let tir = InputState {
+            tir: true,
+            ..Default::default()
+        };
+        monde.update(&tir, &settings, 0.0, 0.5, ECRAN);
+        monde.update(&InputState::default(), &settings, 1.0, 0.5, ECRAN);
+
+        let statistiques = monde.statistiques();
+        assert_eq!(statistiques.tirs, 1);
+        assert_eq!(statistiques.touches, 1);
+        assert_eq!(statistiques.asteroides_detruits, 1);
+        assert_eq!(statistiques.temps_survie, 1.0);
+        assert_eq!(statistiques.score, monde.get_score());
+        assert_eq!(statistiques.niveau, 1);
+
+        monde.nouvelle_partie(&settings, ECRAN);
+        assert_eq!(monde.statistiques().tirs, 0);
+    }
+
+    /// En quittant, le score de la partie en cours est ajouté au fichier des meilleurs scores, s'il y a sa place.
+    #[test]
+    fn test_on_quit() {
+        let chemin = std::env::temp_dir().join(format!("scores_quit_{}.txt", std::process::id()));
+        let settings = Settings {
+            fichier_scores: chemin.to_str().unwrap().to_string(),
+            ..Default::default()
+        };
+        std::fs::write(&chemin, "ABC 500\n").unwrap();
+        let mut monde = World::new(&settings, Some(1), ECRAN);
+        monde.on_quit().unwrap(); // Score nul : le fichier ne change pas.
+        assert_eq!(std::fs::read_to_string(&chemin).unwrap(), "ABC 500\n");
+
+        monde.enregistrer_destructions(3); // 3 x 100 points (combo x1)
+        monde.enregistrer_destructions(2); // 2 x 100 points (combo x4)
+        monde.on_quit().unwrap();
+        let contenu = std::fs::read_to_string(&chemin);
+        std::fs::remove_file(&chemin).unwrap();
+        assert_eq!(contenu.unwrap(), "??? 1100\nABC 500\n");
+    }
+}
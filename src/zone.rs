@@ -0,0 +1,258 @@
+//! Module pour les zones dangereuses de certains niveaux.
+//! Ce module contient la structure `Zone` : une bande de l'écran (par exemple une nébuleuse)
+//! qui ralentit ou détruit les missiles qui la traversent, ce qui oblige le joueur à bien se placer pour tirer.
+//! Il contient aussi `ZoneRalentissement` : un champ circulaire que le joueur déploie pour piéger les astéroïdes.
+
+use crate::missile::Missile;
+use crate::stellarobject::StellarObject;
+use macroquad::prelude::*;
+
+/// Effet d'une zone sur les missiles qui la traversent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EffetZone {
+    Ralentissement(f32), // Part de la vitesse conservée à chaque frame passée dans la zone
+    Destruction,         // Le missile disparaît dès qu'il entre dans la zone
+}
+
+/// Zone rectangulaire de l'écran qui agit sur les missiles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Zone {
+    rect: Rect,       // Rectangle occupé par la zone
+    effet: EffetZone, // Effet appliqué aux missiles qui s'y trouvent
+}
+
+impl Zone {
+    /// Part de la vitesse conservée à chaque frame par un missile dans une nébuleuse.
+    pub const RALENTISSEMENT_NEBULEUSE: f32 = 0.95;
+
+    /// Hauteur (en pixels) de la bande de nébuleuse.
+    pub const HAUTEUR_BANDE: f32 = 120.0;
+
+    /// Crée une zone occupant le rectangle donné.
+    pub fn new(rect: Rect, effet: EffetZone) -> Self {
+        Self { rect, effet }
+    }
+
+    /// Crée une bande horizontale qui traverse tout l'écran en son milieu.
+    /// # Arguments
+    /// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+    /// - `effet` - Effet de la bande sur les missiles.
+    pub fn bande_horizontale(ecran: Vec2, effet: EffetZone) -> Self {
+        let haut = (ecran.y - Self::HAUTEUR_BANDE) / 2.0;
+        Self::new(Rect::new(0.0, haut, ecran.x, Self::HAUTEUR_BANDE), effet)
+    }
+
+    /// Indique si le point donné se trouve dans la zone.
+    pub fn contient(&self, point: Vec2) -> bool {
+        self.rect.contains(point)
+    }
+
+    /// Applique l'effet de la zone au missile s'il s'y trouve.
+    /// # Retourne `true` si le missile doit être enlevé.
+    pub fn affecter_missile(&self, missile: &mut Missile) -> bool {
+        if !self.contient(missile.get_position()) {
+            return false;
+        }
+        match self.effet {
+            EffetZone::Ralentissement(facteur) => {
+                missile.set_vitesse(missile.get_vitesse() * facteur);
+                false
+            }
+            EffetZone::Destruction => true,
+        }
+    }
+
+    /// Dessine la zone en transparence : violette si elle ralentit, rouge si elle détruit.
+    pub fn draw(&self) {
+        let couleur = match self.effet {
+            EffetZone::Ralentissement(_) => Color::new(0.6, 0.3, 0.9, 0.2),
+            EffetZone::Destruction => Color::new(0.9, 0.2, 0.2, 0.2),
+        };
+        draw_rectangle(self.rect.x, self.rect.y, self.rect.w, self.rect.h, couleur);
+    }
+}
+
+/// Champ de ralentissement déployé par le joueur : les astéroïdes qui le traversent avancent moins vite,
+/// jusqu'à ce que le champ disparaisse.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZoneRalentissement {
+    position: Vec2,      // Centre du champ (position du vaisseau au moment du déploiement)
+    rayon: f32,          // Rayon du champ
+    duree_restante: f32, // Temps (en secondes) avant que le champ disparaisse
+}
+
+impl ZoneRalentissement {
+    /// Rayon (en pixels) d'un champ déployé par le joueur.
+    pub const RAYON: f32 = 100.0;
+
+    /// Durée de vie (en secondes) d'un champ déployé par le joueur.
+    pub const DUREE: f32 = 5.0;
+
+    /// Facteur appliqué au déplacement des astéroïdes qui se trouvent dans le champ.
+    pub const FACTEUR: f32 = 0.5;
+
+    /// Crée un champ de ralentissement.
+    /// # Arguments
+    /// - `position` - Centre du champ.
+    /// - `rayon` - Rayon du champ.
+    /// - `duree` - Durée de vie du champ (en secondes).
+    pub fn new(position: Vec2, rayon: f32, duree: f32) -> Self {
+        Self {
+            position,
+            rayon,
+            duree_restante: duree,
+        }
+    }
+
+    /// Crée le champ déployé par le joueur à la position de son vaisseau, avec les valeurs par défaut.
+    pub fn deployer(position: Vec2) -> Self {
+        Self::new(position, Self::RAYON, Self::DUREE)
+    }
+
+    /// Indique si le champ a disparu.
+    pub fn est_expiree(&self) -> bool {
+        self.duree_restante <= 0.0
+    }
+
+    /// Retourne le facteur à appliquer au déplacement d'un astéroïde situé au point donné :
+    /// `FACTEUR` dans le champ, 1.0 en dehors (ou si le champ a disparu).
+    pub fn facteur(&self, point: Vec2) -> f32 {
+        if !self.est_expiree() && self.position.distance(point) < self.rayon {
+            Self::FACTEUR
+        } else {
+            1.0
+        }
+    }
+
+    /// Fait vieillir le champ.
+    /// # Arguments
+    /// - `delta_time` - Temps écoulé (en secondes) depuis la dernière frame.
+    pub fn avancer(&mut self, delta_time: f32) {
+        self.duree_restante = (self.duree_restante - delta_time).max(0.0);
+    }
+
+    /// Dessine le champ en transparence, de plus en plus pâle à mesure qu'il disparaît.
+    pub fn draw(&self) {
+        if self.est_expiree() {
+            return;
+        }
+        let alpha = (self.duree_restante / Self::DUREE).min(1.0);
+        draw_circle(
+            self.position.x,
+            self.position.y,
+            self.rayon,
+            Color::new(0.3, 0.7, 1.0, 0.15 * alpha),
+        );
+        draw_circle_lines(
+            self.position.x,
+            self.position.y,
+            self.rayon,
+            2.0,
+            Color::new(0.3, 0.7, 1.0, 0.6 * alpha),
+        );
+    }
+}
+
+/// Retourne le facteur à appliquer au déplacement d'un astéroïde situé au point donné,
+/// selon les champs de ralentissement : les champs qui se chevauchent ne se cumulent pas.
+pub fn facteur_ralentissement(zones: &[ZoneRalentissement], point: Vec2) -> f32 {
+    zones
+        .iter()
+        .map(|zone| zone.facteur(point))
+        .fold(1.0, f32::min)
+}
+
+/// Retourne les zones dangereuses d'un niveau.
+/// Un niveau sur `intervalle` a une nébuleuse qui ralentit les missiles, et un sur deux de ceux-là
+/// une nébuleuse dense qui les détruit (0 = jamais de nébuleuse).
+/// # Arguments
+/// - `niveau` - Niveau qui commence.
+/// - `intervalle` - Nombre de niveaux entre deux nébuleuses.
+/// - `ecran` - Dimensions de l'écran (largeur, hauteur).
+pub fn zones_niveau(niveau: i32, intervalle: u32, ecran: Vec2) -> Vec<Zone> {
+    let intervalle = intervalle as i32;
+    if intervalle == 0 || niveau % intervalle != 0 {
+        return Vec::new();
+    }
+    let effet = if niveau % (2 * intervalle) == 0 {
+        EffetZone::Destruction
+    } else {
+        EffetZone::Ralentissement(Zone::RALENTISSEMENT_NEBULEUSE)
+    };
+    vec![Zone::bande_horizontale(ecran, effet)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ECRAN: Vec2 = vec2(800.0, 600.0);
+
+    #[test]
+    fn test_point_dans_zone() {
+        let zone = Zone::bande_horizontale(ECRAN, EffetZone::Destruction);
+        assert!(zone.contient(vec2(400.0, 300.0)));
+        assert!(zone.contient(vec2(10.0, 250.0)));
+        assert!(!zone.contient(vec2(400.0, 100.0)));
+        assert!(!zone.contient(vec2(400.0, 500.0)));
+    }
+
+    /// Un missile dans une nébuleuse ralentit, un missile dans une zone de destruction est enlevé,
+    /// et un missile hors de la zone n'est pas affecté.
+    #[test]
+    fn test_effet_sur_missile() {
+        let nebuleuse = Zone::bande_horizontale(ECRAN, EffetZone::Ralentissement(0.5));
+        let mut missile = Missile::nouveau_missile(vec2(400.0, 300.0), 0.0);
+        assert!(!nebuleuse.affecter_missile(&mut missile));
+        assert_eq!(missile.get_vitesse().x, Missile::VITESSE_DEFAUT * 0.5);
+
+        let destruction = Zone::bande_horizontale(ECRAN, EffetZone::Destruction);
+        assert!(destruction.affecter_missile(&mut missile));
+
+        let mut dehors = Missile::nouveau_missile(vec2(400.0, 50.0), 0.0);
+        assert!(!destruction.affecter_missile(&mut dehors));
+        assert!(!nebuleuse.affecter_missile(&mut dehors));
+        assert_eq!(dehors.get_vitesse().x, Missile::VITESSE_DEFAUT);
+    }
+
+    /// Dans le champ, le déplacement des astéroïdes est réduit de moitié ; en dehors, il n'est pas modifié.
+    #[test]
+    fn test_facteur_ralentissement() {
+        let champ = ZoneRalentissement::deployer(vec2(400.0, 300.0));
+        assert_eq!(
+            champ.facteur(vec2(450.0, 300.0)),
+            ZoneRalentissement::FACTEUR
+        );
+        assert_eq!(champ.facteur(vec2(550.0, 300.0)), 1.0);
+
+        let autre = ZoneRalentissement::deployer(vec2(500.0, 300.0));
+        let zones = [champ, autre];
+        assert_eq!(facteur_ralentissement(&zones, vec2(450.0, 300.0)), 0.5); // Pas de cumul
+        assert_eq!(facteur_ralentissement(&zones, vec2(100.0, 100.0)), 1.0);
+        assert_eq!(facteur_ralentissement(&[], vec2(450.0, 300.0)), 1.0);
+    }
+
+    /// Le champ disparaît après sa durée de vie, et ne ralentit plus rien ensuite.
+    #[test]
+    fn test_champ_expire() {
+        let mut champ = ZoneRalentissement::new(vec2(400.0, 300.0), 100.0, 1.0);
+        for _ in 0..59 {
+            champ.avancer(1.0 / 60.0);
+        }
+        assert!(!champ.est_expiree());
+        champ.avancer(0.1);
+        assert!(champ.est_expiree());
+        assert_eq!(champ.facteur(vec2(400.0, 300.0)), 1.0);
+    }
+
+    #[test]
+    fn test_zones_niveau() {
+        assert!(zones_niveau(3, 0, ECRAN).is_empty());
+        assert!(zones_niveau(2, 3, ECRAN).is_empty());
+        assert_eq!(
+            zones_niveau(3, 3, ECRAN)[0].effet,
+            EffetZone::Ralentissement(Zone::RALENTISSEMENT_NEBULEUSE)
+        );
+        assert_eq!(zones_niveau(6, 3, ECRAN)[0].effet, EffetZone::Destruction);
+    }
+}